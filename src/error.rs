@@ -1,17 +1,161 @@
 
 use thiserror::Error;
+use crate::{parameters::QuotaOverage, schema::SchemaViolation};
+
+/// The longest raw value, in characters, kept in a `ConversionFailed` error.
+pub(crate) const MAX_RAW_CHARS: usize = 64;
 
 #[derive(Debug, Error)]
 pub enum ParameterError {
-    #[error("Parameter not found: {0}")]
-    KeyNotFound(String),
+    #[error("Parameter not found: {key}{}", did_you_mean(.suggestion))]
+    KeyNotFound {
+        key: String,
+        /// The closest existing key, if one is similar enough to be a likely typo.
+        suggestion: Option<String>,
+    },
+
+    #[error("Missing required parameters: {}", .0.join(", "))]
+    MissingKeys(Vec<String>),
 
-    #[error("Type conversion failed: {0}")]
-    ConversionFailed(#[from] Box<dyn std::error::Error + Send + Sync>),
+    #[error("Parameter already exists: {0}")]
+    DuplicateKey(String),
+
+    #[error("Conflicting values for parameter: {0}")]
+    MergeConflict(String),
+
+    #[error("Type conversion failed for {key}: cannot convert {raw:?} (stored as {type_name}) to {target}: {source}")]
+    ConversionFailed {
+        key: String,
+        /// The string form of the offending value, masked for sensitive keys
+        /// and truncated to 64 characters.
+        raw: String,
+        /// The type the value was stored as.
+        type_name: &'static str,
+        /// The type the value was being converted to.
+        target: &'static str,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
 
     #[error("Type mismatch: expected {expected}, found {actual}")]
     TypeMismatch {
         expected: &'static str,
         actual: &'static str,
     },
+
+    #[error("Invalid patch: {0}")]
+    InvalidPatch(String),
+
+    #[error("Failed to parse {format}: {message}")]
+    Parse {
+        format: &'static str,
+        message: String,
+    },
+
+    #[error("Checksum mismatch: expected {expected}, computed {actual}")]
+    ChecksumMismatch {
+        expected: String,
+        actual: String,
+    },
+
+    #[error("Evaluation limit exceeded: {limit} is limited to {max}")]
+    EvalLimitExceeded {
+        /// The limit that was crossed: `steps`, `depth` or `output length`.
+        limit: &'static str,
+        max: usize,
+    },
+
+    #[error("Capacity limit exceeded for {key}: {limit} is limited to {max}, got {actual}")]
+    LimitExceeded {
+        key: String,
+        /// The limit that was crossed: `keys` or `value length`.
+        limit: &'static str,
+        max: usize,
+        actual: usize,
+    },
+
+    #[error("Parameters are frozen: cannot modify {0}")]
+    Frozen(String),
+
+    #[error("Namespace conflict: {key} belongs to {}", .owner.as_deref().unwrap_or("the host"))]
+    NamespaceConflict {
+        key: String,
+        /// The plugin that owns the key, or `None` if it belongs to the host application.
+        owner: Option<String>,
+    },
+
+    #[error("Unresolved reference ${{{reference}}}{}", .key.as_ref().map(|key| format!(" in {}", key)).unwrap_or_default())]
+    UnresolvedReference {
+        /// The key whose value holds the reference, or `None` for a template passed to `render`.
+        key: Option<String>,
+        reference: String,
+    },
+
+    #[error("Interpolation cycle: {}", .0.join(" -> "))]
+    InterpolationCycle(Vec<String>),
+
+    #[error("Invalid override {entry:?}: {reason}")]
+    InvalidOverride {
+        entry: String,
+        reason: String,
+    },
+
+    /// A `ParameterStore` backend failed, e.g. a lost Redis connection.
+    #[error("Store error: {0}")]
+    Store(#[source] Box<dyn std::error::Error + Send + Sync>),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Schema validation failed with {} violation(s)", .0.len())]
+    ValidationFailed(Vec<SchemaViolation>),
+
+    #[error("Quota exceeded for {} parameter(s)", .0.len())]
+    QuotaExceeded(Vec<QuotaOverage>),
+}
+
+impl ParameterError {
+    /// A `KeyNotFound` error without a suggestion.
+    pub fn key_not_found(key: impl Into<String>) -> Self {
+        ParameterError::KeyNotFound {
+            key: key.into(),
+            suggestion: None,
+        }
+    }
+
+    /// A `ConversionFailed` error, truncating `raw` to `MAX_RAW_CHARS` characters.
+    pub(crate) fn conversion_failed(
+        key: &str,
+        raw: &str,
+        type_name: &'static str,
+        target: &'static str,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        let raw = match raw.char_indices().nth(MAX_RAW_CHARS) {
+            Some((end, _)) => format!("{}...", &raw[..end]),
+            None => raw.to_string(),
+        };
+        ParameterError::ConversionFailed {
+            key: key.to_string(),
+            raw,
+            type_name,
+            target,
+            source: source.into(),
+        }
+    }
+
+    /// Fill in the key of a `ConversionFailed` raised by a value that does not know its own key.
+    pub(crate) fn with_key(self, key: &str) -> Self {
+        match self {
+            ParameterError::ConversionFailed { key: missing, raw, type_name, target, source } if missing.is_empty() => {
+                ParameterError::ConversionFailed { key: key.to_string(), raw, type_name, target, source }
+            }
+            other => other,
+        }
+    }
+}
+
+fn did_you_mean(suggestion: &Option<String>) -> String {
+    suggestion.as_ref()
+        .map(|suggestion| format!(" (did you mean `{suggestion}`?)"))
+        .unwrap_or_default()
 }
@@ -0,0 +1,186 @@
+use serde_json::Value;
+use crate::{error::ParameterError, Parameters, Result};
+
+fn parse_error(format: &'static str, message: impl ToString) -> ParameterError {
+    ParameterError::Parse {
+        format,
+        message: message.to_string(),
+    }
+}
+
+#[cfg(feature = "msgpack")]
+impl Parameters {
+    /// Encode the `Parameters` as MessagePack.
+    ///
+    /// Values are converted as in `to_json_unredacted`, so sensitive keys and
+    /// `Secret`s keep their values, and nested `Parameters` become maps.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the encoded bytes, or a `ParameterError` if a value cannot be converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("port", 8080).with("db", Parameters::new().with("host", "x"));
+    /// let bytes = params.to_msgpack().unwrap();
+    ///
+    /// let decoded = Parameters::from_msgpack(&bytes).unwrap();
+    /// assert_eq!(decoded.get::<i64>("port"), Some(&8080));
+    /// assert_eq!(decoded.get::<Parameters>("db").unwrap().get_str("host"), Some("x"));
+    /// ```
+    pub fn to_msgpack(&self) -> Result<Vec<u8>> {
+        rmp_serde::to_vec_named(&self.to_json_unredacted()?).map_err(|e| parse_error("MessagePack", e))
+    }
+
+    /// Decode `Parameters` from a MessagePack map, converted as described in [`Parameters::from_json`].
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The encoded map.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Parameters`, or `ParameterError::Parse` if the bytes are not a MessagePack map.
+    pub fn from_msgpack(bytes: &[u8]) -> Result<Parameters> {
+        let json: Value = rmp_serde::from_slice(bytes).map_err(|e| parse_error("MessagePack", e))?;
+        Parameters::from_json(json).map_err(|e| parse_error("MessagePack", e))
+    }
+}
+
+/// A JSON tree bincode can encode: bincode is not self-describing, so it
+/// cannot decode a `serde_json::Value` directly.
+#[cfg(feature = "bincode")]
+#[derive(serde::Serialize, serde::Deserialize)]
+enum Tree {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    String(String),
+    Array(Vec<Tree>),
+    Object(Vec<(String, Tree)>),
+}
+
+#[cfg(feature = "bincode")]
+impl From<Value> for Tree {
+    fn from(json: Value) -> Self {
+        match json {
+            Value::Null => Tree::Null,
+            Value::Bool(b) => Tree::Bool(b),
+            Value::Number(n) => n.as_i64().map(Tree::Int)
+                .or_else(|| n.as_u64().map(Tree::UInt))
+                .unwrap_or_else(|| Tree::Float(n.as_f64().unwrap_or(f64::NAN))),
+            Value::String(s) => Tree::String(s),
+            Value::Array(items) => Tree::Array(items.into_iter().map(Tree::from).collect()),
+            Value::Object(object) => Tree::Object(object.into_iter().map(|(key, value)| (key, value.into())).collect()),
+        }
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl From<Tree> for Value {
+    fn from(tree: Tree) -> Self {
+        match tree {
+            Tree::Null => Value::Null,
+            Tree::Bool(b) => Value::Bool(b),
+            Tree::Int(i) => Value::from(i),
+            Tree::UInt(u) => Value::from(u),
+            Tree::Float(f) => serde_json::Number::from_f64(f).map_or(Value::Null, Value::Number),
+            Tree::String(s) => Value::String(s),
+            Tree::Array(items) => Value::Array(items.into_iter().map(Value::from).collect()),
+            Tree::Object(entries) => Value::Object(entries.into_iter().map(|(key, value)| (key, value.into())).collect()),
+        }
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl Parameters {
+    /// Encode the `Parameters` with bincode.
+    ///
+    /// Values are converted as in `to_json_unredacted`, so sensitive keys and
+    /// `Secret`s keep their values. The encoding is only readable by
+    /// `from_bincode`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the encoded bytes, or a `ParameterError` if a value cannot be converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("ratio", 0.5).with("tags", vec!["a".to_string()]);
+    /// let decoded = Parameters::from_bincode(&params.to_bincode().unwrap()).unwrap();
+    ///
+    /// assert_eq!(decoded.get::<f64>("ratio"), Some(&0.5));
+    /// assert_eq!(decoded.to_json().unwrap()["tags"], serde_json::json!(["a"]));
+    /// ```
+    pub fn to_bincode(&self) -> Result<Vec<u8>> {
+        bincode::serde::encode_to_vec(Tree::from(self.to_json_unredacted()?), bincode::config::standard())
+            .map_err(|e| parse_error("bincode", e))
+    }
+
+    /// Decode `Parameters` written by `to_bincode`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The encoded parameters.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Parameters`, or `ParameterError::Parse` if the bytes are not valid.
+    pub fn from_bincode(bytes: &[u8]) -> Result<Parameters> {
+        let (tree, _): (Tree, usize) = bincode::serde::decode_from_slice(bytes, bincode::config::standard())
+            .map_err(|e| parse_error("bincode", e))?;
+        Parameters::from_json(tree.into()).map_err(|e| parse_error("bincode", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn test_msgpack_round_trip_keeps_sensitive_values() {
+        let mut params = Parameters::new()
+            .with("port", 8080)
+            .with("db", Parameters::new().with("password", "hunter2").with("ratio", 0.25));
+        params.mark_sensitive("password");
+        params.insert_secret("token", "t0k".to_string());
+
+        let decoded = Parameters::from_msgpack(&params.to_msgpack().unwrap()).unwrap();
+        assert_eq!(decoded.get::<i64>("port"), Some(&8080));
+        assert_eq!(decoded.get_str("token"), Some("t0k"));
+        let db = decoded.get::<Parameters>("db").unwrap();
+        assert_eq!((db.get_str("password"), db.get::<f64>("ratio")), (Some("hunter2"), Some(&0.25)));
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn test_msgpack_rejects_non_maps() {
+        let bytes = rmp_serde::to_vec(&[1, 2, 3]).unwrap();
+        assert!(matches!(Parameters::from_msgpack(&bytes), Err(ParameterError::Parse { format: "MessagePack", .. })));
+        assert!(Parameters::from_msgpack(&[0xc1]).is_err());
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_bincode_keeps_types_and_rejects_garbage() {
+        let params = Parameters::new()
+            .with("big", u64::MAX)
+            .with("flag", true)
+            .with("nested", Parameters::new().with("name", "x"));
+
+        let decoded = Parameters::from_bincode(&params.to_bincode().unwrap()).unwrap();
+        assert_eq!(decoded.get::<u64>("big"), Some(&u64::MAX));
+        assert_eq!(decoded.get::<bool>("flag"), Some(&true));
+        assert_eq!(decoded.to_json().unwrap(), params.to_json().unwrap());
+        assert!(Parameters::from_bincode(&[0xff, 0xff]).is_err());
+    }
+}
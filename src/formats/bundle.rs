@@ -0,0 +1,193 @@
+use std::{
+    fmt::Write,
+    fs,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use crate::{error::ParameterError, parameters::write_canonical, Parameters, Result};
+
+/// Identifies the file layout, so later layouts can be told apart.
+const FORMAT: &str = "parameterx-bundle/1";
+
+fn parse_error(message: impl ToString) -> ParameterError {
+    ParameterError::Parse {
+        format: "bundle",
+        message: message.to_string(),
+    }
+}
+
+/// A parameter set packaged with the metadata needed to share and verify it.
+///
+/// A bundle file is a JSON document holding the name, version, creation
+/// time and parameters, plus a SHA-256 checksum over all of them that is
+/// verified on import. Values are stored in their JSON form, so they come
+/// back with the types `Parameters::from_json` gives them; `Secret` values
+/// are exported masked.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{Bundle, Parameters};
+///
+/// let params = Parameters::new().with("learning_rate", 0.01).with("epochs", 20);
+/// let bundle = Bundle::new("baseline", "1.2.0", params);
+///
+/// let text = bundle.to_json_string();
+/// let imported = Bundle::from_json_str(&text).unwrap();
+/// assert_eq!(imported.name, "baseline");
+/// assert_eq!(imported.parameters.get::<i64>("epochs"), Some(&20));
+///
+/// let tampered = text.replace("20", "30");
+/// assert!(Bundle::from_json_str(&tampered).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Bundle {
+    pub name: String,
+    pub version: String,
+    /// Seconds since the Unix epoch.
+    pub created_at: u64,
+    pub parameters: Parameters,
+}
+
+impl Bundle {
+    /// Package `parameters`, stamped with the current time.
+    pub fn new(name: impl Into<String>, version: impl Into<String>, parameters: Parameters) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            created_at: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs()),
+            parameters,
+        }
+    }
+
+    fn content(&self) -> Value {
+        json!({
+            "name": self.name,
+            "version": self.version,
+            "created_at": self.created_at,
+            "parameters": Value::Object(self.parameters.json_object()),
+        })
+    }
+
+    fn digest(content: &Value) -> String {
+        let mut canonical = String::new();
+        write_canonical(content, false, &mut canonical);
+        let mut checksum = String::from("sha256:");
+        for byte in Sha256::digest(canonical.as_bytes()) {
+            let _ = write!(checksum, "{:02x}", byte);
+        }
+        checksum
+    }
+
+    /// The checksum written to the bundle file, as `sha256:` followed by hex digits.
+    pub fn checksum(&self) -> String {
+        Self::digest(&self.content())
+    }
+
+    /// Serialize the bundle, checksum included.
+    pub fn to_json_string(&self) -> String {
+        let mut document = self.content();
+        document["format"] = FORMAT.into();
+        document["checksum"] = self.checksum().into();
+        serde_json::to_string_pretty(&document).unwrap_or_default()
+    }
+
+    /// Parse a bundle and verify its checksum.
+    ///
+    /// Returns `ParameterError::Parse` if the document is not a bundle, and
+    /// `ParameterError::ChecksumMismatch` if its content was altered.
+    pub fn from_json_str(text: &str) -> Result<Self> {
+        let mut document: Value = serde_json::from_str(text).map_err(parse_error)?;
+        let object = document.as_object_mut().ok_or_else(|| parse_error("expected a JSON object"))?;
+        match object.remove("format") {
+            Some(Value::String(format)) if format == FORMAT => {}
+            Some(other) => return Err(parse_error(format!("unsupported bundle format {}", other))),
+            None => return Err(parse_error("missing field `format`")),
+        }
+        let expected = match object.remove("checksum") {
+            Some(Value::String(checksum)) => checksum,
+            _ => return Err(parse_error("missing field `checksum`")),
+        };
+
+        let actual = Self::digest(&document);
+        if actual != expected {
+            return Err(ParameterError::ChecksumMismatch { expected, actual });
+        }
+
+        let field = |name: &str| document.get(name).ok_or_else(|| parse_error(format!("missing field `{}`", name)));
+        let text = |name: &str| field(name)?.as_str().map(str::to_string).ok_or_else(|| parse_error(format!("`{}` must be a string", name)));
+        Ok(Self {
+            name: text("name")?,
+            version: text("version")?,
+            created_at: field("created_at")?.as_u64().ok_or_else(|| parse_error("`created_at` must be a non-negative integer"))?,
+            parameters: Parameters::from_json(field("parameters")?.clone())?,
+        })
+    }
+
+    /// Write the bundle to a file.
+    pub fn export(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, self.to_json_string())?;
+        Ok(())
+    }
+
+    /// Read a bundle file and verify its checksum.
+    pub fn import(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_json_str(&fs::read_to_string(path)?)
+    }
+}
+
+impl Parameters {
+    /// Write these parameters to a bundle file with the given name and version.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The file to write.
+    /// * `name` - A name identifying the parameter set.
+    /// * `version` - The version of the parameter set.
+    ///
+    /// # Returns
+    ///
+    /// The exported `Bundle`, or a `ParameterError::Io` if the file could not be written.
+    pub fn export_bundle(&self, path: impl AsRef<Path>, name: &str, version: &str) -> Result<Bundle> {
+        let bundle = Bundle::new(name, version, self.clone());
+        bundle.export(path)?;
+        Ok(bundle)
+    }
+
+    /// Read a bundle file, verifying its checksum, and return its parameters and metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The file to read.
+    ///
+    /// # Returns
+    ///
+    /// The imported `Bundle`, or `ParameterError::ChecksumMismatch` if the file was altered.
+    pub fn import_bundle(path: impl AsRef<Path>) -> Result<Bundle> {
+        Bundle::import(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_roundtrip_and_metadata_tampering() {
+        let path = std::env::temp_dir().join(format!("parameterx-bundle-{}.json", std::process::id()));
+        let params = Parameters::new().with("db", Parameters::new().with("pool", 8)).with("tags", json!(["a", "b"]));
+        let exported = params.export_bundle(&path, "staging", "3").unwrap();
+
+        let imported = Parameters::import_bundle(&path).unwrap();
+        assert_eq!(imported.checksum(), exported.checksum());
+        assert_eq!(imported.created_at, exported.created_at);
+        assert_eq!(imported.parameters.get::<Parameters>("db").unwrap().get::<i64>("pool"), Some(&8));
+
+        let text = fs::read_to_string(&path).unwrap().replace("\"staging\"", "\"prod\"");
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(Bundle::from_json_str(&text), Err(ParameterError::ChecksumMismatch { .. })));
+        assert!(matches!(Bundle::from_json_str("{}"), Err(ParameterError::Parse { format: "bundle", .. })));
+    }
+}
@@ -0,0 +1,82 @@
+use std::{fs, path::Path};
+use crate::{error::ParameterError, Parameters, Result};
+
+impl Parameters {
+    /// Read a configuration file, choosing the format from its extension.
+    ///
+    /// `.json`, `.properties` and `.ini` are always understood; `.toml`,
+    /// `.yaml`/`.yml` and `.xml` need the matching feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The file to read.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Parameters`, `ParameterError::Io` if the file cannot be read,
+    /// or `ParameterError::Parse` if its contents are malformed or its extension is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let path = std::env::temp_dir().join("parameterx-from-file-doc.json");
+    /// std::fs::write(&path, r#"{"server": {"port": 8080}}"#).unwrap();
+    ///
+    /// let params = Parameters::from_file(&path).unwrap();
+    /// assert_eq!(params.get::<Parameters>("server").unwrap().get::<i64>("port"), Some(&8080));
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Parameters> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)?;
+        let extension = path.extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase)
+            .unwrap_or_default();
+        match extension.as_str() {
+            "json" => {
+                let json = serde_json::from_str(&text).map_err(|e| ParameterError::Parse {
+                    format: "JSON",
+                    message: format!("{}", e),
+                })?;
+                Parameters::from_json(json)
+            }
+            "properties" => Parameters::from_properties(&text),
+            "ini" => Parameters::from_ini(&text),
+            #[cfg(feature = "toml")]
+            "toml" => Parameters::from_toml(&text),
+            #[cfg(feature = "yaml")]
+            "yaml" | "yml" => Parameters::from_yaml(&text),
+            #[cfg(feature = "xml")]
+            "xml" => Parameters::from_xml(&text),
+            _ => Err(ParameterError::Parse {
+                format: "file",
+                message: format!("unsupported file extension for {}", path.display()),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_file_dispatches_on_extension() {
+        let dir = std::env::temp_dir();
+        let ini = dir.join("parameterx-from-file-test.INI");
+        fs::write(&ini, "[db]\nhost = a.local\n").unwrap();
+        let params = Parameters::from_file(&ini).unwrap();
+        assert_eq!(params.get::<Parameters>("db").unwrap().get_string("host").as_deref(), Some("a.local"));
+        fs::remove_file(&ini).unwrap();
+
+        let unknown = dir.join("parameterx-from-file-test.conf");
+        fs::write(&unknown, "").unwrap();
+        assert!(matches!(Parameters::from_file(&unknown), Err(ParameterError::Parse { format: "file", .. })));
+        fs::remove_file(&unknown).unwrap();
+
+        assert!(matches!(Parameters::from_file(dir.join("parameterx-missing.json")), Err(ParameterError::Io(_))));
+    }
+}
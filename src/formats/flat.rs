@@ -0,0 +1,173 @@
+use std::collections::BTreeMap;
+use serde_json::{Map, Value};
+use crate::{error::ParameterError, parameters::value_to_json, Parameters, Result};
+
+/// A partially built tree of dotted keys.
+enum Node {
+    Leaf(Value),
+    Branch(BTreeMap<String, Node>),
+}
+
+fn index(segment: &str) -> Option<usize> {
+    let canonical = !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) && (segment == "0" || !segment.starts_with('0'));
+    segment.parse().ok().filter(|_| canonical)
+}
+
+/// Whether the keys of a branch are exactly the indices `0..len`, in any order.
+fn is_contiguous(children: &BTreeMap<String, Node>) -> bool {
+    let mut seen = vec![false; children.len()];
+    children.keys().all(|key| match index(key) {
+        Some(i) if i < seen.len() && !seen[i] => {
+            seen[i] = true;
+            true
+        }
+        _ => false,
+    })
+}
+
+impl Node {
+    fn into_json(self) -> Value {
+        match self {
+            Node::Leaf(value) => value,
+            Node::Branch(children) if !children.is_empty() && is_contiguous(&children) => {
+                let mut items: Vec<(usize, Node)> = children.into_iter()
+                    .filter_map(|(key, node)| Some((index(&key)?, node)))
+                    .collect();
+                items.sort_by_key(|(index, _)| *index);
+                Value::Array(items.into_iter().map(|(_, node)| node.into_json()).collect())
+            }
+            Node::Branch(children) => Value::Object(children.into_iter()
+                .map(|(key, node)| (key, node.into_json()))
+                .collect()),
+        }
+    }
+}
+
+/// Rebuild nested `Parameters` from flat `a.b.c` keys.
+///
+/// A branch whose segments are the array indices `0` to `n - 1`, as in
+/// `servers.0.host` and `servers.1.host`, becomes a list ordered by index.
+/// Indices with gaps, such as `ports.3` and `ports.10`, are kept as nested
+/// parameters keyed by the index, so no index is renumbered. A key that is
+/// both a value and a prefix of another key is reported as a
+/// `ParameterError::Parse` for `format`.
+pub(crate) fn unflatten<I>(format: &'static str, entries: I) -> Result<Parameters>
+where
+    I: IntoIterator<Item = (String, Value)>,
+{
+    let conflict = |key: &str| ParameterError::Parse {
+        format,
+        message: format!("key is both a value and a table: {key}"),
+    };
+
+    let mut root: BTreeMap<String, Node> = BTreeMap::new();
+    for (key, value) in entries {
+        let mut segments: Vec<&str> = key.split('.').collect();
+        let last = segments.pop().unwrap_or_default();
+        let mut branch = &mut root;
+        for segment in segments {
+            let node = branch.entry(segment.to_string()).or_insert_with(|| Node::Branch(BTreeMap::new()));
+            branch = match node {
+                Node::Branch(children) => children,
+                Node::Leaf(_) => return Err(conflict(&key)),
+            };
+        }
+        match branch.get(last) {
+            Some(Node::Branch(_)) => return Err(conflict(&key)),
+            _ => {
+                branch.insert(last.to_string(), Node::Leaf(value));
+            }
+        }
+    }
+
+    let object: Map<String, Value> = root.into_iter()
+        .map(|(key, node)| (key, node.into_json()))
+        .collect();
+    Parameters::from_json(Value::Object(object))
+}
+
+/// List the values of `params` with their key paths, descending into nested
+/// `Parameters` but not into other values.
+pub(crate) fn leaves(params: &Parameters) -> Vec<(Vec<String>, Value)> {
+    fn walk(path: &mut Vec<String>, params: &Parameters, out: &mut Vec<(Vec<String>, Value)>) {
+        for (key, value) in params.iter() {
+            path.push(String::from(key));
+            match value.as_any().downcast_ref::<Parameters>() {
+                Some(nested) if !nested.is_empty() => walk(path, nested, out),
+                _ => out.push((path.clone(), value_to_json(value.as_ref()))),
+            }
+            path.pop();
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(&mut Vec::new(), params, &mut out);
+    out
+}
+
+/// Flatten `Parameters` into dotted keys, numbering list items from zero.
+///
+/// Strings are emitted as-is and other scalars in their JSON form.
+pub(crate) fn flatten(params: &Parameters) -> Vec<(String, String)> {
+    fn walk(prefix: &str, json: &Value, out: &mut Vec<(String, String)>) {
+        let child = |key: &str| format!("{prefix}.{key}");
+        match json {
+            Value::Object(object) => {
+                for (key, value) in object {
+                    walk(&child(key), value, out);
+                }
+            }
+            Value::Array(items) => {
+                for (index, value) in items.iter().enumerate() {
+                    walk(&child(&index.to_string()), value, out);
+                }
+            }
+            Value::String(text) => out.push((prefix.to_string(), text.clone())),
+            other => out.push((prefix.to_string(), other.to_string())),
+        }
+    }
+
+    let mut out = Vec::new();
+    for (path, json) in leaves(params) {
+        walk(&path.join("."), &json, &mut out);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn test_unflatten_lists() {
+        let entries = [
+            ("servers.1.host", "b"),
+            ("servers.0.host", "a"),
+            ("servers.0.port", "80"),
+            ("ports.3", "x"),
+            ("ports.10", "y"),
+            ("codes.01", "z"),
+        ].map(|(k, v)| (k.to_string(), Value::from(v)));
+        let params = unflatten("properties", entries).unwrap();
+
+        assert_eq!(
+            params.get::<Value>("servers"),
+            Some(&json!([{ "host": "a", "port": "80" }, { "host": "b" }])),
+        );
+        let ports = params.get::<Parameters>("ports").unwrap();
+        assert_eq!(ports.get::<String>("10").map(String::as_str), Some("y"));
+        assert!(params.get::<Parameters>("codes").is_some());
+
+        let flat = flatten(&params);
+        assert!(flat.contains(&("servers.1.host".to_string(), "b".to_string())));
+        assert!(flat.contains(&("ports.3".to_string(), "x".to_string())));
+        assert!(flat.contains(&("ports.10".to_string(), "y".to_string())));
+    }
+
+    #[test]
+    fn test_unflatten_conflict() {
+        let entries = [("a", "1"), ("a.b", "2")].map(|(k, v)| (k.to_string(), Value::from(v)));
+        assert!(matches!(unflatten("properties", entries), Err(ParameterError::Parse { .. })));
+    }
+}
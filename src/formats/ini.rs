@@ -0,0 +1,79 @@
+use serde_json::Value;
+use crate::{error::ParameterError, Parameters, Result};
+use super::flat::unflatten;
+
+impl Parameters {
+    /// Build `Parameters` from an INI document.
+    ///
+    /// Each `[section]` header prefixes the keys below it, so `[db]` followed
+    /// by `host = x` is stored as `db.host`; the resulting dotted keys are
+    /// unflattened the same way as in `from_properties`, including list
+    /// construction from numeric segments. Lines starting with `;` or `#` are
+    /// comments, and values wrapped in double quotes are unquoted.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The INI document.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Parameters`, or `ParameterError::Parse` for a line that is not a section, entry or comment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    /// use serde_json::json;
+    ///
+    /// let params = Parameters::from_ini("
+    /// [replicas.0]
+    /// host = db-1
+    /// [replicas.1]
+    /// host = \"db-2\"
+    /// ").unwrap();
+    ///
+    /// assert_eq!(params.get::<serde_json::Value>("replicas"), Some(&json!([{ "host": "db-1" }, { "host": "db-2" }])));
+    /// ```
+    pub fn from_ini(text: &str) -> Result<Parameters> {
+        let mut section = String::new();
+        let mut entries = Vec::new();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+                section = name.trim().to_string();
+                continue;
+            }
+            let Some((key, value)) = line.split_once(['=', ':']) else {
+                return Err(ParameterError::Parse {
+                    format: "INI",
+                    message: format!("line {}: expected `key = value`", number + 1),
+                });
+            };
+            let value = value.trim();
+            let value = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')).unwrap_or(value);
+            let key = match section.as_str() {
+                "" => key.trim().to_string(),
+                section => format!("{}.{}", section, key.trim()),
+            };
+            entries.push((key, Value::String(value.to_string())));
+        }
+        unflatten("INI", entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections_and_errors() {
+        let params = Parameters::from_ini("top = 1\n; note\n[db]\nhost: x\nport = 5432\n").unwrap();
+        assert_eq!(params.get_string("top"), Some("1".to_string()));
+        assert_eq!(params.get::<Parameters>("db").unwrap().get_string("port"), Some("5432".to_string()));
+
+        assert!(matches!(Parameters::from_ini("[db]\nbroken"), Err(ParameterError::Parse { format: "INI", .. })));
+    }
+}
@@ -0,0 +1,21 @@
+mod flat;
+mod bundle;
+mod properties;
+mod ini;
+mod file;
+#[cfg(feature = "xml")]
+mod xml;
+#[cfg(feature = "toml")]
+mod toml;
+#[cfg(feature = "yaml")]
+mod yaml;
+#[cfg(any(feature = "msgpack", feature = "bincode"))]
+mod binary;
+
+pub use bundle::Bundle;
+#[cfg(feature = "xml")]
+pub use xml::XmlOptions;
+#[cfg(feature = "toml")]
+pub use toml::TomlEditor;
+#[cfg(feature = "yaml")]
+pub use yaml::YamlEditor;
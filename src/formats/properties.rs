@@ -0,0 +1,200 @@
+use serde_json::Value;
+use crate::{Parameters, Result};
+use super::flat::{flatten, unflatten};
+
+/// Join physical lines into logical ones, following backslash continuations
+/// and dropping blank and comment lines.
+fn logical_lines(text: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current: Option<String> = None;
+    for raw in text.lines() {
+        let line = match current {
+            Some(_) => raw.trim_start(),
+            None => {
+                let trimmed = raw.trim_start();
+                if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with('!') {
+                    continue;
+                }
+                trimmed
+            }
+        };
+        let trailing = line.bytes().rev().take_while(|&b| b == b'\\').count();
+        let continues = trailing % 2 == 1;
+        let content = if continues { &line[..line.len() - 1] } else { line };
+        current.get_or_insert_with(String::new).push_str(content);
+        if !continues {
+            lines.extend(current.take());
+        }
+    }
+    lines.extend(current);
+    lines
+}
+
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('f') => out.push('\u{c}'),
+            Some('u') => {
+                let hex: String = chars.by_ref().take(4).collect();
+                match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                    Some(decoded) => out.push(decoded),
+                    None => {
+                        out.push_str("\\u");
+                        out.push_str(&hex);
+                    }
+                }
+            }
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out
+}
+
+/// Split a logical line at the first unescaped `=`, `:` or whitespace.
+fn split_entry(line: &str) -> (String, String) {
+    let mut escaped = false;
+    let mut end = line.len();
+    for (i, c) in line.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == '=' || c == ':' || c.is_whitespace() {
+            end = i;
+            break;
+        }
+    }
+    let (key, rest) = line.split_at(end);
+    let rest = rest.trim_start();
+    let rest = rest.strip_prefix(['=', ':']).unwrap_or(rest).trim_start();
+    (unescape(key), unescape(rest))
+}
+
+fn escape(text: &str, is_key: bool) -> String {
+    let mut out = String::with_capacity(text.len());
+    for (i, c) in text.chars().enumerate() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{c}' => out.push_str("\\f"),
+            '=' | ':' | '#' | '!' if is_key => {
+                out.push('\\');
+                out.push(c);
+            }
+            ' ' if is_key || i == 0 => out.push_str("\\ "),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+impl Parameters {
+    /// Build `Parameters` from a Java-style `.properties` document.
+    ///
+    /// Dotted keys are unflattened into nested `Parameters`, and a group of
+    /// keys whose next segment is a number, such as `servers.0.host` and
+    /// `servers.1.host`, becomes a list ordered by index. All values are
+    /// stored as strings. Comments (`#`, `!`), `=`/`:`/space separators, line
+    /// continuations and `\uXXXX` escapes follow the `java.util.Properties`
+    /// format.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The properties document.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Parameters`, or `ParameterError::Parse` if a key is used both as a value and as a prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    /// use serde_json::json;
+    ///
+    /// let params = Parameters::from_properties("
+    /// # deployment
+    /// app.name = billing
+    /// servers.0.host = db-1
+    /// servers.1.host = db-2
+    /// ").unwrap();
+    ///
+    /// assert_eq!(params.get::<Parameters>("app").unwrap().get_string("name"), Some("billing".to_string()));
+    /// assert_eq!(params.get::<serde_json::Value>("servers"), Some(&json!([{ "host": "db-1" }, { "host": "db-2" }])));
+    /// ```
+    pub fn from_properties(text: &str) -> Result<Parameters> {
+        let entries = logical_lines(text).into_iter()
+            .map(|line| {
+                let (key, value) = split_entry(&line);
+                (key, Value::String(value))
+            });
+        unflatten("properties", entries)
+    }
+
+    /// Render the `Parameters` as a `.properties` document.
+    ///
+    /// Nested `Parameters` and lists are flattened into dotted keys, lists
+    /// numbered from zero, so the output reads back with `from_properties`.
+    ///
+    /// # Returns
+    ///
+    /// The document, one `key=value` line per leaf value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("db", Parameters::new().with("port", 5432));
+    /// assert_eq!(params.to_properties(), "db.port=5432\n");
+    /// ```
+    pub fn to_properties(&self) -> String {
+        flatten(self).into_iter()
+            .map(|(key, value)| format!("{}={}\n", escape(&key, true), escape(&value, false)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_properties_syntax() {
+        let text = "! comment\n\
+            a=1\n\
+            b : two\n\
+            c three\n\
+            long = first \\\n    second\n\
+            key\\=with\\:sep = \\u00e9\\tx\n\
+            empty\n";
+        let params = Parameters::from_properties(text).unwrap();
+
+        assert_eq!(params.get_string("a"), Some("1".to_string()));
+        assert_eq!(params.get_string("b"), Some("two".to_string()));
+        assert_eq!(params.get_string("c"), Some("three".to_string()));
+        assert_eq!(params.get_string("long"), Some("first second".to_string()));
+        assert_eq!(params.get_string("key=with:sep"), Some("é\tx".to_string()));
+        assert_eq!(params.get_string("empty"), Some(String::new()));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let text = "servers.0.host=a b\nservers.1.host=c\nx\\ y= lead\n";
+        let params = Parameters::from_properties(text).unwrap();
+        assert_eq!(params.to_properties(), "servers.0.host=a b\nservers.1.host=c\nx\\ y=lead\n");
+        assert_eq!(Parameters::from_properties(&params.to_properties()).unwrap(), params);
+    }
+}
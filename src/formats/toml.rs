@@ -0,0 +1,240 @@
+use std::{fmt, fs, path::Path, str::FromStr};
+use serde_json::Value;
+use toml_edit::{Array, DocumentMut, InlineTable, Item, TableLike};
+use crate::{error::ParameterError, parameters::value_to_json, Parameters, Result};
+use super::flat::leaves;
+
+fn parse_error(message: impl ToString) -> ParameterError {
+    ParameterError::Parse {
+        format: "TOML",
+        message: message.to_string(),
+    }
+}
+
+fn item_to_json(item: &Item) -> Value {
+    match item {
+        Item::None => Value::Null,
+        Item::Value(value) => value_to_json_tree(value),
+        Item::Table(table) => table_to_json(table),
+        Item::ArrayOfTables(tables) => Value::Array(tables.iter().map(|table| table_to_json(table)).collect()),
+    }
+}
+
+fn table_to_json(table: &dyn TableLike) -> Value {
+    Value::Object(table.iter()
+        .map(|(key, item)| (key.to_string(), item_to_json(item)))
+        .collect())
+}
+
+fn value_to_json_tree(value: &toml_edit::Value) -> Value {
+    match value {
+        toml_edit::Value::String(s) => Value::String(s.value().clone()),
+        toml_edit::Value::Integer(i) => Value::from(*i.value()),
+        toml_edit::Value::Float(f) => serde_json::Number::from_f64(*f.value()).map_or(Value::Null, Value::Number),
+        toml_edit::Value::Boolean(b) => Value::Bool(*b.value()),
+        toml_edit::Value::Datetime(d) => Value::String(d.value().to_string()),
+        toml_edit::Value::Array(items) => Value::Array(items.iter().map(value_to_json_tree).collect()),
+        toml_edit::Value::InlineTable(table) => table_to_json(table),
+    }
+}
+
+fn json_to_toml(json: &Value) -> Result<toml_edit::Value> {
+    Ok(match json {
+        Value::Null => return Err(ParameterError::TypeMismatch { expected: "TOML value", actual: "null" }),
+        Value::Bool(b) => (*b).into(),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => i.into(),
+            None => n.as_f64().unwrap_or(f64::NAN).into(),
+        },
+        Value::String(s) => s.as_str().into(),
+        Value::Array(items) => {
+            let mut array = Array::new();
+            for item in items {
+                array.push(json_to_toml(item)?);
+            }
+            toml_edit::Value::Array(array)
+        }
+        Value::Object(object) => {
+            let mut table = InlineTable::new();
+            for (key, value) in object {
+                table.insert(key, json_to_toml(value)?);
+            }
+            toml_edit::Value::InlineTable(table)
+        }
+    })
+}
+
+/// A TOML document that can be updated in place without losing comments or layout.
+///
+/// Only the values that are written change; comments, key order, whitespace
+/// and the comment trailing an updated value are kept as they were. Keys are
+/// addressed with dots, as in `db.port`.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{Parameters, TomlEditor};
+///
+/// let mut editor = TomlEditor::parse("[db]\nport = 5432 # primary\nhost = \"db.local\"\n").unwrap();
+///
+/// editor.apply(&Parameters::new().with("db", Parameters::new().with("port", 6432))).unwrap();
+/// assert_eq!(editor.to_string(), "[db]\nport = 6432 # primary\nhost = \"db.local\"\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct TomlEditor {
+    document: DocumentMut,
+}
+
+impl TomlEditor {
+    pub fn parse(text: &str) -> Result<Self> {
+        let document = DocumentMut::from_str(text).map_err(parse_error)?;
+        Ok(Self { document })
+    }
+
+    /// Read and parse a TOML file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Write the edited document to a file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, self.document.to_string())?;
+        Ok(())
+    }
+
+    /// The document's current contents as `Parameters`.
+    pub fn to_parameters(&self) -> Result<Parameters> {
+        Parameters::from_json(table_to_json(self.document.as_table()))
+    }
+
+    /// Set the value at a dotted key, creating missing tables.
+    ///
+    /// Nested `Parameters` are written key by key, so existing tables keep
+    /// their layout. Returns `ParameterError::TypeMismatch` if a prefix of the
+    /// key holds a value rather than a table, or if the value is null.
+    pub fn set(&mut self, key: &str, value: &dyn crate::ParameterValue) -> Result<()> {
+        let segments: Vec<&str> = key.split('.').collect();
+        self.set_json(&segments, &value_to_json(value))
+    }
+
+    /// Write every value of `params` whose counterpart in the document differs.
+    ///
+    /// Keys present in the document but not in `params` are left alone.
+    pub fn apply(&mut self, params: &Parameters) -> Result<()> {
+        for (path, json) in leaves(params) {
+            let segments: Vec<&str> = path.iter().map(String::as_str).collect();
+            if self.get_json(&segments).as_ref() != Some(&json) {
+                self.set_json(&segments, &json)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn get_json(&self, segments: &[&str]) -> Option<Value> {
+        let mut item = self.document.as_item();
+        for segment in segments {
+            item = item.as_table_like()?.get(segment)?;
+        }
+        Some(item_to_json(item))
+    }
+
+    fn set_json(&mut self, segments: &[&str], json: &Value) -> Result<()> {
+        if let Value::Object(object) = json {
+            if !object.is_empty() {
+                for (key, value) in object {
+                    let mut path = segments.to_vec();
+                    path.push(key);
+                    self.set_json(&path, value)?;
+                }
+                return Ok(());
+            }
+        }
+
+        let Some((last, parents)) = segments.split_last() else {
+            return Err(ParameterError::TypeMismatch { expected: "key", actual: "empty key" });
+        };
+        let mut table: &mut dyn TableLike = self.document.as_table_mut();
+        for segment in parents {
+            table = table.entry(segment)
+                .or_insert_with(toml_edit::table)
+                .as_table_like_mut()
+                .ok_or(ParameterError::TypeMismatch { expected: "table", actual: "value" })?;
+        }
+
+        let mut value = json_to_toml(json)?;
+        match table.get_mut(last) {
+            Some(item) => {
+                if let Some(existing) = item.as_value() {
+                    *value.decor_mut() = existing.decor().clone();
+                }
+                *item = Item::Value(value);
+            }
+            None => {
+                table.insert(last, Item::Value(value));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for TomlEditor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.document)
+    }
+}
+
+impl Parameters {
+    /// Build `Parameters` from a TOML document.
+    ///
+    /// Tables become nested `Parameters`, strings, integers, floats and bools
+    /// are stored as `String`, `i64`, `f64` and `bool`, datetimes as their
+    /// string form, and arrays as `serde_json::Value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The TOML document.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Parameters`, or `ParameterError::Parse` if the document is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::from_toml("[server]\nport = 8080").unwrap();
+    /// assert_eq!(params.get::<Parameters>("server").unwrap().get::<i64>("port"), Some(&8080));
+    /// ```
+    pub fn from_toml(text: &str) -> Result<Parameters> {
+        TomlEditor::parse(text)?.to_parameters()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_creates_tables_and_keeps_comments() {
+        let mut editor = TomlEditor::parse("title = \"x\" # name\n\n[db]\n# pool settings\npool = 4\n").unwrap();
+        editor.set("title", &"y").unwrap();
+        editor.set("db.pool", &8).unwrap();
+        editor.set("cache.ttl", &30).unwrap();
+
+        assert_eq!(format!("{}", editor), "title = \"y\" # name\n\n[db]\n# pool settings\npool = 8\n\n[cache]\nttl = 30\n");
+        assert!(matches!(editor.set("title.sub", &1), Err(ParameterError::TypeMismatch { .. })));
+    }
+
+    #[test]
+    fn test_unchanged_values_are_not_rewritten() {
+        let text = "port = 0x1F90\nhosts = [ \"a\",   \"b\" ]\n";
+        let mut editor = TomlEditor::parse(text).unwrap();
+        let params = editor.to_parameters().unwrap();
+        editor.apply(&params).unwrap();
+        assert_eq!(format!("{}", editor), text);
+
+        editor.apply(&Parameters::new().with("port", 9090)).unwrap();
+        assert_eq!(format!("{}", editor), "port = 9090\nhosts = [ \"a\",   \"b\" ]\n");
+    }
+}
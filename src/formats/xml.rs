@@ -0,0 +1,279 @@
+use std::collections::BTreeSet;
+use quick_xml::{events::{BytesStart, Event}, Reader};
+use serde_json::{Map, Value};
+use crate::{error::ParameterError, Parameters, Result};
+
+fn parse_error(message: impl ToString) -> ParameterError {
+    ParameterError::Parse {
+        format: "XML",
+        message: message.to_string(),
+    }
+}
+
+/// Conventions for mapping an XML document onto `Parameters`.
+///
+/// By default attributes become keys prefixed with `@`, the text of an
+/// element that also has attributes or children is stored under `#text`,
+/// repeated sibling elements become lists, and all text stays a string.
+#[derive(Debug, Clone)]
+pub struct XmlOptions {
+    attribute_prefix: String,
+    text_key: String,
+    list_elements: BTreeSet<String>,
+    infer_types: bool,
+}
+
+impl Default for XmlOptions {
+    fn default() -> Self {
+        Self {
+            attribute_prefix: "@".to_string(),
+            text_key: "#text".to_string(),
+            list_elements: BTreeSet::new(),
+            infer_types: false,
+        }
+    }
+}
+
+impl XmlOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prefix added to attribute names to keep them apart from child elements.
+    pub fn attribute_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.attribute_prefix = prefix.into();
+        self
+    }
+
+    /// Key holding the text of elements that also have attributes or children.
+    pub fn text_key(mut self, key: impl Into<String>) -> Self {
+        self.text_key = key.into();
+        self
+    }
+
+    /// Always read elements with this name as a list, even when they appear only once.
+    pub fn list_element(mut self, name: impl Into<String>) -> Self {
+        self.list_elements.insert(name.into());
+        self
+    }
+
+    /// Store text that looks like a bool or number as `bool`, `i64` or `f64` instead of `String`.
+    pub fn infer_types(mut self, infer: bool) -> Self {
+        self.infer_types = infer;
+        self
+    }
+
+    fn scalar(&self, text: String) -> Value {
+        if !self.infer_types {
+            return Value::String(text);
+        }
+        match text.as_str() {
+            "true" => Value::Bool(true),
+            "false" => Value::Bool(false),
+            _ => text.parse::<i64>().map(Value::from)
+                .ok()
+                .or_else(|| text.parse::<f64>().ok().and_then(serde_json::Number::from_f64).map(Value::Number))
+                .unwrap_or(Value::String(text)),
+        }
+    }
+}
+
+/// An element whose end tag has not been read yet.
+#[derive(Default)]
+struct Element {
+    name: String,
+    members: Map<String, Value>,
+    children: Vec<(String, Value)>,
+    text: String,
+}
+
+impl Element {
+    fn open(start: &BytesStart<'_>, options: &XmlOptions) -> Result<Self> {
+        let mut element = Element {
+            name: String::from_utf8_lossy(start.name().as_ref()).into_owned(),
+            ..Element::default()
+        };
+        for attribute in start.attributes() {
+            let attribute = attribute.map_err(parse_error)?;
+            let key = format!("{}{}", options.attribute_prefix, String::from_utf8_lossy(attribute.key.as_ref()));
+            let value = attribute.unescape_value().map_err(parse_error)?.into_owned();
+            element.members.insert(key, options.scalar(value));
+        }
+        Ok(element)
+    }
+
+    fn close(self, options: &XmlOptions) -> Value {
+        let text = self.text.trim().to_string();
+        if self.members.is_empty() && self.children.is_empty() {
+            return options.scalar(text);
+        }
+
+        let mut members = self.members;
+        for (name, value) in self.children {
+            let as_list = options.list_elements.contains(&name);
+            match members.get_mut(&name) {
+                Some(Value::Array(items)) if as_list || items.len() > 1 => items.push(value),
+                Some(existing) => {
+                    let first = existing.take();
+                    *existing = Value::Array(vec![first, value]);
+                }
+                None if as_list => {
+                    members.insert(name, Value::Array(vec![value]));
+                }
+                None => {
+                    members.insert(name, value);
+                }
+            }
+        }
+        if !text.is_empty() {
+            members.insert(options.text_key.clone(), options.scalar(text));
+        }
+        Value::Object(members)
+    }
+}
+
+impl Parameters {
+    /// Build `Parameters` from an XML document using the default `XmlOptions`.
+    ///
+    /// The root element's attributes and children become the top-level keys;
+    /// the root element's own name is not kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `xml` - The XML document.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Parameters`, or `ParameterError::Parse` if the document is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::from_xml(r#"
+    ///     <service name="billing">
+    ///         <port>8080</port>
+    ///         <replica>db-1</replica>
+    ///         <replica>db-2</replica>
+    ///     </service>
+    /// "#).unwrap();
+    ///
+    /// assert_eq!(params.get_string("@name"), Some("billing".to_string()));
+    /// assert_eq!(params.get_string("port"), Some("8080".to_string()));
+    /// assert_eq!(params.get_pointer("/replica/1").unwrap().to_string(), r#""db-2""#);
+    /// ```
+    pub fn from_xml(xml: &str) -> Result<Parameters> {
+        Self::from_xml_with(xml, &XmlOptions::default())
+    }
+
+    /// Build `Parameters` from an XML document with custom conventions.
+    ///
+    /// # Arguments
+    ///
+    /// * `xml` - The XML document.
+    /// * `options` - How attributes, text and repeated elements are mapped.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Parameters`, or `ParameterError::Parse` if the document is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{Parameters, XmlOptions};
+    ///
+    /// let options = XmlOptions::new().list_element("host").infer_types(true);
+    /// let params = Parameters::from_xml_with("<cfg><host>a</host><retries>3</retries></cfg>", &options).unwrap();
+    ///
+    /// assert_eq!(params.get::<i64>("retries"), Some(&3));
+    /// assert!(params.get::<serde_json::Value>("host").unwrap().is_array());
+    /// ```
+    pub fn from_xml_with(xml: &str, options: &XmlOptions) -> Result<Parameters> {
+        let mut reader = Reader::from_str(xml);
+        let mut stack: Vec<Element> = Vec::new();
+        let mut root = None;
+
+        loop {
+            let closed = match reader.read_event().map_err(parse_error)? {
+                Event::Start(start) => {
+                    stack.push(Element::open(&start, options)?);
+                    None
+                }
+                Event::Empty(start) => Some(Element::open(&start, options)?),
+                Event::End(_) => stack.pop(),
+                Event::Text(text) => {
+                    if let Some(element) = stack.last_mut() {
+                        element.text.push_str(&text.unescape().map_err(parse_error)?);
+                    }
+                    None
+                }
+                Event::CData(data) => {
+                    if let Some(element) = stack.last_mut() {
+                        element.text.push_str(&String::from_utf8_lossy(&data));
+                    }
+                    None
+                }
+                Event::Eof => break,
+                _ => None,
+            };
+
+            if let Some(element) = closed {
+                let name = element.name.clone();
+                let value = element.close(options);
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push((name, value)),
+                    None if root.is_none() => root = Some(value),
+                    None => return Err(parse_error("document has more than one root element")),
+                }
+            }
+        }
+
+        if !stack.is_empty() {
+            return Err(parse_error("unexpected end of document"));
+        }
+        match root {
+            Some(Value::Object(members)) => Parameters::from_json(Value::Object(members)),
+            Some(Value::String(text)) if text.is_empty() => Ok(Parameters::new()),
+            Some(text) => Parameters::from_json(Value::Object(Map::from_iter([(options.text_key.clone(), text)]))),
+            None => Err(parse_error("document has no root element")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn test_attributes_text_and_nesting() {
+        let xml = r#"<config env="prod">
+            <db pool="4">primary<host>db.local</host></db>
+            <feature enabled="true"/>
+            <note><![CDATA[a < b]]></note>
+        </config>"#;
+        let params = Parameters::from_xml_with(xml, &XmlOptions::new().infer_types(true)).unwrap();
+
+        let db = params.get::<Parameters>("db").unwrap();
+        assert_eq!(db.get::<i64>("@pool"), Some(&4));
+        assert_eq!(db.get::<String>("#text"), Some(&"primary".to_string()));
+        assert_eq!(db.get::<String>("host"), Some(&"db.local".to_string()));
+        assert_eq!(params.get::<Parameters>("feature").unwrap().get::<bool>("@enabled"), Some(&true));
+        assert_eq!(params.get::<String>("note"), Some(&"a < b".to_string()));
+        assert_eq!(params.get::<String>("@env"), Some(&"prod".to_string()));
+    }
+
+    #[test]
+    fn test_repeated_elements_become_lists() {
+        let xml = "<c><server><host>a</host></server><server><host>b</host></server><server><host>c</host></server></c>";
+        let params = Parameters::from_xml(xml).unwrap();
+        assert_eq!(
+            params.get::<Value>("server"),
+            Some(&json!([{ "host": "a" }, { "host": "b" }, { "host": "c" }])),
+        );
+
+        assert!(matches!(Parameters::from_xml("<a><b></a>"), Err(ParameterError::Parse { .. })));
+        assert!(matches!(Parameters::from_xml("<a/><b/>"), Err(ParameterError::Parse { .. })));
+    }
+}
@@ -0,0 +1,403 @@
+use std::{fmt, fs, path::Path};
+use serde_json::{Map, Value};
+use crate::{error::ParameterError, parameters::value_to_json, Parameters, Result};
+use super::flat::leaves;
+
+fn parse_error(message: impl ToString) -> ParameterError {
+    ParameterError::Parse {
+        format: "YAML",
+        message: message.to_string(),
+    }
+}
+
+/// Where the scalar of a `key: value` line sits in the document.
+#[derive(Debug, Clone)]
+struct Scalar {
+    path: Vec<String>,
+    line: usize,
+    start: usize,
+    end: usize,
+}
+
+/// The byte offset where a trailing ` # comment` starts, ignoring `#` inside quotes.
+fn comment_start(text: &str) -> usize {
+    let mut quote = None;
+    let mut previous = ' ';
+    for (i, c) in text.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '#' && previous.is_whitespace() => return i,
+            None => {}
+        }
+        previous = c;
+    }
+    text.len()
+}
+
+fn unquote_key(key: &str) -> String {
+    let key = key.trim();
+    if key.len() >= 2 && ((key.starts_with('"') && key.ends_with('"')) || (key.starts_with('\'') && key.ends_with('\''))) {
+        key[1..key.len() - 1].to_string()
+    } else {
+        key.to_string()
+    }
+}
+
+/// Split `key: rest` at the first `:` followed by whitespace or the end of line.
+fn split_key(content: &str) -> Option<(&str, usize)> {
+    let bytes = content.as_bytes();
+    let mut quote = None;
+    for (i, &b) in bytes.iter().enumerate() {
+        match quote {
+            Some(q) if b == q => quote = None,
+            Some(_) => {}
+            None if b == b'"' || b == b'\'' => quote = Some(b),
+            None if b == b':' && bytes.get(i + 1).is_none_or(|next| next.is_ascii_whitespace()) => {
+                return Some((&content[..i], i + 1));
+            }
+            None => {}
+        }
+    }
+    None
+}
+
+/// Read a plain or quoted scalar the way a YAML loader would.
+fn scalar_to_json(text: &str) -> Value {
+    if text.starts_with('"') {
+        return serde_json::from_str(text).unwrap_or_else(|_| Value::String(text.to_string()));
+    }
+    if let Some(inner) = text.strip_prefix('\'').and_then(|t| t.strip_suffix('\'')) {
+        return Value::String(inner.replace("''", "'"));
+    }
+    match text {
+        "" | "~" | "null" => Value::Null,
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => serde_json::from_str::<serde_json::Number>(text)
+            .map(Value::Number)
+            .unwrap_or_else(|_| Value::String(text.to_string())),
+    }
+}
+
+/// Render a value as an inline YAML scalar, quoting strings only when needed.
+fn render(json: &Value) -> String {
+    match json {
+        Value::String(s) => {
+            let plain = !s.is_empty()
+                && s.chars().all(|c| c.is_alphanumeric() || "_-./@".contains(c))
+                && matches!(scalar_to_json(s), Value::String(_));
+            if plain {
+                s.clone()
+            } else {
+                json.to_string()
+            }
+        }
+        other => other.to_string(),
+    }
+}
+
+/// A YAML document whose scalar values can be updated without touching comments or layout.
+///
+/// Only existing `key: value` entries of block mappings can be edited; the
+/// rest of each line, including a trailing comment, is kept byte for byte.
+/// Block scalars (`|`, `>`) and values inside sequences are not addressable.
+/// Keys are addressed with dots, as in `db.port`.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{Parameters, YamlEditor};
+///
+/// let mut editor = YamlEditor::parse("db:\n  # primary database\n  port: 5432  # default\n  host: db.local\n").unwrap();
+///
+/// editor.apply(&Parameters::new().with("db", Parameters::new().with("port", 6432))).unwrap();
+/// assert_eq!(editor.to_string(), "db:\n  # primary database\n  port: 6432  # default\n  host: db.local\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct YamlEditor {
+    lines: Vec<String>,
+    trailing_newline: bool,
+    scalars: Vec<Scalar>,
+    /// The first construct outside the supported subset, for `from_yaml` to reject.
+    unsupported: Option<String>,
+}
+
+impl YamlEditor {
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut editor = Self {
+            lines: text.lines().map(str::to_string).collect(),
+            trailing_newline: text.ends_with('\n'),
+            scalars: Vec::new(),
+            unsupported: None,
+        };
+        editor.index()?;
+        Ok(editor)
+    }
+
+    /// Read and parse a YAML file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Write the edited document to a file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, format!("{}", self))?;
+        Ok(())
+    }
+
+    /// The document's current scalar values as `Parameters`, nested by mapping.
+    ///
+    /// Only what the editor can address is read: entries inside sequences and
+    /// block scalars are left out.
+    pub fn to_parameters(&self) -> Result<Parameters> {
+        let mut root = Map::new();
+        for scalar in &self.scalars {
+            let (last, parents) = scalar.path.split_last().expect("a scalar has a key");
+            let mut object = &mut root;
+            for parent in parents {
+                let entry = object.entry(parent.clone()).or_insert_with(|| Value::Object(Map::new()));
+                object = entry.as_object_mut().expect("mapping keys hold no scalar");
+            }
+            object.insert(last.clone(), scalar_to_json(self.current_scalar(scalar)));
+        }
+        Parameters::from_json(Value::Object(root))
+    }
+
+    /// Replace the scalar at a dotted key.
+    ///
+    /// Returns `ParameterError::KeyNotFound` if the document has no editable
+    /// scalar at that key.
+    pub fn set(&mut self, key: &str, value: &dyn crate::ParameterValue) -> Result<()> {
+        let path: Vec<String> = key.split('.').map(str::to_string).collect();
+        let index = self.find(&path).ok_or_else(|| ParameterError::key_not_found(key))?;
+        self.replace(index, &value_to_json(value));
+        Ok(())
+    }
+
+    /// Write every value of `params` whose counterpart in the document differs.
+    ///
+    /// Fails without editing anything if a key of `params` has no editable
+    /// scalar in the document.
+    pub fn apply(&mut self, params: &Parameters) -> Result<()> {
+        let mut edits = Vec::new();
+        for (path, json) in leaves(params) {
+            let index = self.find(&path).ok_or_else(|| ParameterError::key_not_found(path.join(".")))?;
+            if scalar_to_json(self.current(index)) != json {
+                edits.push((index, json));
+            }
+        }
+        for (index, json) in edits {
+            self.replace(index, &json);
+        }
+        Ok(())
+    }
+
+    fn find(&self, path: &[String]) -> Option<usize> {
+        self.scalars.iter().position(|scalar| scalar.path == path)
+    }
+
+    fn current(&self, index: usize) -> &str {
+        self.current_scalar(&self.scalars[index])
+    }
+
+    fn current_scalar(&self, scalar: &Scalar) -> &str {
+        &self.lines[scalar.line][scalar.start..scalar.end]
+    }
+
+    fn replace(&mut self, index: usize, json: &Value) {
+        let Scalar { line, start, end, .. } = self.scalars[index].clone();
+        let rendered = render(json);
+        let padded = if start == end && !self.lines[line][..start].ends_with(' ') {
+            format!(" {rendered}")
+        } else {
+            rendered
+        };
+        self.lines[line].replace_range(start..end, &padded);
+        self.index().expect("editing a scalar keeps the document valid");
+    }
+
+    /// Locate every `key: scalar` entry, tracking the mapping path by indentation.
+    fn index(&mut self) -> Result<()> {
+        let mut parents: Vec<(usize, String)> = Vec::new();
+        let mut scalars = Vec::new();
+        let mut block_indent: Option<usize> = None;
+        let mut unsupported = None;
+        let mut note = |number: usize, what: &str| {
+            unsupported.get_or_insert_with(|| format!("line {}: {} are not supported", number + 1, what));
+        };
+
+        for (number, line) in self.lines.iter().enumerate() {
+            let content = line.trim_start();
+            let indent = line.len() - content.len();
+            if content.is_empty() || content.starts_with('#') || content == "---" || content == "..." {
+                continue;
+            }
+            if let Some(block) = block_indent {
+                if indent > block {
+                    continue;
+                }
+                block_indent = None;
+            }
+            if line[..indent].contains('\t') {
+                return Err(parse_error(format!("line {}: tabs are not allowed in indentation", number + 1)));
+            }
+            while parents.last().is_some_and(|(parent, _)| *parent >= indent) {
+                parents.pop();
+            }
+            if content.starts_with("- ") || content == "-" {
+                note(number, "sequences");
+                block_indent = Some(indent);
+                continue;
+            }
+            let Some((key, after)) = split_key(content) else {
+                note(number, "entries other than `key: value`");
+                continue;
+            };
+            if key.starts_with(['?', '&', '*', '!', '[', '{']) {
+                note(number, "complex, anchored or tagged keys");
+            }
+
+            let rest = &content[after..];
+            let value_offset = indent + after + (rest.len() - rest.trim_start().len());
+            let value = &line[value_offset..];
+            let value = value[..comment_start(value)].trim_end();
+            let mut path: Vec<String> = parents.iter().map(|(_, key)| key.clone()).collect();
+            path.push(unquote_key(key));
+
+            if value.is_empty() {
+                parents.push((indent, unquote_key(key)));
+            } else if value.starts_with('|') || value.starts_with('>') {
+                note(number, "block scalars");
+                block_indent = Some(indent);
+            } else {
+                if value.starts_with(['[', '{']) {
+                    note(number, "flow collections");
+                } else if value.starts_with(['&', '*']) {
+                    note(number, "anchors and aliases");
+                } else if value.starts_with('!') {
+                    note(number, "tags");
+                }
+                scalars.push(Scalar { path, line: number, start: value_offset, end: value_offset + value.len() });
+            }
+        }
+        self.scalars = scalars;
+        self.unsupported = unsupported;
+        Ok(())
+    }
+}
+
+impl Parameters {
+    /// Parse the block mappings of a YAML document into nested `Parameters`.
+    ///
+    /// This reads the same subset `YamlEditor` edits: `key: value` entries of
+    /// nested mappings with plain or quoted scalars. Any other construct, such
+    /// as a sequence, a block scalar, a flow collection, an anchor or a tag, is
+    /// rejected rather than skipped, so a document never loads partially.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The YAML document.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the parsed `Parameters`, or `ParameterError::Parse` naming the line if the
+    /// document is malformed or uses an unsupported construct.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::from_yaml("server:\n  port: 8080  # http\n  host: 'a.local'\n").unwrap();
+    /// let server = params.get::<Parameters>("server").unwrap();
+    /// assert_eq!(server.get::<i64>("port"), Some(&8080));
+    /// assert_eq!(server.get::<String>("host").map(String::as_str), Some("a.local"));
+    ///
+    /// assert!(Parameters::from_yaml("tags:\n  - a\n").is_err());
+    /// ```
+    pub fn from_yaml(text: &str) -> Result<Parameters> {
+        let editor = YamlEditor::parse(text)?;
+        if let Some(message) = &editor.unsupported {
+            return Err(parse_error(message));
+        }
+        editor.to_parameters()
+    }
+}
+
+impl fmt::Display for YamlEditor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.lines.join("\n"))?;
+        if self.trailing_newline {
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edits_keep_layout() {
+        let text = "\
+name: \"svc\" # quoted
+limits:
+  cpu: 2
+  notes: |
+    cpu: not a key
+  'mem gb': 4
+tags:
+  - cpu: 1
+retries: 3";
+        let mut editor = YamlEditor::parse(text).unwrap();
+        editor.set("limits.mem gb", &8).unwrap();
+        editor.set("name", &"a: b").unwrap();
+        editor.set("retries", &"5").unwrap();
+
+        assert_eq!(format!("{}", editor), "\
+name: \"a: b\" # quoted
+limits:
+  cpu: 2
+  notes: |
+    cpu: not a key
+  'mem gb': 8
+tags:
+  - cpu: 1
+retries: \"5\"");
+        assert!(matches!(editor.set("limits.notes.cpu", &1), Err(ParameterError::KeyNotFound { .. })));
+        assert!(matches!(editor.set("tags.cpu", &1), Err(ParameterError::KeyNotFound { .. })));
+    }
+
+    #[test]
+    fn test_apply_is_atomic_and_skips_equal_values() {
+        let text = "a: 'x'\nb: 1.50\n";
+        let mut editor = YamlEditor::parse(text).unwrap();
+        editor.apply(&Parameters::new().with("a", "x").with("b", 1.5)).unwrap();
+        assert_eq!(format!("{}", editor), text);
+
+        let result = editor.apply(&Parameters::new().with("a", "y").with("missing", 1));
+        assert!(matches!(result, Err(ParameterError::KeyNotFound { key, .. }) if key == "missing"));
+        assert_eq!(format!("{}", editor), text);
+    }
+
+    #[test]
+    fn test_from_yaml_rejects_unsupported_constructs() {
+        for (text, message) in [
+            ("a: 1\ntags:\n  - a\n", "line 3: sequences are not supported"),
+            ("notes: |\n  text\n", "line 1: block scalars are not supported"),
+            ("x: {y: 1}\n", "line 1: flow collections are not supported"),
+            ("a: &base 5\n", "line 1: anchors and aliases are not supported"),
+            ("a: !!str 5\n", "line 1: tags are not supported"),
+            ("a: one\n  two\n", "line 2: entries other than `key: value` are not supported"),
+        ] {
+            match Parameters::from_yaml(text) {
+                Err(ParameterError::Parse { format: "YAML", message: got }) => assert_eq!(got, message),
+                other => panic!("unexpected result for {:?}: {:?}", text, other),
+            }
+        }
+        assert!(YamlEditor::parse("tags:\n  - a\n").is_ok());
+    }
+}
@@ -0,0 +1,121 @@
+use std::{future::{ready, Future, Ready}, pin::Pin};
+use actix_web::{
+    dev::Payload,
+    error::{ErrorBadRequest, ErrorUnprocessableEntity},
+    http::header::CONTENT_TYPE,
+    web::{Form, Json, Query},
+    Error, FromRequest, HttpRequest,
+};
+use serde_json::Value;
+use crate::Parameters;
+use super::request::RequestParameters;
+
+fn url_parameters(req: &HttpRequest) -> Result<Parameters, Error> {
+    let Query(query) = Query::<Vec<(String, String)>>::from_query(req.query_string()).map_err(ErrorBadRequest)?;
+    let mut params = Parameters::new();
+    params.extend(query);
+    params.extend(req.match_info().iter().map(|(key, value)| (key.to_string(), value.to_string())));
+    Ok(params)
+}
+
+/// The path parameters and query string values of a request, all stored as `String`s.
+///
+/// Path parameters override query parameters with the same name, and a
+/// repeated query parameter keeps its last value.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actix_web::{web, App};
+/// use parameterx::Parameters;
+///
+/// async fn show(params: Parameters) -> String {
+///     format!("user {} page {}", params.get_str("id").unwrap_or("?"), params.get_i64("page").unwrap_or(1))
+/// }
+///
+/// let app = App::new().route("/users/{id}", web::get().to(show));
+/// ```
+impl FromRequest for Parameters {
+    type Error = Error;
+    type Future = Ready<Result<Self, Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(url_parameters(req))
+    }
+}
+
+/// The path parameters, query string and body of a request.
+///
+/// JSON object bodies are converted as in `Parameters::from_json`, so their
+/// values keep their JSON types; form bodies are stored as `String`s. Other
+/// content types leave the body unread.
+impl FromRequest for RequestParameters {
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let from_url = url_parameters(req);
+        let content_type = req.headers().get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        let body: Pin<Box<dyn Future<Output = Result<Parameters, Error>>>> = if content_type.starts_with("application/json") {
+            let json = Json::<Value>::from_request(req, payload);
+            Box::pin(async move {
+                Parameters::from_json(json.await?.into_inner()).map_err(ErrorUnprocessableEntity)
+            })
+        } else if content_type.starts_with("application/x-www-form-urlencoded") {
+            let form = Form::<Vec<(String, String)>>::from_request(req, payload);
+            Box::pin(async move { Ok(form.await?.into_inner().into_iter().collect()) })
+        } else {
+            Box::pin(ready(Ok(Parameters::new())))
+        };
+
+        Box::pin(async move {
+            let from_url = from_url?;
+            let mut params = body.await?;
+            params.extend(from_url);
+            Ok(RequestParameters(params))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test::TestRequest};
+    use super::*;
+
+    fn rendered(params: &Parameters) -> String {
+        let mut pairs: Vec<_> = params.iter().map(|(key, value)| format!("{}={}", key, value.to_string())).collect();
+        pairs.sort();
+        pairs.join("&")
+    }
+
+    #[tokio::test]
+    async fn test_path_overrides_query_and_body() {
+        let (req, mut payload) = TestRequest::post()
+            .uri("/items/7?id=0&page=2")
+            .param("id", "7")
+            .insert_header((CONTENT_TYPE, "application/json"))
+            .set_payload(r#"{"page": 1, "qty": 3}"#)
+            .to_http_parts();
+        let params = RequestParameters::from_request(&req, &mut payload).await.unwrap();
+        assert_eq!(rendered(&params), "id=7&page=2&qty=3");
+
+        let (req, mut payload) = TestRequest::post()
+            .uri("/items?x=1")
+            .insert_header((CONTENT_TYPE, "application/x-www-form-urlencoded"))
+            .set_payload("note=a+b")
+            .to_http_parts();
+        assert_eq!(rendered(&Parameters::from_request(&req, &mut Payload::None).await.unwrap()), "x=1");
+        assert_eq!(rendered(&RequestParameters::from_request(&req, &mut payload).await.unwrap()), "note=a b&x=1");
+
+        let (req, mut payload) = TestRequest::post()
+            .insert_header((CONTENT_TYPE, "application/json"))
+            .set_payload("[1]")
+            .to_http_parts();
+        let error = RequestParameters::from_request(&req, &mut payload).await.unwrap_err();
+        assert_eq!(error.as_response_error().status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
@@ -0,0 +1,131 @@
+use axum::{
+    extract::{FromRequest, FromRequestParts, Query, RawPathParams, Request},
+    http::{header::CONTENT_TYPE, request::Parts},
+    response::{IntoResponse, Response},
+    Form, Json,
+};
+use axum::extract::rejection::RawPathParamsRejection;
+use serde_json::Value;
+use crate::Parameters;
+use super::request::RequestParameters;
+
+/// The path parameters and query string values of a request, all stored as `String`s.
+///
+/// Path parameters override query parameters with the same name, and a
+/// repeated query parameter keeps its last value. Outside a router, where
+/// there are no path parameters, only the query string is read.
+///
+/// # Examples
+///
+/// ```no_run
+/// use axum::{routing::get, Router};
+/// use parameterx::Parameters;
+///
+/// async fn show(params: Parameters) -> String {
+///     format!("user {} page {}", params.get_str("id").unwrap_or("?"), params.get_i64("page").unwrap_or(1))
+/// }
+///
+/// let app: Router = Router::new().route("/users/{id}", get(show));
+/// ```
+impl<S: Send + Sync> FromRequestParts<S> for Parameters {
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(query) = Query::<Vec<(String, String)>>::from_request_parts(parts, state).await
+            .map_err(IntoResponse::into_response)?;
+        let path = match RawPathParams::from_request_parts(parts, state).await {
+            Ok(path) => path.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect(),
+            Err(RawPathParamsRejection::MissingPathParams(_)) => Vec::new(),
+            Err(rejection) => return Err(rejection.into_response()),
+        };
+
+        let mut params = Parameters::new();
+        params.extend(query);
+        params.extend(path);
+        Ok(params)
+    }
+}
+
+/// The path parameters, query string and body of a request.
+///
+/// JSON object bodies are converted as in `Parameters::from_json`, so their
+/// values keep their JSON types; form bodies are stored as `String`s. Other
+/// content types leave the body unread.
+///
+/// # Examples
+///
+/// ```no_run
+/// use axum::{routing::post, Router};
+/// use parameterx::RequestParameters;
+///
+/// async fn create(params: RequestParameters) -> String {
+///     format!("{} items", params.len())
+/// }
+///
+/// let app: Router = Router::new().route("/items", post(create));
+/// ```
+impl<S: Send + Sync> FromRequest<S> for RequestParameters {
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let content_type = req.headers().get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let (mut parts, body) = req.into_parts();
+        let from_url = Parameters::from_request_parts(&mut parts, state).await?;
+        let req = Request::from_parts(parts, body);
+
+        let mut params = if content_type.starts_with("application/json") {
+            let Json(json) = Json::<Value>::from_request(req, state).await.map_err(IntoResponse::into_response)?;
+            Parameters::from_json(json).map_err(|e| (axum::http::StatusCode::UNPROCESSABLE_ENTITY, e.to_string()).into_response())?
+        } else if content_type.starts_with("application/x-www-form-urlencoded") {
+            let Form(fields) = Form::<Vec<(String, String)>>::from_request(req, state).await.map_err(IntoResponse::into_response)?;
+            fields.into_iter().collect()
+        } else {
+            Parameters::new()
+        };
+        params.extend(from_url);
+        Ok(RequestParameters(params))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, http::StatusCode, routing::post, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+    use super::*;
+
+    async fn echo(params: RequestParameters) -> String {
+        let mut keys: Vec<_> = params.iter().map(|(key, value)| format!("{}={}", key, value.to_string())).collect();
+        keys.sort();
+        keys.join("&")
+    }
+
+    async fn call(request: Request) -> (StatusCode, String) {
+        let app = Router::new().route("/items/{id}", post(echo));
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_path_overrides_query_and_body() {
+        let request = Request::post("/items/7?id=0&page=2")
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(r#"{"page": 1, "qty": 3}"#))
+            .unwrap();
+        assert_eq!(call(request).await, (StatusCode::OK, "id=7&page=2&qty=3".to_string()));
+
+        let request = Request::post("/items/8")
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(Body::from("note=a+b"))
+            .unwrap();
+        assert_eq!(call(request).await, (StatusCode::OK, "id=8&note=a b".to_string()));
+
+        let request = Request::post("/items/9").header(CONTENT_TYPE, "application/json").body(Body::from("[1]")).unwrap();
+        assert_eq!(call(request).await.0, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
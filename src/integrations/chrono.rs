@@ -0,0 +1,137 @@
+use std::{any::Any, sync::Arc};
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use serde_json::Value;
+use crate::{value::as_str, ParameterValue, Parameters, Result};
+
+macro_rules! timestamp_value {
+    ($($t:ty => $render:expr),+ $(,)?) => {
+        $(
+            impl ParameterValue for $t {
+                fn to_string(&self) -> String {
+                    let render: fn(&$t) -> String = $render;
+                    render(self)
+                }
+
+                fn type_name(&self) -> &'static str {
+                    std::any::type_name::<Self>()
+                }
+
+                fn clone_arc(&self) -> Arc<dyn ParameterValue> {
+                    Arc::new(*self)
+                }
+
+                fn as_any(&self) -> &dyn Any {
+                    self
+                }
+
+                fn as_any_mut(&mut self) -> &mut dyn Any {
+                    self
+                }
+
+                fn eq_dyn(&self, other: &dyn ParameterValue) -> bool {
+                    other.as_any().downcast_ref::<Self>() == Some(self)
+                }
+
+                fn to_json(&self) -> Result<Value> {
+                    Ok(Value::String(ParameterValue::to_string(self)))
+                }
+            }
+        )+
+    };
+}
+
+timestamp_value! {
+    DateTime<Utc> => |t| t.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+    NaiveDate => |d| d.format("%Y-%m-%d").to_string(),
+}
+
+impl Parameters {
+    /// Get a timestamp, whether stored as a `DateTime<Utc>` or as an RFC 3339 string.
+    ///
+    /// Strings with another UTC offset are converted to UTC. Use
+    /// `try_get_parse::<DateTime<Utc>>` to get the parse error instead of `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// The timestamp, or `None` if the key is missing or the value is not a timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use parameterx::Parameters;
+    ///
+    /// let start = Utc.with_ymd_and_hms(2024, 3, 1, 9, 30, 0).unwrap();
+    /// let params = Parameters::new()
+    ///     .with("start", start)
+    ///     .with("end", "2024-03-01T12:00:00+02:00");
+    ///
+    /// assert_eq!(params.get_string("start").as_deref(), Some("2024-03-01T09:30:00Z"));
+    /// assert_eq!(params.get_datetime("start"), Some(start));
+    /// assert_eq!(params.get_datetime("end"), Some(Utc.with_ymd_and_hms(2024, 3, 1, 10, 0, 0).unwrap()));
+    /// ```
+    pub fn get_datetime(&self, key: &str) -> Option<DateTime<Utc>> {
+        let value = self.lookup(key)?.as_ref();
+        if let Some(timestamp) = value.as_any().downcast_ref::<DateTime<Utc>>() {
+            return Some(*timestamp);
+        }
+        DateTime::parse_from_rfc3339(as_str(value)?.trim()).ok().map(|t| t.with_timezone(&Utc))
+    }
+
+    /// Get a calendar date, whether stored as a `NaiveDate` or as a `YYYY-MM-DD` string.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// The date, or `None` if the key is missing or the value is not a date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("cutoff", "2024-12-31");
+    /// assert_eq!(params.get_date("cutoff"), NaiveDate::from_ymd_opt(2024, 12, 31));
+    /// ```
+    pub fn get_date(&self, key: &str) -> Option<NaiveDate> {
+        let value = self.lookup(key)?.as_ref();
+        if let Some(date) = value.as_any().downcast_ref::<NaiveDate>() {
+            return Some(*date);
+        }
+        as_str(value)?.trim().parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use crate::ParameterError;
+    use super::*;
+
+    #[test]
+    fn test_round_trip_through_json_and_parse() {
+        let start = Utc.with_ymd_and_hms(2024, 3, 1, 9, 30, 0).unwrap();
+        let day = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let params = Parameters::new().with("start", start).with("day", day);
+
+        let json = params.to_json().unwrap();
+        assert_eq!(json["start"], "2024-03-01T09:30:00Z");
+        assert_eq!(json["day"], "2024-03-01");
+
+        let loaded = Parameters::from_json(json).unwrap();
+        assert_eq!(loaded.try_get_parse::<DateTime<Utc>>("start").unwrap(), start);
+        assert_eq!(loaded.try_get_parse::<NaiveDate>("day").unwrap(), day);
+
+        let bad = Parameters::new().with("start", "tomorrow");
+        assert!(matches!(bad.try_get_parse::<DateTime<Utc>>("start"), Err(ParameterError::ConversionFailed { .. })));
+        assert_eq!(bad.get_datetime("start"), None);
+    }
+}
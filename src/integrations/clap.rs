@@ -0,0 +1,120 @@
+use std::{path::PathBuf, sync::Arc};
+use clap::{parser::ValueSource as ArgSource, ArgMatches, Id};
+use crate::{ParameterValue, Parameters};
+
+/// The values of one argument with the type its value parser produced.
+///
+/// A single value is stored as it is and several values as a `Vec`. Types
+/// not listed here are stored as strings.
+fn typed_values(matches: &ArgMatches, id: &str) -> Option<Arc<dyn ParameterValue>> {
+    macro_rules! try_types {
+        ($($ty:ty),*) => {$(
+            if let Ok(values) = matches.try_get_many::<$ty>(id) {
+                let mut values: Vec<$ty> = values?.cloned().collect();
+                return Some(match values.len() {
+                    1 => Arc::new(values.remove(0)),
+                    _ => Arc::new(values),
+                });
+            }
+        )*};
+    }
+    try_types!(String, bool, i64, i32, i16, i8, u64, u32, u16, u8, usize, isize, f64, f32, char, PathBuf);
+
+    let mut values: Vec<String> = matches.get_raw(id)?
+        .map(|value| value.to_string_lossy().into_owned())
+        .collect();
+    Some(match values.len() {
+        1 => Arc::new(values.remove(0)),
+        _ => Arc::new(values),
+    })
+}
+
+/// Every argument of the parsed command line, keyed by its id.
+///
+/// Values keep the type their value parser produced, so `value_parser!(u16)`
+/// gives a `u16` and `ArgAction::SetTrue` a `bool`; an argument given
+/// several values becomes a `Vec`. Values that came from `default_value`
+/// are stored as defaults, so they can be told apart from explicit ones and
+/// give way to values merged in from other sources. Argument groups are skipped. The
+/// chosen subcommand, if any, is stored under `subcommand`, and its own
+/// arguments as nested parameters under its name.
+///
+/// # Examples
+///
+/// ```
+/// use clap::{arg, value_parser, Command};
+/// use parameterx::{Parameters, ValueSource};
+///
+/// let matches = Command::new("export")
+///     .arg(arg!(--format <FORMAT>).default_value("json"))
+///     .arg(arg!(--workers <N>).value_parser(value_parser!(u16)))
+///     .arg(arg!(--verbose))
+///     .get_matches_from(["export", "--workers", "8", "--verbose"]);
+///
+/// let params = Parameters::from(&matches);
+/// assert_eq!(params.get::<u16>("workers"), Some(&8));
+/// assert_eq!(params.get::<bool>("verbose"), Some(&true));
+/// assert_eq!(params.get_or_default::<String>("format").map(String::as_str), Some("json"));
+/// assert_eq!(params.value_source("format"), Some(ValueSource::Default));
+/// ```
+impl From<&ArgMatches> for Parameters {
+    fn from(matches: &ArgMatches) -> Self {
+        let mut params = Parameters::new();
+        for id in matches.ids() {
+            let id = id.as_str();
+            if matches.try_get_one::<Id>(id).is_ok() {
+                continue;
+            }
+            let Some(value) = typed_values(matches, id) else {
+                continue;
+            };
+            match matches.value_source(id) {
+                Some(ArgSource::DefaultValue) => {
+                    params.defaults_mut().insert(id, value);
+                }
+                _ => {
+                    params.insert_arc(id, value);
+                }
+            }
+        }
+        if let Some((name, sub)) = matches.subcommand() {
+            params.insert("subcommand", name.to_string());
+            params.insert(name, Parameters::from(sub));
+        }
+        params
+    }
+}
+
+impl From<ArgMatches> for Parameters {
+    fn from(matches: ArgMatches) -> Self {
+        Parameters::from(&matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::{Arg, ArgAction, ArgGroup, Command};
+    use super::*;
+
+    #[test]
+    fn test_lists_groups_and_subcommands() {
+        let matches = Command::new("tool")
+            .arg(Arg::new("tag").long("tag").action(ArgAction::Append))
+            .arg(Arg::new("level").short('v').action(ArgAction::Count))
+            .arg(Arg::new("json").long("json").action(ArgAction::SetTrue))
+            .group(ArgGroup::new("output").arg("json"))
+            .subcommand(Command::new("run").arg(Arg::new("target").required(true)))
+            .get_matches_from(["tool", "--tag", "a", "--tag", "b", "-vv", "run", "web"]);
+
+        let params = Parameters::from(matches);
+        assert_eq!(params.get::<Vec<String>>("tag"), Some(&vec!["a".to_string(), "b".to_string()]));
+        assert_eq!(params.get::<u8>("level"), Some(&2));
+        assert_eq!(params.get_or_default::<bool>("json"), Some(&false));
+        assert!(!params.contains_key("json"));
+        assert!(!params.contains_key("output"));
+        assert_eq!(params.get_str("subcommand"), Some("run"));
+
+        let run = params.get::<Parameters>("run").unwrap();
+        assert_eq!(run.get_str("target"), Some("web"));
+    }
+}
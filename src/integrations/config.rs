@@ -0,0 +1,88 @@
+use config::{Config, ConfigError, Map, Source, Value};
+use crate::{ParameterError, Parameters};
+
+/// Every value of a built `Config`, with nested tables as nested parameters.
+///
+/// Values keep the types the config sources gave them; environment variables,
+/// for example, arrive as strings.
+///
+/// # Examples
+///
+/// ```
+/// use config::Config;
+/// use parameterx::Parameters;
+///
+/// let config = Config::builder()
+///     .set_default("workers", 4).unwrap()
+///     .set_override("db.host", "localhost").unwrap()
+///     .build()
+///     .unwrap();
+///
+/// let params = Parameters::try_from(config).unwrap();
+/// assert_eq!(params.get_i64("workers"), Some(4));
+/// assert_eq!(params.get::<Parameters>("db").unwrap().get_str("host"), Some("localhost"));
+/// ```
+impl TryFrom<Config> for Parameters {
+    type Error = ParameterError;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        let json = config.try_deserialize::<serde_json::Value>().map_err(|error| ParameterError::Parse {
+            format: "config",
+            message: error.to_string(),
+        })?;
+        Parameters::from_json(json)
+    }
+}
+
+/// Lets a `Parameters` set be layered into a `config::ConfigBuilder` like any other source.
+///
+/// The explicit values are used unredacted, and dotted keys such as
+/// `db.port` are set as paths, as `config` does for its own sources.
+///
+/// # Examples
+///
+/// ```
+/// use config::Config;
+/// use parameterx::Parameters;
+///
+/// let overrides = Parameters::new().with("db.port", 6432).with("debug", true);
+///
+/// let config = Config::builder()
+///     .set_default("db.port", 5432).unwrap()
+///     .add_source(overrides)
+///     .build()
+///     .unwrap();
+/// assert_eq!(config.get_int("db.port").unwrap(), 6432);
+/// assert!(config.get_bool("debug").unwrap());
+/// ```
+impl Source for Parameters {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, ConfigError> {
+        let json = self.to_json_unredacted().map_err(|error| ConfigError::Foreign(Box::new(error)))?;
+        serde_json::from_value(json).map_err(|error| ConfigError::Foreign(Box::new(error)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_through_config() {
+        let mut params = Parameters::new()
+            .with("name", "api")
+            .with("ports", vec![80i64, 443])
+            .with("tls", Parameters::new().with("password", "hunter2"));
+        params.mark_sensitive("tls.password");
+
+        let config = Config::builder().add_source(params).build().unwrap();
+        assert_eq!(config.get_string("tls.password").unwrap(), "hunter2");
+
+        let back = Parameters::try_from(config).unwrap();
+        assert_eq!(back.get_str("name"), Some("api"));
+        assert_eq!(back.get::<serde_json::Value>("ports"), Some(&serde_json::json!([80, 443])));
+    }
+}
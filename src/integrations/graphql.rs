@@ -0,0 +1,92 @@
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+use serde_json::Value as JsonValue;
+use crate::{error::ParameterError, Parameters, Result};
+
+/// `Parameters` appear in a GraphQL schema as a `Parameters` scalar holding an
+/// object, so mutations can accept free-form settings as a typed argument.
+///
+/// Input objects are converted as described in [`Parameters::from_json`];
+/// enum values arrive as strings and binary values as arrays of bytes.
+#[Scalar(name = "Parameters")]
+impl ScalarType for Parameters {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        if !matches!(value, Value::Object(_)) {
+            return Err(InputValueError::expected_type(value));
+        }
+        Parameters::try_from(value).map_err(InputValueError::custom)
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::Object(_))
+    }
+
+    fn to_value(&self) -> Value {
+        self.to_graphql_value()
+    }
+}
+
+impl TryFrom<Value> for Parameters {
+    type Error = ParameterError;
+
+    fn try_from(value: Value) -> Result<Self> {
+        let json = value.into_json()
+            .map_err(|e| ParameterError::Parse { format: "GraphQL", message: e.to_string() })?;
+        Parameters::from_json(json)
+    }
+}
+
+impl Parameters {
+    /// Convert to a GraphQL object value.
+    ///
+    /// GraphQL values go out to clients, so keys marked with `mark_sensitive`
+    /// and `Secret`s are masked as in `to_json`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new().with("retries", 3).with("api_key", "k3y");
+    /// params.mark_sensitive("api_key");
+    /// assert_eq!(params.to_graphql_value().to_string(), r#"{api_key: "***", retries: 3}"#);
+    /// ```
+    pub fn to_graphql_value(&self) -> Value {
+        Value::from_json(JsonValue::Object(self.redacted_json_object(&self.redaction)))
+            .unwrap_or(Value::Null)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_graphql::{value, EmptySubscription, Object, Schema};
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let input = value!({ "name": "svc", "limits": { "cpu": 2, "burst": true }, "tags": ["a", "b"] });
+        let params = Parameters::try_from(input.clone()).unwrap();
+        assert_eq!(params.get_string("name"), Some("svc".to_string()));
+        assert_eq!(params.get::<Parameters>("limits").unwrap().get::<i64>("cpu"), Some(&2));
+        assert_eq!(params.to_graphql_value(), input);
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn echo(&self, settings: Parameters) -> Parameters {
+            settings
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scalar_argument() {
+        let schema = Schema::new(Query, async_graphql::EmptyMutation, EmptySubscription);
+        let response = schema.execute(r#"{ echo(settings: { retries: 3 }) }"#).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(response.data, value!({ "echo": { "retries": 3 } }));
+
+        let response = schema.execute(r#"{ echo(settings: 3) }"#).await;
+        assert!(!response.errors.is_empty());
+    }
+}
@@ -0,0 +1,144 @@
+use http::{HeaderMap, HeaderName, HeaderValue};
+use crate::Parameters;
+
+impl Parameters {
+    /// Build parameters from HTTP headers, storing every value as a `String`.
+    ///
+    /// Keys are the lowercase header names. Bytes that are not valid UTF-8
+    /// are replaced with `U+FFFD`, and a header sent several times keeps all
+    /// of its values joined with `", "`, as HTTP allows for list headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` - The headers to read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http::HeaderMap;
+    /// use parameterx::Parameters;
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("X-Tenant", "acme".parse().unwrap());
+    /// headers.append("Accept", "text/html".parse().unwrap());
+    /// headers.append("Accept", "application/json".parse().unwrap());
+    ///
+    /// let params = Parameters::from_headers(&headers);
+    /// assert_eq!(params.get_str("x-tenant"), Some("acme"));
+    /// assert_eq!(params.get_str("accept"), Some("text/html, application/json"));
+    /// ```
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let mut params = Parameters::new();
+        for name in headers.keys() {
+            let values: Vec<String> = headers.get_all(name).iter()
+                .map(|value| String::from_utf8_lossy(value.as_bytes()).into_owned())
+                .collect();
+            params.insert(name.as_str(), values.join(", "));
+        }
+        params
+    }
+
+    /// Render the parameters as HTTP headers.
+    ///
+    /// Values are rendered as in `to_string_map`. Keys marked with
+    /// `mark_sensitive` and `Secret` values are left out rather than sent as
+    /// `***`; use `to_headers_unredacted` to forward credentials. Nested
+    /// parameters, and keys or values that are not valid in a header, are skipped.
+    ///
+    /// # Returns
+    ///
+    /// The headers, with names lowercased.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new()
+    ///     .with("x-request-id", "b7f1")
+    ///     .with("x-retries", 3)
+    ///     .with("not a header", "skipped");
+    ///
+    /// let headers = params.to_headers();
+    /// assert_eq!(headers.len(), 2);
+    /// assert_eq!(headers["x-retries"], "3");
+    /// ```
+    pub fn to_headers(&self) -> HeaderMap {
+        self.headers_with(false)
+    }
+
+    /// Render the parameters as HTTP headers, including sensitive values.
+    ///
+    /// Like `to_headers`, but keys marked with `mark_sensitive` are sent with
+    /// their values and `Secret`s exposed, for propagating credentials such as
+    /// `Authorization` to a trusted downstream service.
+    ///
+    /// # Returns
+    ///
+    /// The headers, with names lowercased.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new().with("x-tenant", "acme");
+    /// params.insert_secret("authorization", "Bearer t0k");
+    ///
+    /// assert!(!params.to_headers().contains_key("authorization"));
+    /// assert_eq!(params.to_headers_unredacted()["authorization"], "Bearer t0k");
+    /// ```
+    pub fn to_headers_unredacted(&self) -> HeaderMap {
+        self.headers_with(true)
+    }
+
+    fn headers_with(&self, expose: bool) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (key, value) in self.iter() {
+            let value = match value.exposed() {
+                Some(_) if !expose => continue,
+                Some(inner) => inner,
+                None if !expose && self.redaction.is_sensitive(key) => continue,
+                None => value.as_ref(),
+            };
+            if value.as_any().is::<Parameters>() {
+                continue;
+            }
+            let name = HeaderName::from_bytes(key.as_bytes());
+            let value = HeaderValue::from_str(&value.to_string());
+            if let (Ok(name), Ok(value)) = (name, value) {
+                headers.insert(name, value);
+            }
+        }
+        headers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Secret;
+    use super::*;
+
+    #[test]
+    fn test_round_trip_skips_unrepresentable_values() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-name", HeaderValue::from_bytes(b"caf\xe9").unwrap());
+
+        let mut params = Parameters::from_headers(&headers)
+            .with("Authorization", Secret::new("token".to_string()))
+            .with("x-api-key", "k3y")
+            .with("x-multiline", "a\nb")
+            .with("x-nested", Parameters::new().with("a", 1));
+        params.mark_sensitive("x-api-key");
+        assert_eq!(params.get_str("x-name"), Some("caf\u{fffd}"));
+
+        let headers = params.to_headers();
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers["x-name"], "caf\u{fffd}");
+
+        let headers = params.to_headers_unredacted();
+        assert_eq!(headers.len(), 3);
+        assert_eq!(headers["authorization"], "token");
+        assert_eq!(headers["x-api-key"], "k3y");
+    }
+}
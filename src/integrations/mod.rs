@@ -0,0 +1,37 @@
+#[cfg(feature = "async-graphql")]
+mod graphql;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "url")]
+mod url;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "tracing")]
+mod tracing;
+#[cfg(feature = "clap")]
+mod clap;
+#[cfg(feature = "config")]
+mod config;
+#[cfg(feature = "sql")]
+mod sql;
+#[cfg(feature = "redis")]
+mod redis;
+#[cfg(feature = "sled")]
+mod sled;
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(feature = "axum")]
+mod axum;
+#[cfg(feature = "actix")]
+mod actix;
+#[cfg(any(feature = "axum", feature = "actix"))]
+mod request;
+
+#[cfg(feature = "tracing")]
+pub use self::tracing::TracingFields;
+#[cfg(feature = "redis")]
+pub use self::redis::RedisStore;
+#[cfg(feature = "sled")]
+pub use self::sled::SledStore;
+#[cfg(any(feature = "axum", feature = "actix"))]
+pub use request::RequestParameters;
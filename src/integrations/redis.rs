@@ -0,0 +1,70 @@
+use redis::{Client, Commands};
+use crate::{ParameterError, ParameterStore, Result};
+
+fn store_error(error: redis::RedisError) -> ParameterError {
+    ParameterError::Store(Box::new(error))
+}
+
+/// A `ParameterStore` keeping each namespace as one Redis string.
+///
+/// Namespaces are stored under `parameterx:<namespace>` unless another
+/// prefix is set with `with_prefix`. Each call opens a connection from the
+/// client.
+///
+/// # Examples
+///
+/// ```no_run
+/// use parameterx::{ParameterStore, Parameters, RedisStore};
+///
+/// let store = RedisStore::open("redis://127.0.0.1/").unwrap().with_prefix("pipeline:");
+///
+/// store.save(&Parameters::new().with("batch_size", 500), "job-42").unwrap();
+/// let params = store.load("job-42").unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RedisStore {
+    client: Client,
+    prefix: String,
+}
+
+impl RedisStore {
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            prefix: "parameterx:".to_string(),
+        }
+    }
+
+    /// Connect to the server at `url`, such as `redis://127.0.0.1/`.
+    pub fn open(url: &str) -> Result<Self> {
+        Client::open(url).map(Self::new).map_err(store_error)
+    }
+
+    /// Store namespaces under `prefix` instead of `parameterx:`.
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    fn key(&self, namespace: &str) -> String {
+        format!("{}{}", self.prefix, namespace)
+    }
+}
+
+impl ParameterStore for RedisStore {
+    fn save_bytes(&self, namespace: &str, bytes: Vec<u8>) -> Result<()> {
+        let mut connection = self.client.get_connection().map_err(store_error)?;
+        connection.set(self.key(namespace), bytes).map_err(store_error)
+    }
+
+    fn load_bytes(&self, namespace: &str) -> Result<Option<Vec<u8>>> {
+        let mut connection = self.client.get_connection().map_err(store_error)?;
+        connection.get(self.key(namespace)).map_err(store_error)
+    }
+
+    fn delete(&self, namespace: &str) -> Result<bool> {
+        let mut connection = self.client.get_connection().map_err(store_error)?;
+        let removed: usize = connection.del(self.key(namespace)).map_err(store_error)?;
+        Ok(removed > 0)
+    }
+}
@@ -0,0 +1,37 @@
+use std::ops::{Deref, DerefMut};
+use crate::Parameters;
+
+/// The parameters of a web request, including its JSON or form body.
+///
+/// Extracting `Parameters` itself reads the path parameters and query
+/// string only, so it can be combined with other body extractors; extract
+/// `RequestParameters` instead to merge the body in as well. Path
+/// parameters override query parameters, which override body fields.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RequestParameters(pub Parameters);
+
+impl RequestParameters {
+    pub fn into_inner(self) -> Parameters {
+        self.0
+    }
+}
+
+impl Deref for RequestParameters {
+    type Target = Parameters;
+
+    fn deref(&self) -> &Parameters {
+        &self.0
+    }
+}
+
+impl DerefMut for RequestParameters {
+    fn deref_mut(&mut self) -> &mut Parameters {
+        &mut self.0
+    }
+}
+
+impl From<RequestParameters> for Parameters {
+    fn from(params: RequestParameters) -> Self {
+        params.0
+    }
+}
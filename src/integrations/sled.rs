@@ -0,0 +1,79 @@
+use crate::{ParameterError, ParameterStore, Result};
+
+fn store_error(error: sled::Error) -> ParameterError {
+    ParameterError::Store(Box::new(error))
+}
+
+/// A `ParameterStore` keeping each namespace as one entry of a sled tree.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{ParameterStore, Parameters, SledStore};
+///
+/// let db = sled::Config::new().temporary(true).open().unwrap();
+/// let store = SledStore::new(db.open_tree("parameters").unwrap());
+///
+/// store.save(&Parameters::new().with("stage", "extract"), "run-7").unwrap();
+/// let params = store.load("run-7").unwrap().unwrap();
+/// assert_eq!(params.get_str("stage"), Some("extract"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SledStore {
+    tree: sled::Tree,
+}
+
+impl SledStore {
+    /// Store namespaces in `tree`; a `sled::Db` can be used through its default tree.
+    pub fn new(tree: sled::Tree) -> Self {
+        Self { tree }
+    }
+
+    /// Flush pending writes to disk, which sled otherwise does periodically.
+    pub fn flush(&self) -> Result<()> {
+        self.tree.flush().map(drop).map_err(store_error)
+    }
+}
+
+impl ParameterStore for SledStore {
+    fn save_bytes(&self, namespace: &str, bytes: Vec<u8>) -> Result<()> {
+        self.tree.insert(namespace, bytes).map(drop).map_err(store_error)
+    }
+
+    fn load_bytes(&self, namespace: &str) -> Result<Option<Vec<u8>>> {
+        let value = self.tree.get(namespace).map_err(store_error)?;
+        Ok(value.map(|value| value.to_vec()))
+    }
+
+    fn delete(&self, namespace: &str) -> Result<bool> {
+        let removed = self.tree.remove(namespace).map_err(store_error)?;
+        Ok(removed.is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Parameters;
+    use super::*;
+
+    #[test]
+    fn test_save_replaces_and_delete_removes() {
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        let store = SledStore::new(db.open_tree("params").unwrap());
+
+        let mut params = Parameters::new()
+            .with("db", Parameters::new().with("password", "hunter2"))
+            .with("retries", 3);
+        params.mark_sensitive("db.password");
+        store.save(&params, "stage-1").unwrap();
+        store.save(&params.with("retries", 5), "stage-1").unwrap();
+
+        let loaded = store.load("stage-1").unwrap().unwrap();
+        assert_eq!(loaded.get_i64("retries"), Some(5));
+        assert_eq!(loaded.get::<Parameters>("db").unwrap().get_str("password"), Some("hunter2"));
+
+        assert!(store.delete("stage-1").unwrap());
+        assert!(!store.delete("stage-1").unwrap());
+        assert!(store.load("stage-1").unwrap().is_none());
+    }
+}
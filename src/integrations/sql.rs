@@ -0,0 +1,112 @@
+use sqlx::{error::BoxDynError, postgres::{PgArguments, Postgres}, query::Query};
+use crate::{value::is_null, ParameterError, ParameterValue, Parameters, Result};
+
+type PgQuery<'q> = Query<'q, Postgres, PgArguments>;
+
+/// Bind `value` as the next argument of `query`, or return `None` if its type has no PostgreSQL encoding.
+fn bind_value(query: &mut PgQuery<'_>, value: &dyn ParameterValue) -> Option<std::result::Result<(), BoxDynError>> {
+    let any = value.as_any();
+    macro_rules! bind_as {
+        ($($ty:ty),*) => {$(
+            if let Some(value) = any.downcast_ref::<$ty>() {
+                return Some(query.try_bind(value.clone()));
+            }
+        )*};
+    }
+    bind_as!(bool, i16, i32, i64, f32, f64, String, Vec<u8>, &'static str);
+
+    if let Some(value) = any.downcast_ref::<i8>() {
+        return Some(query.try_bind(i16::from(*value)));
+    }
+    if let Some(value) = any.downcast_ref::<u8>() {
+        return Some(query.try_bind(i16::from(*value)));
+    }
+    if let Some(value) = any.downcast_ref::<u16>() {
+        return Some(query.try_bind(i32::from(*value)));
+    }
+    if let Some(value) = any.downcast_ref::<u32>() {
+        return Some(query.try_bind(i64::from(*value)));
+    }
+    if is_null(value) {
+        return Some(query.try_bind(None::<String>));
+    }
+    None
+}
+
+impl Parameters {
+    /// Bind the values of `keys`, in order, as the arguments of a PostgreSQL query.
+    ///
+    /// Values keep their types: `bool`, `i16`, `i32`, `i64`, `f32`, `f64`,
+    /// strings and `Vec<u8>` bind as they are, smaller and unsigned integers
+    /// widen to the next signed type that holds them, and `u64` binds as
+    /// `i64` when it fits. Null values bind as a text `NULL`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The query to bind to, with placeholders `$1`, `$2`, ... matching `keys`.
+    /// * `keys` - The keys whose values to bind.
+    ///
+    /// # Returns
+    ///
+    /// The query with every value bound, or `KeyNotFound` for a missing key,
+    /// `ConversionFailed` for a `u64` beyond `i64::MAX` or a value that fails
+    /// to encode, and `TypeMismatch` for a value with no PostgreSQL encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new()
+    ///     .with("tenant", "acme")
+    ///     .with("limit", 50i64);
+    ///
+    /// let query = params.bind_all(
+    ///     sqlx::query("select id from jobs where tenant = $1 limit $2"),
+    ///     &["tenant", "limit"],
+    /// ).unwrap();
+    /// # let _ = query;
+    /// ```
+    pub fn bind_all<'q>(&self, mut query: PgQuery<'q>, keys: &[&str]) -> Result<PgQuery<'q>> {
+        for key in keys {
+            let value = self.lookup(key).ok_or_else(|| self.missing_key(key))?;
+            let bound = match value.as_any().downcast_ref::<u64>() {
+                Some(number) => {
+                    let number = i64::try_from(*number).map_err(|error| self.conversion_failed(key, value.as_ref(), "i64", error))?;
+                    query.try_bind(number)
+                }
+                None => bind_value(&mut query, value.as_ref()).ok_or_else(|| ParameterError::TypeMismatch {
+                    expected: "a type with a PostgreSQL encoding",
+                    actual: value.type_name(),
+                })?,
+            };
+            bound.map_err(|error| self.conversion_failed(key, value.as_ref(), "PostgreSQL argument", error))?;
+        }
+        Ok(query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::{Arguments, Execute};
+    use crate::value::Null;
+    use super::*;
+
+    #[test]
+    fn test_binds_in_order_and_reports_failures() {
+        let params = Parameters::new()
+            .with("id", 7u32)
+            .with("name", "job".to_string())
+            .with("note", Null)
+            .with("huge", u64::MAX)
+            .with("tags", vec!["a".to_string()]);
+
+        let mut query = params.bind_all(sqlx::query("insert into jobs values ($1, $2, $3)"), &["id", "name", "note"]).unwrap();
+        assert_eq!(query.take_arguments().unwrap().unwrap().len(), 3);
+
+        let bind = |keys: &[&str]| params.bind_all(sqlx::query("select $1"), keys).err();
+        assert!(matches!(bind(&["missing"]), Some(ParameterError::KeyNotFound { .. })));
+        assert!(matches!(bind(&["huge"]), Some(ParameterError::ConversionFailed { .. })));
+        assert!(matches!(bind(&["tags"]), Some(ParameterError::TypeMismatch { .. })));
+    }
+}
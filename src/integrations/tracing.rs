@@ -0,0 +1,156 @@
+use std::fmt;
+use tracing::Span;
+use crate::Parameters;
+
+/// The values of a `Parameters` set prepared for structured logging.
+///
+/// Nested parameters are expanded into dotted keys and sensitive keys are
+/// masked. Displays as `logfmt`, e.g. `db.host=localhost retries=3`, quoting
+/// values that contain spaces, quotes or `=`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TracingFields(Vec<(String, String)>);
+
+impl TracingFields {
+    /// The rendered `(key, value)` pairs, in key order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for TracingFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (key, value)) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            if value.is_empty() || value.contains([' ', '"', '=']) {
+                write!(f, "{}={:?}", key, value)?;
+            } else {
+                write!(f, "{}={}", key, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Parameters {
+    /// Record the parameters as fields of `span`.
+    ///
+    /// Tracing spans only accept the fields named when they were created, so
+    /// each key, with nested keys dotted, is recorded into the field of the
+    /// same name if the span declares it, typically as `tracing::field::Empty`.
+    /// A declared `params` field receives all of them as `as_tracing_fields`.
+    /// Sensitive keys are masked.
+    ///
+    /// # Arguments
+    ///
+    /// * `span` - The span to record into.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tracing::{field, info_span};
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("job", "export").with("batch_size", 500);
+    ///
+    /// let span = info_span!("run", job = field::Empty, batch_size = field::Empty);
+    /// params.record_on(&span);
+    /// ```
+    pub fn record_on(&self, span: &Span) {
+        let fields = self.as_tracing_fields();
+        for (key, value) in fields.iter() {
+            span.record(key, value);
+        }
+        span.record("params", tracing::field::display(&fields));
+    }
+
+    /// The parameters as structured fields, for logging or `#[instrument]`.
+    ///
+    /// # Returns
+    ///
+    /// The fields, which display as `logfmt` with sensitive keys masked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new()
+    ///     .with("db", Parameters::new().with("host", "localhost").with("password", "hunter2"))
+    ///     .with("mode", "dry run");
+    /// params.mark_sensitive("*password");
+    ///
+    /// let fields = params.as_tracing_fields();
+    /// assert_eq!(fields.to_string(), r#"db.host=localhost db.password=*** mode="dry run""#);
+    ///
+    /// tracing::info!(params = %fields, "starting");
+    /// ```
+    pub fn as_tracing_fields(&self) -> TracingFields {
+        TracingFields(self.rendered_leaves())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+        Event, Metadata, Subscriber,
+    };
+    use super::*;
+
+    /// Keeps the values recorded into spans after their creation.
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<(String, String)>>>);
+
+    impl Visit for Recorder {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0.lock().unwrap().push((field.name().to_string(), format!("{:?}", value)));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+        fn record(&self, _: &Id, values: &Record<'_>) {
+            values.record(&mut self.clone());
+        }
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, _: &Event<'_>) {}
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn test_records_declared_fields_only() {
+        let mut params = Parameters::new()
+            .with("region", "eu")
+            .with("token", "abc")
+            .with("db", Parameters::new().with("port", 5432));
+        params.mark_sensitive("token");
+
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let span = tracing::info_span!("job", region = tracing::field::Empty, token = tracing::field::Empty, "db.port" = tracing::field::Empty);
+            params.record_on(&span);
+        });
+
+        let recorded = recorder.0.lock().unwrap().clone();
+        let recorded: Vec<(&str, &str)> = recorded.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+        assert_eq!(recorded, [("db.port", "\"5432\""), ("region", "\"eu\""), ("token", "\"***\"")]);
+    }
+}
@@ -0,0 +1,46 @@
+use url::Url;
+use crate::{DisplayValue, Parameters};
+
+impl DisplayValue for Url {}
+
+impl Parameters {
+    /// Get a URL, whether stored as a `Url` or as a string.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// The URL, or `None` if the key is missing or the value is not a valid URL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("endpoint", "https://api.example.com/v1/");
+    ///
+    /// let endpoint = params.get_url("endpoint").unwrap();
+    /// assert_eq!(endpoint.host_str(), Some("api.example.com"));
+    /// assert_eq!(endpoint.join("users").unwrap().as_str(), "https://api.example.com/v1/users");
+    /// ```
+    pub fn get_url(&self, key: &str) -> Option<Url> {
+        self.get_typed_or_parsed(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stored_and_invalid_urls() {
+        let url = Url::parse("postgres://db:5432/app").unwrap();
+        let params = Parameters::new().with("database", url.clone()).with("bad", "not a url");
+
+        assert_eq!(params.get_url("database"), Some(url));
+        assert_eq!(params.get_string("database").as_deref(), Some("postgres://db:5432/app"));
+        assert_eq!(params.get_url("bad"), None);
+    }
+}
@@ -0,0 +1,72 @@
+use serde::Serialize;
+use serde_wasm_bindgen::Serializer;
+use wasm_bindgen::JsValue;
+use crate::{ParameterError, Parameters, Result};
+
+impl Parameters {
+    /// Build parameters from a JavaScript object.
+    ///
+    /// The object is converted as in `from_json`: nested objects become
+    /// nested `Parameters`, numbers become `i64` or `f64`, and arrays and
+    /// nulls are kept as `serde_json::Value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A plain JavaScript object, or a `Map` with string keys.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Parameters`, a `ParameterError::Parse` if
+    /// the value cannot be read as JSON data, or a `TypeMismatch` if it is
+    /// not an object.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wasm_bindgen::prelude::*;
+    /// use parameterx::Parameters;
+    ///
+    /// #[wasm_bindgen]
+    /// pub fn render_chart(options: JsValue) -> Result<String, JsValue> {
+    ///     let params = Parameters::from_js_value(options).map_err(|error| JsValue::from_str(&error.to_string()))?;
+    ///     Ok(format!("{} bars", params.get_i64("bars").unwrap_or(10)))
+    /// }
+    /// ```
+    pub fn from_js_value(value: JsValue) -> Result<Parameters> {
+        let json = serde_wasm_bindgen::from_value(value).map_err(|error| ParameterError::Parse {
+            format: "JavaScript value",
+            message: error.to_string(),
+        })?;
+        Parameters::from_json(json)
+    }
+
+    /// Convert to a plain JavaScript object.
+    ///
+    /// Values are converted as in `to_json_unredacted`, since the object is
+    /// data for the JavaScript side rather than display output: sensitive keys
+    /// and `Secret`s keep their values. Nested parameters become nested objects.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the object, or the error of a value that cannot be converted.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wasm_bindgen::prelude::*;
+    /// use parameterx::Parameters;
+    ///
+    /// #[wasm_bindgen]
+    /// pub fn defaults() -> Result<JsValue, JsValue> {
+    ///     let params = Parameters::new().with("bars", 12).with("theme", "dark");
+    ///     params.to_js_value().map_err(|error| JsValue::from_str(&error.to_string()))
+    /// }
+    /// ```
+    pub fn to_js_value(&self) -> Result<JsValue> {
+        let json = self.to_json_unredacted()?;
+        json.serialize(&Serializer::json_compatible()).map_err(|error| ParameterError::Parse {
+            format: "JavaScript value",
+            message: error.to_string(),
+        })
+    }
+}
@@ -2,30 +2,205 @@
 mod error;
 mod value;
 mod parameters;
+mod schema;
+mod tenant;
+mod overrides;
+mod shared;
+mod namespace;
+mod persist;
+#[cfg(feature = "watch")]
+mod watch;
+mod integrations;
+mod formats;
+#[cfg(feature = "async")]
+mod source;
 
 pub use error::ParameterError;
-pub use value::{ParameterValue, IntVec, IntegerBehavior};
-pub use parameters::{Parameters, ParametersBuilder};
+pub use value::{ParameterValue, DisplayValue, ParameterKind, IntVec, StrVec, FloatVec, BoolVec, IntegerBehavior, Secret, RangeValue, Null, ByteSize, HumanDuration, format_duration, parse_duration};
+pub use parameters::{Parameters, ParametersBuilder, ChangeBatch, ChangeKind, ParameterChange, ValueSource, Entry, OccupiedEntry, VacantEntry, ExplainStep, Explanation, LayerOutcome, ParametersDiff, DiffFormat, CompactionReport, MergeStrategy, MergePreview, MergeWarning, QuotaOverage, ScopedParameters, ParametersSnapshot, FrozenParameters, FromParameter, Meta, NullPolicy, RenamePolicy, StorageBackend, TrackedChange, Warning, Key, intern_keys, EvalBudget, EvalLimits, CapacityLimits};
+pub use tenant::TenantParameters;
+pub use overrides::{Override, OverrideId, OverrideManager};
+pub use shared::SharedParameters;
+pub use namespace::{Namespace, NamespaceRegistry};
+pub use persist::ParameterStore;
+#[cfg(feature = "watch")]
+pub use watch::FileWatcher;
+pub use schema::{ParameterSchema, FieldSchema, SchemaViolation, ViolationKind};
+pub use formats::Bundle;
+#[cfg(any(feature = "axum", feature = "actix"))]
+pub use integrations::RequestParameters;
+#[cfg(feature = "tracing")]
+pub use integrations::TracingFields;
+#[cfg(feature = "redis")]
+pub use integrations::RedisStore;
+#[cfg(feature = "sled")]
+pub use integrations::SledStore;
+#[cfg(feature = "xml")]
+pub use formats::XmlOptions;
+#[cfg(feature = "toml")]
+pub use formats::TomlEditor;
+#[cfg(feature = "yaml")]
+pub use formats::YamlEditor;
+#[cfg(feature = "async")]
+pub use source::{ParameterSource, CompositeSource, ParallelLoader, MemoryStore, PushOutcome, RemoteStore, Resolution, SyncConflict, SyncReport, SyncedParameters, Versioned, CachedParameters, FallbackSource, SourceFailure, StartupReport};
+#[cfg(feature = "async")]
+pub use async_trait::async_trait;
 
 pub type Result<T> = std::result::Result<T, ParameterError>;
 
+/// Build `Parameters` from `key => value` entries.
+///
+/// A `{ ... }` block as the value builds nested `Parameters` from the
+/// entries inside it; wrap a block expression in parentheses to store its
+/// result instead. `..expr` splices in the entries of an existing
+/// `Parameters`, and entries after it override the spliced ones. A single
+/// expression is converted with `Parameters::from`.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{parameters, Parameters};
+///
+/// let base = parameters! { "region" => "eu", "debug" => false };
+/// let params = parameters! {
+///     "db" => {
+///         "host" => "localhost",
+///         "pool" => { "size" => 4 },
+///     },
+///     ..base,
+///     "debug" => true,
+/// };
+///
+/// let db = params.get::<Parameters>("db").unwrap();
+/// assert_eq!(db.get::<Parameters>("pool").unwrap().get::<i32>("size"), Some(&4));
+/// assert_eq!(params.get::<&str>("region"), Some(&"eu"));
+/// assert_eq!(params.get::<bool>("debug"), Some(&true));
+/// ```
 #[macro_export]
 macro_rules! parameters {
+    (@entries $params:ident;) => {};
+
+    (@entries $params:ident; .. $other:expr $(, $($rest:tt)*)?) => {
+        $params.extend($crate::Parameters::from($other));
+        $($crate::parameters!(@entries $params; $($rest)*);)?
+    };
+
+    (@entries $params:ident; $key:expr => { $($nested:tt)* } $(, $($rest:tt)*)?) => {
+        $params.insert($key, $crate::parameters!({ $($nested)* }));
+        $($crate::parameters!(@entries $params; $($rest)*);)?
+    };
+
+    (@entries $params:ident; $key:expr => $value:expr $(, $($rest:tt)*)?) => {
+        $params.insert($key, $value);
+        $($crate::parameters!(@entries $params; $($rest)*);)?
+    };
+
     () => {
         $crate::Parameters::new()
     };
 
+    ({ $($entries:tt)* }) => {{
+        #[allow(unused_mut)]
+        let mut params = $crate::Parameters::new();
+        $crate::parameters!(@entries params; $($entries)*);
+        params
+    }};
+
+    (.. $($entries:tt)*) => {
+        $crate::parameters!({ .. $($entries)* })
+    };
+
+    ($key:expr => $($entries:tt)*) => {
+        $crate::parameters!({ $key => $($entries)* })
+    };
+
     ($text:expr) => {
         $crate::Parameters::from($text)
     };
+}
 
-    ($($key:expr => $value:expr),+ $(,)?) => {{
-        let mut params = $crate::Parameters::new();
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::parameters::{Collector, UnwrapAll};
+}
+
+/// Read several parameters at once, converting each to the type it is bound to.
+///
+/// Each key is read with [`FromParameter`], so the target types come from
+/// the binding. With one key the result is a `Result<T>`, otherwise a
+/// `Result` of a tuple with one element per key, up to twelve. Every key is
+/// read before failing: missing keys are reported together as
+/// `ParameterError::MissingKeys`, and otherwise the first conversion error
+/// is returned.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{params_get, ParameterError, Parameters};
+///
+/// fn connect(params: &Parameters) -> Result<String, ParameterError> {
+///     let (host, port, tls): (&str, u16, Option<bool>) = params_get!(params, "host", "port", "tls")?;
+///     Ok(format!("{}:{} tls={}", host, port, tls.unwrap_or(false)))
+/// }
+///
+/// let params = Parameters::new().with("host", "db").with("port", "5432");
+/// assert_eq!(connect(&params).unwrap(), "db:5432 tls=false");
+///
+/// let err = connect(&Parameters::new()).unwrap_err();
+/// assert_eq!(err.to_string(), "Missing required parameters: host, port");
+/// ```
+#[macro_export]
+macro_rules! params_get {
+    ($params:expr, $key:expr $(,)?) => {
+        $crate::FromParameter::from_parameter(&$params, $key)
+    };
+
+    ($params:expr, $($key:expr),+ $(,)?) => {{
+        let params: &$crate::Parameters = &$params;
+        let mut collector = $crate::__private::Collector::default();
+        let values = ($(collector.take($key, $crate::FromParameter::from_parameter(params, $key)),)+);
+        collector.finish(|| $crate::__private::UnwrapAll::unwrap_all(values))
+    }};
+}
+
+/// Declare `static` parameter sets built on first access.
+///
+/// Each static is a `std::sync::LazyLock<Parameters>`, so it is initialized
+/// exactly once, from whichever thread reads it first, and can be shared
+/// freely afterwards.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::static_parameters;
+///
+/// static_parameters! {
+///     /// Defaults shipped with the HTTP client.
+///     pub static DEFAULTS = {
+///         "timeout" => 30,
+///         "retries" => 3,
+///     };
+///     static EMPTY = {};
+/// }
+///
+/// assert_eq!(DEFAULTS.get::<i32>("retries"), Some(&3));
+/// assert!(EMPTY.is_empty());
+/// ```
+#[macro_export]
+macro_rules! static_parameters {
+    ($($(#[$meta:meta])* $vis:vis static $name:ident = { $($key:expr => $value:expr),* $(,)? };)+) => {
         $(
-            params = params.with($key, $value);
+            $(#[$meta])*
+            $vis static $name: ::std::sync::LazyLock<$crate::Parameters> = ::std::sync::LazyLock::new(|| {
+                #[allow(unused_mut)]
+                let mut params = $crate::Parameters::new();
+                $(
+                    params = params.with($key, $value);
+                )*
+                params
+            });
         )+
-        params
-    }};
+    };
 }
 
 #[cfg(test)]
@@ -59,6 +234,62 @@ mod tests {
         assert_eq!(params.get::<IntVec<i32>>("scores").map(|v| v.0.clone()), Some(vec![85, 92, 78]));
     }
 
+    #[test]
+    fn test_get_mut_copies_shared_values() {
+        let mut params = Parameters::new().with("scores", IntVec::<i32>(vec![1, 2]));
+        let snapshot = params.clone();
+        let shared = params.iter().next().map(|(_, v)| v.clone()).unwrap();
+
+        params.get_mut::<IntVec<i32>>("scores").unwrap().0.push(3);
+
+        assert_eq!(params.get::<IntVec<i32>>("scores").unwrap().0, vec![1, 2, 3]);
+        assert_eq!(snapshot.get::<IntVec<i32>>("scores").unwrap().0, vec![1, 2]);
+        assert_eq!(shared.to_string(), "[1, 2]");
+        assert!(params.get_mut::<String>("scores").is_none());
+    }
+
+    #[test]
+    fn test_get_mut_follows_aliases_and_bypasses_write_checks() {
+        let mut params = Parameters::new().with("count", 1u64).with_tracking();
+        params.alias("hits", "count");
+        params.set_capacity_limits(CapacityLimits::new().max_value_string_len(1));
+        let notified = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = notified.clone();
+        params.on_change(move |_| { counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst); });
+
+        *params.get_mut::<u64>("hits").unwrap() = 100;
+
+        assert_eq!(params.get::<u64>("count"), Some(&100));
+        assert!(params.changes().is_empty());
+        assert_eq!(notified.load(std::sync::atomic::Ordering::SeqCst), 0);
+        assert!(params.check_capacity().is_err());
+    }
+
+    #[test]
+    fn test_clone_shares_map_until_written() {
+        let params: Parameters = (0..1000).map(|i| (format!("key{i}"), format!("{i}"))).collect();
+        let mut copy = params.clone();
+        assert!(std::sync::Arc::ptr_eq(&params.map, &copy.map));
+
+        copy.insert("key0", "changed".to_string());
+        assert!(!std::sync::Arc::ptr_eq(&params.map, &copy.map));
+        assert_eq!(params.get_string("key0"), Some("0".to_string()));
+        assert_eq!(copy.get_string("key0"), Some("changed".to_string()));
+    }
+
+    #[test]
+    fn test_parameters_equality() {
+        let expected = parameters! { "name" => "Eve", "age" => 41 };
+        let mut actual = Parameters::new().with("age", 41);
+        assert_ne!(actual, expected);
+
+        actual.insert("name", "Eve");
+        assert_eq!(actual, expected);
+
+        actual.insert("age", 41u8);
+        assert_ne!(actual, expected);
+    }
+
     #[test]
     fn test_macro() {
         let params = parameters! {
@@ -68,6 +299,27 @@ mod tests {
 
         assert_eq!(params.get_string("name"), Some("Charlie".to_string()));
         assert_eq!(params.get_string("age"), Some("25".to_string()));
+
+        let nested = parameters! { ..params.clone(), "name" => "Dana", "limits" => { "cpu" => 2 } };
+        assert_eq!(nested.get_string("age"), Some("25".to_string()));
+        assert_eq!(nested.get_string("name"), Some("Dana".to_string()));
+        assert_eq!(nested.get::<Parameters>("limits"), Some(&parameters! { "cpu" => 2 }));
+        assert_eq!(parameters! { "empty" => {} }.get::<Parameters>("empty").map(Parameters::len), Some(0));
+    }
+
+    #[test]
+    fn test_static_parameters_shared_across_threads() {
+        static_parameters! {
+            static DEFAULTS = { "workers" => 4, "name" => "svc" };
+        }
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| std::thread::spawn(|| DEFAULTS.get::<i32>("workers").copied()))
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), Some(4));
+        }
+        assert_eq!(DEFAULTS.len(), 2);
     }
 
     #[test]
@@ -78,12 +330,14 @@ mod tests {
             age: i32,
         }
 
-        impl ToString for Person {
-            fn to_string(&self) -> String {
-                format!("{} ({})", self.name, self.age)
+        impl std::fmt::Display for Person {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{} ({})", self.name, self.age)
             }
         }
 
+        impl crate::DisplayValue for Person {}
+
         let person = Person {
             name: "Dave".to_string(),
             age: 35,
@@ -95,4 +349,87 @@ mod tests {
         assert_eq!(stored_person.name, "Dave");
         assert_eq!(stored_person.age, 35);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_reads_unredacted_values() {
+        let mut params = Parameters::new().with("pin", "1234").with("ratio", 0.5f32);
+        params.mark_sensitive("pin");
+
+        assert_eq!(params.get_string("pin"), Some("***".to_string()));
+        assert_eq!(params.try_get_parse::<u32>("pin").unwrap(), 1234);
+        assert_eq!(params.try_get_parse::<f64>("ratio").unwrap(), 0.5);
+        assert!(matches!(params.try_get_parse::<u8>("missing"), Err(ParameterError::KeyNotFound { .. })));
+    }
+
+    #[test]
+    fn test_try_get_reads_unredacted_values() {
+        let mut params = Parameters::new().with("api_key", "k3y").with("port", 8080);
+        params.mark_sensitive("api_key");
+
+        assert_eq!(params.get_string("api_key"), Some("***".to_string()));
+        assert_eq!(params.try_get::<String>("api_key").unwrap(), "k3y");
+        assert_eq!(params.try_get::<String>("port").unwrap(), "8080");
+
+        params.insert_secret("db_port", 5432u16);
+        params.insert_secret("token", "t0k".to_string());
+        assert_eq!(params.try_get::<String>("token").unwrap(), "t0k");
+        assert_eq!(params.try_get_parse::<u16>("db_port").unwrap(), 5432);
+        match params.try_get_parse::<u8>("token") {
+            Err(ParameterError::ConversionFailed { raw, .. }) => assert_eq!(raw, "***"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_conversion_failure_carries_raw_value() {
+        let mut params = Parameters::new().with("pin", "12a4").with("port", "9".repeat(100));
+        params.mark_sensitive("pin");
+
+        match params.try_get_parse::<u32>("pin") {
+            Err(ParameterError::ConversionFailed { key, raw, type_name, target, .. }) => {
+                assert_eq!((key.as_str(), raw.as_str(), type_name, target), ("pin", "***", "&str", "u32"));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        match params.try_get_parse::<u16>("port") {
+            Err(ParameterError::ConversionFailed { raw, .. }) => assert_eq!(raw, format!("{}...", "9".repeat(64))),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_string_map_round_trip() {
+        let env = std::collections::HashMap::from([("HOST".to_string(), "db".to_string()), ("PORT".to_string(), "5432".to_string())]);
+        let mut params = Parameters::from(env);
+        assert_eq!(params.get::<String>("PORT").map(String::as_str), Some("5432"));
+
+        params.insert("TOKEN", "t0k3n");
+        params.mark_sensitive("TOKEN");
+        let map = params.to_string_map();
+        assert_eq!(map["TOKEN"], "***");
+        assert_eq!(Parameters::from(map).len(), 3);
+    }
+
+    #[test]
+    fn test_collection_traits() {
+        let source = Parameters::new().with("a", 1).with("b", 2).with("c", 3);
+        let mut keys = Vec::new();
+        for (key, _) in &source {
+            keys.push(key.clone());
+        }
+        assert_eq!(keys, ["a", "b", "c"]);
+
+        let odd: Parameters = source.into_iter()
+            .filter(|(_, v)| v.as_any().downcast_ref::<i32>().is_some_and(|n| n % 2 == 1))
+            .collect();
+        assert_eq!(odd, Parameters::new().with("a", 1).with("c", 3));
+
+        let mut params = Parameters::new();
+        params.extend(odd);
+        params.extend([("d".to_string(), "4".to_string())]);
+        params.extend([("e", 5u8), ("f", 6u8)]);
+        assert_eq!(params.len(), 5);
+        assert_eq!(params.get_string("d"), Some("4".to_string()));
+        assert_eq!(params.get::<u8>("f"), Some(&6));
+    }
+}
@@ -0,0 +1,219 @@
+use std::{collections::BTreeMap, sync::Arc};
+use crate::{Key, ParameterError, ParameterValue, Parameters, Result};
+
+/// Whether `key` is `prefix` itself or lies under it, matching whole dotted segments.
+fn is_under(key: &str, prefix: &str) -> bool {
+    key.strip_prefix(prefix).is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+/// One `Parameters` set shared by plugins that each own a key prefix.
+///
+/// A plugin claims a prefix such as `cache` and writes through its
+/// [`Namespace`] handle with short names: `timeout` is stored as
+/// `cache.timeout`, so two plugins can both use generic names without
+/// overwriting each other. Claims may not overlap, writes to keys outside a
+/// plugin's own prefix are rejected, and the host cannot write into a claimed
+/// prefix either. Every failure is a `ParameterError::NamespaceConflict`
+/// naming the owner of the key.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{NamespaceRegistry, ParameterError, Parameters};
+///
+/// let mut registry = NamespaceRegistry::new(Parameters::new());
+/// registry.claim("cache-plugin", "cache").unwrap();
+/// registry.claim("http-plugin", "http").unwrap();
+/// assert!(registry.claim("rogue", "cache.inner").is_err());
+///
+/// registry.namespace("cache-plugin").unwrap().insert("timeout", 30);
+/// registry.namespace("http-plugin").unwrap().insert("timeout", 5);
+/// assert_eq!(registry.parameters().get::<i32>("cache.timeout"), Some(&30));
+/// assert_eq!(registry.parameters().get::<i32>("http.timeout"), Some(&5));
+///
+/// let mut http = registry.namespace("http-plugin").unwrap();
+/// let err = http.insert_key("cache.timeout", 0).unwrap_err();
+/// assert!(matches!(err, ParameterError::NamespaceConflict { owner: Some(owner), .. } if owner == "cache-plugin"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NamespaceRegistry {
+    params: Parameters,
+    /// Claimed prefix for each plugin.
+    claims: BTreeMap<String, String>,
+}
+
+impl NamespaceRegistry {
+    pub fn new(params: Parameters) -> Self {
+        Self {
+            params,
+            claims: BTreeMap::new(),
+        }
+    }
+
+    /// Give `plugin` ownership of the keys under `prefix`.
+    ///
+    /// # Arguments
+    ///
+    /// * `plugin` - The plugin's name, used to look up its handle.
+    /// * `prefix` - The dotted prefix to own, such as `cache` or `vendor.cache`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that is `ParameterError::NamespaceConflict` if the plugin already holds a
+    /// claim, or if the prefix contains, or lies inside, another plugin's prefix.
+    pub fn claim(&mut self, plugin: impl Into<String>, prefix: impl Into<String>) -> Result<()> {
+        let plugin = plugin.into();
+        let prefix = prefix.into().trim_end_matches('.').to_string();
+        if let Some(existing) = self.claims.get(&plugin) {
+            return Err(ParameterError::NamespaceConflict { key: existing.clone(), owner: Some(plugin) });
+        }
+        if let Some((owner, _)) = self.claims.iter().find(|(_, claimed)| is_under(&prefix, claimed) || is_under(claimed, &prefix)) {
+            return Err(ParameterError::NamespaceConflict { key: prefix, owner: Some(owner.clone()) });
+        }
+        self.claims.insert(plugin, prefix);
+        Ok(())
+    }
+
+    /// Give up a plugin's claim, returning its prefix. The keys it wrote are kept.
+    pub fn release(&mut self, plugin: &str) -> Option<String> {
+        self.claims.remove(plugin)
+    }
+
+    /// The prefix claimed by `plugin`.
+    pub fn prefix_of(&self, plugin: &str) -> Option<&str> {
+        self.claims.get(plugin).map(String::as_str)
+    }
+
+    /// The plugin whose prefix contains `key`, if any.
+    pub fn owner_of(&self, key: &str) -> Option<&str> {
+        self.claims.iter()
+            .find(|(_, prefix)| is_under(key, prefix))
+            .map(|(plugin, _)| plugin.as_str())
+    }
+
+    /// A handle for writing within `plugin`'s namespace, or `None` if it has not claimed one.
+    pub fn namespace(&mut self, plugin: &str) -> Option<Namespace<'_>> {
+        let (plugin, prefix) = self.claims.get_key_value(plugin)?;
+        Some(Namespace { plugin, prefix, claims: &self.claims, params: &mut self.params })
+    }
+
+    /// Insert a value owned by the host application, outside every plugin's namespace.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the previous value, or `ParameterError::NamespaceConflict` if a plugin owns the key.
+    pub fn insert<K, V>(&mut self, key: K, value: V) -> Result<Option<Arc<dyn ParameterValue>>>
+    where
+        K: Into<Key>,
+        V: ParameterValue + 'static,
+    {
+        let key = key.into();
+        if let Some(owner) = self.owner_of(&key) {
+            return Err(ParameterError::NamespaceConflict { key: String::from(key), owner: Some(owner.to_string()) });
+        }
+        Ok(self.params.insert(key, value))
+    }
+
+    /// All parameters, with every namespace's keys fully qualified.
+    pub fn parameters(&self) -> &Parameters {
+        &self.params
+    }
+
+    pub fn into_parameters(self) -> Parameters {
+        self.params
+    }
+}
+
+/// A plugin's write access to its own prefix within a [`NamespaceRegistry`].
+#[derive(Debug)]
+pub struct Namespace<'a> {
+    plugin: &'a str,
+    prefix: &'a str,
+    claims: &'a BTreeMap<String, String>,
+    params: &'a mut Parameters,
+}
+
+impl Namespace<'_> {
+    pub fn plugin(&self) -> &str {
+        self.plugin
+    }
+
+    pub fn prefix(&self) -> &str {
+        self.prefix
+    }
+
+    fn qualify(&self, key: &str) -> String {
+        format!("{}.{}", self.prefix, key)
+    }
+
+    /// Insert a value under this namespace, so `timeout` is stored as `prefix.timeout`.
+    pub fn insert<V: ParameterValue + 'static>(&mut self, key: &str, value: V) -> Option<Arc<dyn ParameterValue>> {
+        let key = self.qualify(key);
+        self.params.insert(key, value)
+    }
+
+    /// Insert a value under a fully qualified key, which must lie within this namespace.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the previous value, or `ParameterError::NamespaceConflict`
+    /// naming the key's owner, `None` for the host, if the key is outside this namespace.
+    pub fn insert_key<V: ParameterValue + 'static>(&mut self, key: &str, value: V) -> Result<Option<Arc<dyn ParameterValue>>> {
+        if !is_under(key, self.prefix) {
+            let owner = self.claims.iter()
+                .find(|(_, prefix)| is_under(key, prefix))
+                .map(|(plugin, _)| plugin.clone());
+            return Err(ParameterError::NamespaceConflict { key: key.to_string(), owner });
+        }
+        Ok(self.params.insert(key, value))
+    }
+
+    /// Get a value by its name within this namespace.
+    pub fn get<T: 'static>(&self, key: &str) -> Option<&T> {
+        self.params.get(&self.qualify(key))
+    }
+
+    pub fn get_string(&self, key: &str) -> Option<String> {
+        self.params.get_string(&self.qualify(key))
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.params.contains_key(&self.qualify(key))
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<Arc<dyn ParameterValue>> {
+        let key = self.qualify(key);
+        self.params.remove(&key)
+    }
+
+    /// The names of this namespace's keys, without the prefix.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        let prefix = self.prefix;
+        self.params.keys().filter_map(move |key| key.strip_prefix(prefix)?.strip_prefix('.'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claims_and_writes_respect_namespaces() {
+        let mut registry = NamespaceRegistry::new(Parameters::new().with("timeout", 60));
+        registry.claim("a", "plugins.a.").unwrap();
+        assert!(registry.claim("b", "plugins").is_err());
+        assert!(registry.claim("a", "other").is_err());
+        registry.claim("b", "plugins.ab").unwrap();
+
+        let mut a = registry.namespace("a").unwrap();
+        a.insert("timeout", 1);
+        assert!(matches!(a.insert_key("timeout", 2), Err(ParameterError::NamespaceConflict { owner: None, .. })));
+        assert_eq!(a.keys().collect::<Vec<_>>(), ["timeout"]);
+
+        assert_eq!(registry.owner_of("plugins.ab.x"), Some("b"));
+        assert!(registry.insert("plugins.a.timeout", 3).is_err());
+        registry.insert("timeout", 90).unwrap();
+        assert_eq!(registry.parameters().get::<i32>("timeout"), Some(&90));
+        assert_eq!(registry.parameters().get::<i32>("plugins.a.timeout"), Some(&1));
+    }
+}
@@ -0,0 +1,223 @@
+use std::time::{Duration, Instant};
+use crate::Parameters;
+
+/// Identifies an override applied to an [`OverrideManager`], for removing it later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct OverrideId(u64);
+
+/// A set of values layered over the base parameters, with a priority and an optional expiry.
+#[derive(Debug, Clone)]
+pub struct Override {
+    id: OverrideId,
+    label: String,
+    priority: i32,
+    expires_at: Option<Instant>,
+    parameters: Parameters,
+}
+
+impl Override {
+    pub fn id(&self) -> OverrideId {
+        self.id
+    }
+
+    /// The description given when the override was applied, such as an incident number.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    pub fn expires_at(&self) -> Option<Instant> {
+        self.expires_at
+    }
+
+    /// The time left before the override expires, or `None` if it never does.
+    pub fn expires_in(&self) -> Option<Duration> {
+        self.expires_at.map(|at| at.saturating_duration_since(Instant::now()))
+    }
+
+    pub fn parameters(&self) -> &Parameters {
+        &self.parameters
+    }
+
+    fn is_active(&self, now: Instant) -> bool {
+        self.expires_at.is_none_or(|at| now < at)
+    }
+}
+
+/// A base `Parameters` set with temporary, prioritized overrides on top.
+///
+/// Reads resolve through the active overrides first, highest priority
+/// first, and fall back to the base set. Among overrides of equal priority
+/// the most recently applied wins. Overrides with an expiry stop applying
+/// as soon as it passes, even before they are purged; expired overrides are
+/// dropped whenever the manager is modified, or explicitly with
+/// `purge_expired`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use parameterx::{OverrideManager, Parameters};
+///
+/// let mut manager = OverrideManager::new(Parameters::new().with("rate_limit", 100).with("region", "eu"));
+/// manager.apply_for("INC-42: shed load", 10, Parameters::new().with("rate_limit", 10), Duration::from_secs(3600));
+///
+/// assert_eq!(manager.get::<i32>("rate_limit"), Some(&10));
+/// assert_eq!(manager.get::<&str>("region"), Some(&"eu"));
+/// assert_eq!(manager.active().next().unwrap().label(), "INC-42: shed load");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct OverrideManager {
+    base: Parameters,
+    /// Ordered by descending priority, newest first within a priority.
+    overrides: Vec<Override>,
+    next_id: u64,
+}
+
+impl OverrideManager {
+    pub fn new(base: Parameters) -> Self {
+        Self {
+            base,
+            overrides: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// The parameters used when no active override sets a key.
+    pub fn base(&self) -> &Parameters {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut Parameters {
+        &mut self.base
+    }
+
+    /// Apply an override that stays until it is removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - A description of why the override exists.
+    /// * `priority` - Overrides with a higher priority win over lower ones.
+    /// * `parameters` - The values to override.
+    ///
+    /// # Returns
+    ///
+    /// The `OverrideId` to pass to `remove`.
+    pub fn apply(&mut self, label: impl Into<String>, priority: i32, parameters: Parameters) -> OverrideId {
+        self.push(label.into(), priority, None, parameters)
+    }
+
+    /// Apply an override that expires after `ttl`.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - A description of why the override exists.
+    /// * `priority` - Overrides with a higher priority win over lower ones.
+    /// * `parameters` - The values to override.
+    /// * `ttl` - How long the override applies for.
+    ///
+    /// # Returns
+    ///
+    /// The `OverrideId` to pass to `remove`.
+    pub fn apply_for(&mut self, label: impl Into<String>, priority: i32, parameters: Parameters, ttl: Duration) -> OverrideId {
+        self.apply_until(label, priority, parameters, Instant::now() + ttl)
+    }
+
+    /// Apply an override that expires at `deadline`.
+    pub fn apply_until(&mut self, label: impl Into<String>, priority: i32, parameters: Parameters, deadline: Instant) -> OverrideId {
+        self.push(label.into(), priority, Some(deadline), parameters)
+    }
+
+    fn push(&mut self, label: String, priority: i32, expires_at: Option<Instant>, parameters: Parameters) -> OverrideId {
+        self.purge_expired();
+        let id = OverrideId(self.next_id);
+        self.next_id += 1;
+        let at = self.overrides.partition_point(|existing| existing.priority > priority);
+        self.overrides.insert(at, Override { id, label, priority, expires_at, parameters });
+        id
+    }
+
+    /// Remove an override before it expires, returning it if it was still held.
+    pub fn remove(&mut self, id: OverrideId) -> Option<Override> {
+        self.purge_expired();
+        let at = self.overrides.iter().position(|existing| existing.id == id)?;
+        Some(self.overrides.remove(at))
+    }
+
+    /// Drop the overrides whose expiry has passed, returning them.
+    pub fn purge_expired(&mut self) -> Vec<Override> {
+        let now = Instant::now();
+        let (active, expired) = std::mem::take(&mut self.overrides)
+            .into_iter()
+            .partition(|existing| existing.is_active(now));
+        self.overrides = active;
+        expired
+    }
+
+    /// Iterate over the active overrides, in the order reads consult them.
+    pub fn active(&self) -> impl Iterator<Item = &Override> {
+        let now = Instant::now();
+        self.overrides.iter().filter(move |existing| existing.is_active(now))
+    }
+
+    /// Get a value of type `T`, resolving the active overrides before the base.
+    ///
+    /// The first layer holding the key always wins, even if its value is not a `T`.
+    pub fn get<T: 'static>(&self, key: &str) -> Option<&T> {
+        self.layer_for(key).get(key)
+    }
+
+    pub fn get_string(&self, key: &str) -> Option<String> {
+        self.layer_for(key).get_string(key)
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.layer_for(key).contains_key(key)
+    }
+
+    /// The active override that sets `key`, if any.
+    pub fn overridden_by(&self, key: &str) -> Option<&Override> {
+        self.active().find(|existing| existing.parameters.contains_key(key))
+    }
+
+    /// Build the effective parameters: the base with the active overrides merged on top.
+    pub fn resolve(&self) -> Parameters {
+        let mut resolved = self.base.clone();
+        let active: Vec<&Override> = self.active().collect();
+        for existing in active.into_iter().rev() {
+            resolved.merge(existing.parameters.clone());
+        }
+        resolved
+    }
+
+    fn layer_for(&self, key: &str) -> &Parameters {
+        self.overridden_by(key).map_or(&self.base, |existing| &existing.parameters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority_order_and_expiry() {
+        let mut manager = OverrideManager::new(Parameters::new().with("workers", 4).with("debug", false));
+        let low = manager.apply("tuning", 1, Parameters::new().with("workers", 8).with("debug", true));
+        manager.apply("hotfix", 5, Parameters::new().with("workers", 2));
+        manager.apply("newer tuning", 1, Parameters::new().with("debug", false));
+        manager.apply_until("expired", 9, Parameters::new().with("workers", 99), Instant::now());
+
+        assert_eq!(manager.get::<i32>("workers"), Some(&2));
+        assert_eq!(manager.get::<bool>("debug"), Some(&false));
+        assert_eq!(manager.resolve().get::<i32>("workers"), Some(&2));
+        assert_eq!(manager.active().map(Override::label).collect::<Vec<_>>(), ["hotfix", "newer tuning", "tuning"]);
+
+        assert_eq!(manager.purge_expired().len(), 1);
+        assert_eq!(manager.remove(low).unwrap().label(), "tuning");
+        assert!(manager.remove(low).is_none());
+        assert_eq!(manager.overridden_by("workers").map(Override::priority), Some(5));
+    }
+}
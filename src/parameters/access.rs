@@ -0,0 +1,151 @@
+use std::{collections::{BTreeMap, BTreeSet}, sync::{Arc, Mutex, MutexGuard}};
+use crate::value::ParameterValue;
+use super::core::Parameters;
+
+#[derive(Debug, Default)]
+struct AccessState {
+    accessed: BTreeSet<String>,
+    missing: BTreeMap<String, usize>,
+}
+
+/// Records which keys are read, when access tracking is enabled.
+#[derive(Debug, Default)]
+pub(crate) struct AccessLog {
+    state: Option<Mutex<AccessState>>,
+}
+
+impl AccessLog {
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.state.is_some()
+    }
+
+    /// A log in the same enabled state, with nothing recorded.
+    pub(crate) fn fresh(&self) -> Self {
+        Self {
+            state: self.state.as_ref().map(|_| Mutex::default()),
+        }
+    }
+
+    fn lock(&self) -> Option<MutexGuard<'_, AccessState>> {
+        self.state.as_ref().map(|state| state.lock().unwrap_or_else(|poisoned| poisoned.into_inner()))
+    }
+
+    pub(crate) fn record(&self, key: &str, found: bool) {
+        let Some(mut state) = self.lock() else {
+            return;
+        };
+        if found {
+            if !state.accessed.contains(key) {
+                state.accessed.insert(key.to_string());
+            }
+        } else {
+            *state.missing.entry(key.to_string()).or_default() += 1;
+        }
+    }
+}
+
+impl Parameters {
+    /// Enable access tracking, so the keys read and the lookups of absent keys are recorded.
+    ///
+    /// Every value lookup counts, such as `get`, `get_string`, `try_get` or
+    /// `get_i64`; `contains_key` and iteration do not. Clones start with
+    /// tracking in the same state but nothing recorded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("timeout", 30).with("legacy_mode", true).with_access_tracking();
+    /// params.get::<i32>("timeout");
+    /// params.get::<i32>("timout");
+    ///
+    /// assert_eq!(params.accessed_keys(), ["timeout"]);
+    /// assert_eq!(params.unused_keys(), ["legacy_mode"]);
+    /// assert_eq!(params.missing_lookups(), [("timout".to_string(), 1)]);
+    /// ```
+    pub fn with_access_tracking(mut self) -> Self {
+        self.set_access_tracking(true);
+        self
+    }
+
+    /// Turn access tracking on or off. Turning it off discards what was recorded.
+    pub fn set_access_tracking(&mut self, enabled: bool) {
+        if enabled != self.access.is_enabled() {
+            self.access = AccessLog { state: enabled.then(Mutex::default) };
+        }
+    }
+
+    /// Forget the recorded accesses, keeping tracking enabled.
+    pub fn reset_access_log(&mut self) {
+        self.access = self.access.fresh();
+    }
+
+    /// The keys that were found when looked up, sorted.
+    pub fn accessed_keys(&self) -> Vec<String> {
+        self.access.lock().map(|state| state.accessed.iter().cloned().collect()).unwrap_or_default()
+    }
+
+    /// The explicitly set keys that were never looked up, in iteration order.
+    ///
+    /// With tracking disabled, nothing counts as accessed and every key is listed.
+    pub fn unused_keys(&self) -> Vec<String> {
+        let state = self.access.lock();
+        self.keys()
+            .filter(|key| !state.as_ref().is_some_and(|state| state.accessed.contains(key.as_str())))
+            .map(String::from)
+            .collect()
+    }
+
+    /// The keys that were looked up but not present, with how many times each was tried, sorted by key.
+    pub fn missing_lookups(&self) -> Vec<(String, usize)> {
+        self.access.lock()
+            .map(|state| state.missing.iter().map(|(key, count)| (key.clone(), *count)).collect())
+            .unwrap_or_default()
+    }
+
+    /// Look up an explicit value through any alias, recording the access under the key as stored.
+    pub(crate) fn lookup(&self, key: &str) -> Option<&Arc<dyn ParameterValue>> {
+        let key = self.dealias(key);
+        let found = self.map.get_key_value(key).filter(|_| !self.expiry.is_expired(key));
+        self.access.record(found.map_or(key, |(stored, _)| stored.as_str()), found.is_some());
+        found.map(|(_, value)| value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracks_typed_and_default_lookups() {
+        let mut params = Parameters::new().with("workers", "4").with_access_tracking();
+        params.set_default("port", 8080);
+        assert_eq!(params.get_i64("workers"), Some(4));
+        assert_eq!(params.get_or_default::<i32>("port"), Some(&8080));
+        assert!(params.get_string("host").is_none());
+        assert!(params.try_get::<String>("host").is_err());
+
+        assert_eq!(params.accessed_keys(), ["port", "workers"]);
+        assert!(params.unused_keys().is_empty());
+        assert_eq!(params.missing_lookups(), [("host".to_string(), 2)]);
+
+        params.reset_access_log();
+        assert!(params.accessed_keys().is_empty());
+        params.set_access_tracking(false);
+        params.get::<i32>("host");
+        assert!(params.missing_lookups().is_empty());
+    }
+
+    #[test]
+    fn test_case_insensitive_reads_count_for_the_stored_key() {
+        let params = Parameters::new_case_insensitive()
+            .with("Content-Type", "text/html")
+            .with("Accept", "*/*")
+            .with_access_tracking();
+        assert_eq!(params.get_str("content-type"), Some("text/html"));
+
+        assert_eq!(params.accessed_keys(), ["Content-Type"]);
+        assert_eq!(params.unused_keys(), ["Accept"]);
+    }
+}
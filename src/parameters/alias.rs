@@ -0,0 +1,156 @@
+use std::{collections::BTreeMap, sync::Arc};
+use super::{core::Parameters, key::Key};
+
+/// Deprecated key names and the keys they now resolve to, shared between clones.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Aliases {
+    targets: Arc<BTreeMap<Key, Key>>,
+}
+
+impl Aliases {
+    pub(crate) fn target(&self, key: &str) -> Option<&Key> {
+        self.targets.get(key)
+    }
+
+    fn add(&mut self, old: Key, new: Key) {
+        let new = self.targets.get(&new).cloned().unwrap_or(new);
+        if new == old {
+            return;
+        }
+        let targets = Arc::make_mut(&mut self.targets);
+        for target in targets.values_mut().filter(|target| **target == old) {
+            *target = new.clone();
+        }
+        targets.insert(old, new);
+    }
+
+    pub(crate) fn extend(&mut self, other: &Aliases) {
+        for (old, new) in other.targets.iter() {
+            self.add(old.clone(), new.clone());
+        }
+    }
+}
+
+impl Parameters {
+    /// Make lookups of `old` resolve to `new`, for renaming a key without breaking its readers.
+    ///
+    /// Reads through `get`, `get_string`, `try_get`, `get_or_default`,
+    /// `contains_key` and the other lookups are redirected, and so are writes
+    /// and removals through `insert`, `try_insert`, `entry`, `remove` and
+    /// `rename_key`, so `old` never holds a value of its own. Each use of `old`
+    /// raises a deprecation warning for the subscribers registered with
+    /// `on_warning`. Aliasing a key that is itself an alias target follows the
+    /// chain, and an alias that would resolve to itself is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The deprecated key name.
+    /// * `new` - The key it was renamed to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new().with("http.timeout_ms", 500);
+    /// params.alias("timeout", "http.timeout_ms");
+    /// let warnings = Arc::new(Mutex::new(Vec::new()));
+    /// let sink = warnings.clone();
+    /// params.on_warning(move |warning| sink.lock().unwrap().push(warning.to_string()));
+    ///
+    /// assert_eq!(params.get::<i32>("timeout"), Some(&500));
+    /// assert_eq!(*warnings.lock().unwrap(), ["timeout: deprecated; use http.timeout_ms instead"]);
+    ///
+    /// params.insert("timeout", 250);
+    /// assert_eq!(params.get::<i32>("http.timeout_ms"), Some(&250));
+    /// assert_eq!(params.len(), 1);
+    /// ```
+    pub fn alias<K1: Into<Key>, K2: Into<Key>>(&mut self, old: K1, new: K2) {
+        self.aliases.add(old.into(), new.into());
+    }
+
+    /// Stop redirecting `old`, returning the key it resolved to.
+    pub fn remove_alias(&mut self, old: &str) -> Option<Key> {
+        Arc::make_mut(&mut self.aliases.targets).remove(old)
+    }
+
+    /// The key a lookup of `key` resolves to: its alias target, or the key itself.
+    pub fn canonical_key<'a>(&'a self, key: &'a str) -> &'a str {
+        self.aliases.target(key).map_or(key, Key::as_str)
+    }
+
+    /// Iterate over the aliases as `(old, new)` pairs.
+    pub fn aliases(&self) -> impl Iterator<Item = (&Key, &Key)> {
+        self.aliases.targets.iter()
+    }
+
+    /// Resolve an alias for a read, warning that the old name is deprecated.
+    pub(crate) fn dealias<'a>(&'a self, key: &'a str) -> &'a str {
+        match self.aliases.target(key) {
+            Some(target) => {
+                self.warnings.emit(key, &format!("deprecated; use {} instead", target));
+                target
+            }
+            None => key,
+        }
+    }
+
+    /// Resolve an alias for a write or removal, warning like `dealias`.
+    ///
+    /// Returns the target to use instead of `key`, or `None` if `key` is not an alias.
+    pub(crate) fn dealias_write(&self, key: &str) -> Option<Key> {
+        let target = self.aliases.target(key)?;
+        self.warnings.emit(key, &format!("deprecated; use {} instead", target));
+        Some(target.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chains_and_cycles() {
+        let mut params = Parameters::new().with("c", 1);
+        params.alias("a", "b");
+        params.alias("b", "c");
+        params.alias("c", "a");
+        params.set_default("d", 2);
+        params.alias("old_d", "d");
+
+        assert_eq!(params.canonical_key("a"), "c");
+        assert_eq!(params.get::<i32>("a"), Some(&1));
+        assert!(params.contains_key("b"));
+        assert_eq!(params.get_or_default::<i32>("old_d"), Some(&2));
+        assert_eq!(params.aliases().count(), 3);
+
+        let copy = params.clone();
+        params.remove_alias("a");
+        assert!(params.get::<i32>("a").is_none());
+        assert_eq!(copy.get::<i32>("a"), Some(&1));
+    }
+
+    #[test]
+    fn test_writes_and_removes_follow_aliases() {
+        let mut params = Parameters::new().with("new", 1);
+        params.alias("old", "new");
+
+        assert!(params.try_insert("old", 2).is_err());
+        assert_eq!(params.insert("old", 2).and_then(|old| old.as_any().downcast_ref::<i32>().copied()), Some(1));
+        assert_eq!(params.get::<i32>("new"), Some(&2));
+        assert_eq!(params.len(), 1);
+        assert_eq!(params.entry("old").key(), "new");
+
+        assert!(params.remove("old").is_some());
+        assert!(params.is_empty());
+        params.entry("old").or_insert(3).unwrap();
+        assert_eq!(params.keys().collect::<Vec<_>>(), ["new"]);
+
+        params.insert("other", 4);
+        params.remove("new");
+        params.rename_key("other", "old").unwrap();
+        assert_eq!(params.get::<i32>("new"), Some(&4));
+        assert_eq!(params.len(), 1);
+    }
+}
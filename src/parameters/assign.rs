@@ -0,0 +1,158 @@
+use std::{collections::BTreeSet, sync::Arc};
+use crate::{error::ParameterError, value::{parse_as, ParameterValue}, Result};
+use super::{core::Parameters, key::Key};
+
+fn invalid(entry: &str, reason: impl ToString) -> ParameterError {
+    ParameterError::InvalidOverride {
+        entry: entry.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+/// Infer the type of an override value. A value replacing an existing one is
+/// parsed as that value's type when it can be; otherwise `true`/`false`, then
+/// integers, then finite floats, and otherwise a string. Quotes force a string.
+fn infer_value(text: &str, existing: Option<&Arc<dyn ParameterValue>>) -> Arc<dyn ParameterValue> {
+    let quoted = ['"', '\''].iter().find_map(|quote| {
+        text.strip_prefix(*quote)?.strip_suffix(*quote)
+    });
+    if let Some(inner) = quoted.filter(|_| text.len() >= 2) {
+        return Arc::new(inner.to_string());
+    }
+    if let Some(value) = existing.and_then(|existing| parse_as(existing.as_ref(), text)) {
+        return value;
+    }
+    match text {
+        "true" => return Arc::new(true),
+        "false" => return Arc::new(false),
+        _ => {}
+    }
+    if let Ok(i) = text.parse::<i64>() {
+        return Arc::new(i);
+    }
+    let numeric = text.bytes().any(|b| b.is_ascii_digit());
+    match text.parse::<f64>() {
+        Ok(f) if numeric && f.is_finite() => Arc::new(f),
+        _ => Arc::new(text.to_string()),
+    }
+}
+
+/// Set `path` in `params`, descending into nested `Parameters` and creating
+/// them as needed. A key already stored with the remaining dots is set
+/// directly. Returns the key written at this level.
+fn set_path(params: &mut Parameters, path: &[&str], text: &str, entry: &str) -> Result<String> {
+    let joined = path.join(".");
+    let (first, rest) = path.split_first().ok_or_else(|| invalid(entry, "missing key"))?;
+    if rest.is_empty() || params.is_stored(&joined) {
+        let value = infer_value(text, params.stored(&joined));
+        params.try_insert_arc(Key::from(&joined), value)?;
+        return Ok(joined);
+    }
+
+    let mut child = match params.stored(first) {
+        Some(existing) => existing.as_any().downcast_ref::<Parameters>().cloned()
+            .ok_or_else(|| invalid(entry, format!("`{}` is not nested parameters but {}", first, existing.type_name())))?,
+        None => Parameters::with_backend(params.backend()),
+    };
+    set_path(&mut child, rest, text, entry)?;
+    params.try_insert_arc(Key::from(*first), Arc::new(child))?;
+    Ok(String::from(*first))
+}
+
+impl Parameters {
+    /// Apply overrides written as `key=value`, such as `db.pool.max=32` or `feature.x=true`.
+    ///
+    /// A value replacing an existing one is parsed as that value's type, so
+    /// `port=81` keeps a `u16` port a `u16`. Other values are stored as `bool`
+    /// for `true` and `false`, as `i64` or `f64` when they parse as a number,
+    /// and as `String` otherwise; wrap a value in single or double quotes to
+    /// keep it a string. Dotted keys descend
+    /// into nested `Parameters`, creating them as needed, unless the dotted
+    /// key itself is already stored. Either every override is applied or, on
+    /// error, none is.
+    ///
+    /// # Arguments
+    ///
+    /// * `overrides` - The `key=value` entries, applied in order.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that is `ParameterError::InvalidOverride` naming the entry if one has no `=`,
+    /// an empty key or path segment, or a path through a value that is not nested `Parameters`,
+    /// or `ParameterError::LimitExceeded` if the overrides would cross the capacity limits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new()
+    ///     .with("db", Parameters::new().with("host", "localhost"));
+    ///
+    /// params.apply_overrides(["db.pool.max=32", "feature.x=true", "ratio=0.5", "name='42'"]).unwrap();
+    ///
+    /// let db = params.get::<Parameters>("db").unwrap();
+    /// assert_eq!(db.get::<Parameters>("pool").unwrap().get::<i64>("max"), Some(&32));
+    /// assert_eq!(db.get::<&str>("host"), Some(&"localhost"));
+    /// assert_eq!(params.get::<Parameters>("feature").unwrap().get::<bool>("x"), Some(&true));
+    /// assert_eq!(params.get::<f64>("ratio"), Some(&0.5));
+    /// assert_eq!(params.get::<String>("name").map(String::as_str), Some("42"));
+    /// ```
+    pub fn apply_overrides<I, S>(&mut self, overrides: I) -> Result<()>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut next = self.explicit();
+        let mut touched = BTreeSet::new();
+        for entry in overrides {
+            let entry = entry.as_ref();
+            let (key, value) = entry.split_once('=').ok_or_else(|| invalid(entry, "expected `key=value`"))?;
+            let path: Vec<&str> = key.trim().split('.').collect();
+            if path.iter().any(|segment| segment.is_empty()) {
+                return Err(invalid(entry, "empty key or path segment"));
+            }
+            touched.insert(set_path(&mut next, &path, value.trim(), entry)?);
+        }
+
+        self.batch(|params| {
+            for key in touched {
+                if let Some(value) = next.stored(&key) {
+                    params.insert_arc(key, value.clone());
+                }
+            }
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed_entries_apply_nothing() {
+        let mut params = Parameters::new().with("port", 80).with("db.host", "a");
+
+        for (entry, reason) in [
+            ("verbose", "expected `key=value`"),
+            ("db..x=1", "empty key or path segment"),
+            ("=1", "empty key or path segment"),
+            ("port.max=1", "`port` is not nested parameters but i32"),
+        ] {
+            match params.apply_overrides(["port=81", entry]) {
+                Err(ParameterError::InvalidOverride { entry: got, reason: why }) => assert_eq!((got.as_str(), why.as_str()), (entry, reason)),
+                other => panic!("unexpected result for {}: {:?}", entry, other),
+            }
+        }
+        assert_eq!(params.get::<i32>("port"), Some(&80));
+        params.apply_overrides(["port=81"]).unwrap();
+        assert_eq!(params.get::<i32>("port"), Some(&81));
+
+        params.apply_overrides(["db.host = b", "url=http://x/?a=1", "inf=inf", "empty="]).unwrap();
+        assert_eq!(params.get::<String>("db.host").map(String::as_str), Some("b"));
+        assert_eq!(params.get::<String>("url").map(String::as_str), Some("http://x/?a=1"));
+        assert_eq!(params.get::<String>("inf").map(String::as_str), Some("inf"));
+        assert_eq!(params.get::<String>("empty").map(String::as_str), Some(""));
+    }
+}
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+use num_traits::NumCast;
+use crate::value::{as_number, ParameterValue};
+use super::core::Parameters;
+
+/// Interpolate two numeric values, storing the result as `b`'s type.
+///
+/// Integer results are rounded; if the rounded value does not fit `b`'s
+/// integer type it is stored as `f64` instead.
+fn blend_numbers(a: &dyn ParameterValue, b: &dyn ParameterValue, t: f64) -> Option<Arc<dyn ParameterValue>> {
+    let (from, to) = (as_number(a)?.as_f64(), as_number(b)?.as_f64());
+    let blended = from + (to - from) * t;
+    let any = b.as_any();
+
+    macro_rules! int {
+        ($($t:ty),+) => {
+            $(if any.is::<$t>() {
+                return Some(match <$t as NumCast>::from(blended.round()) {
+                    Some(v) => Arc::new(v),
+                    None => Arc::new(blended),
+                });
+            })+
+        };
+    }
+
+    int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    if any.is::<f32>() {
+        return Some(Arc::new(blended as f32));
+    }
+    Some(Arc::new(blended))
+}
+
+impl Parameters {
+    /// Linearly interpolate between two parameter sets.
+    ///
+    /// Keys holding numbers in both sets get `a + (b - a) * t`, stored as
+    /// `b`'s type (integers are rounded). Nested `Parameters` are blended key
+    /// by key. Any other key shared by both sets takes `b`'s value, and keys
+    /// present in only one set are kept as they are. Defaults and
+    /// sensitive-key patterns of both sets are carried over, `b`'s winning.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The set returned at `t = 0`.
+    /// * `b` - The set returned at `t = 1`.
+    /// * `t` - The blend factor; values outside `0..=1` extrapolate.
+    ///
+    /// # Returns
+    ///
+    /// The blended `Parameters`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let cautious = Parameters::new().with("rate", 0.1).with("batch", 10).with("mode", "safe");
+    /// let aggressive = Parameters::new().with("rate", 0.5).with("batch", 50).with("mode", "fast");
+    ///
+    /// let halfway = Parameters::blend(&cautious, &aggressive, 0.25);
+    /// assert!((halfway.get::<f64>("rate").unwrap() - 0.2).abs() < 1e-9);
+    /// assert_eq!(halfway.get::<i32>("batch"), Some(&20));
+    /// assert_eq!(halfway.get::<&str>("mode"), Some(&"fast"));
+    /// ```
+    pub fn blend(a: &Parameters, b: &Parameters, t: f64) -> Parameters {
+        let mut blended = a.clone();
+        blended.defaults_mut().extend(b.defaults.iter().map(|(k, v)| (k.clone(), v.clone())));
+        blended.redaction.extend(&b.redaction);
+
+        for (key, to) in b.iter() {
+            let value = match a.stored(key) {
+                Some(from) => match (
+                    from.as_any().downcast_ref::<Parameters>(),
+                    to.as_any().downcast_ref::<Parameters>(),
+                ) {
+                    (Some(from), Some(to)) => Arc::new(Parameters::blend(from, to, t)),
+                    _ => blend_numbers(from.as_ref(), to.as_ref(), t).unwrap_or_else(|| to.clone_arc()),
+                },
+                None => to.clone_arc(),
+            };
+            blended.map_mut().insert(key.clone(), value);
+        }
+        blended
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blend_types_and_nesting() {
+        let a = Parameters::new()
+            .with("level", 250u8)
+            .with("gain", 1.0f32)
+            .with("mixed", 1)
+            .with("only_a", true)
+            .with("pool", Parameters::new().with("size", 4));
+        let b = Parameters::new()
+            .with("level", -250i64)
+            .with("gain", 3.0f32)
+            .with("mixed", 2.0)
+            .with("only_b", "x")
+            .with("pool", Parameters::new().with("size", 8));
+
+        let mid = Parameters::blend(&a, &b, 0.5);
+        assert_eq!(mid.get::<i64>("level"), Some(&0));
+        assert_eq!(mid.get::<f32>("gain"), Some(&2.0));
+        assert_eq!(mid.get::<f64>("mixed"), Some(&1.5));
+        assert_eq!(mid.get::<bool>("only_a"), Some(&true));
+        assert_eq!(mid.get::<&str>("only_b"), Some(&"x"));
+        assert_eq!(mid.get::<Parameters>("pool").unwrap().get::<i32>("size"), Some(&6));
+
+        let overflow = Parameters::blend(&Parameters::new().with("n", 0u8), &Parameters::new().with("n", 255u8), 2.0);
+        assert_eq!(overflow.get::<f64>("n"), Some(&510.0));
+    }
+}
@@ -1,8 +1,9 @@
-use crate::{Parameters, value::ParameterValue};
+use crate::{Key, ParameterSchema, Parameters, Result, value::ParameterValue};
 
 #[derive(Default)]
 pub struct ParametersBuilder {
     params: Parameters,
+    schema: Option<ParameterSchema>,
 }
 
 /// A builder for creating `Parameters` instances.
@@ -20,9 +21,14 @@ pub struct ParametersBuilder {
 /// # Methods
 ///
 /// - `new`: Creates a new `ParametersBuilder` instance.
-/// - `add`: Adds a key-value pair to the parameters. The key must implement `Into<String>` and the value must implement `ParameterValue`.
+/// - `add`: Adds a key-value pair to the parameters. The key must implement `Into<Key>` and the value must implement `ParameterValue`.
+/// - `add_opt`: Adds a value if it is `Some`, and skips it if it is `None`.
+/// - `add_if`: Adds a value only if a condition holds.
+/// - `add_default`: Adds a value only if the key has not been added yet.
 /// - `merge`: Merges another `Parameters` instance into the builder.
+/// - `schema`: Sets a schema for `try_build` to validate against.
 /// - `build`: Consumes the builder and returns the constructed `Parameters` instance.
+/// - `try_build`: Like `build`, but fails if the parameters violate the schema.
 impl ParametersBuilder {
     pub fn new() -> Self {
         Self::default()
@@ -30,19 +36,139 @@ impl ParametersBuilder {
 
     pub fn add<K, V>(mut self, key: K, value: V) -> Self
     where
-        K: Into<String>,
+        K: Into<Key>,
         V: ParameterValue + 'static,
     {
         self.params.insert(key, value);
         self
     }
 
+    /// Add the value if it is `Some`; `None` leaves the key out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::ParametersBuilder;
+    ///
+    /// let proxy: Option<&str> = None;
+    /// let params = ParametersBuilder::new()
+    ///     .add_opt("timeout", Some(30))
+    ///     .add_opt("proxy", proxy)
+    ///     .build();
+    ///
+    /// assert!(params.contains_key("timeout"));
+    /// assert!(!params.contains_key("proxy"));
+    /// ```
+    pub fn add_opt<K, V>(self, key: K, value: Option<V>) -> Self
+    where
+        K: Into<Key>,
+        V: ParameterValue + 'static,
+    {
+        match value {
+            Some(value) => self.add(key, value),
+            None => self,
+        }
+    }
+
+    /// Add the value only if `condition` is true.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::ParametersBuilder;
+    ///
+    /// let verbose = false;
+    /// let params = ParametersBuilder::new()
+    ///     .add_if(verbose, "log_level", "debug")
+    ///     .add_if(!verbose, "log_level", "warn")
+    ///     .build();
+    ///
+    /// assert_eq!(params.get::<&str>("log_level"), Some(&"warn"));
+    /// ```
+    pub fn add_if<K, V>(self, condition: bool, key: K, value: V) -> Self
+    where
+        K: Into<Key>,
+        V: ParameterValue + 'static,
+    {
+        if condition {
+            self.add(key, value)
+        } else {
+            self
+        }
+    }
+
+    /// Add the value only if the key has not been added yet, so earlier values win.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::ParametersBuilder;
+    ///
+    /// let params = ParametersBuilder::new()
+    ///     .add("port", 9090)
+    ///     .add_default("port", 8080)
+    ///     .add_default("host", "localhost")
+    ///     .build();
+    ///
+    /// assert_eq!(params.get::<i32>("port"), Some(&9090));
+    /// assert_eq!(params.get::<&str>("host"), Some(&"localhost"));
+    /// ```
+    pub fn add_default<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<Key>,
+        V: ParameterValue + 'static,
+    {
+        let key = key.into();
+        if !self.params.contains_key(&key) {
+            self.params.insert(key, value);
+        }
+        self
+    }
+
     pub fn merge(mut self, other: Parameters) -> Self {
         self.params.merge(other);
         self
     }
 
+    /// Validate the built parameters against `schema` in `try_build`, replacing any previous schema.
+    pub fn schema(mut self, schema: ParameterSchema) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Build the parameters without validating them, even if a schema is set.
     pub fn build(self) -> Parameters {
         self.params
     }
+
+    /// Build the parameters, validating them against the schema if one is set.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Parameters`, or `ParameterError::ValidationFailed` listing
+    /// every missing key, type mismatch and out-of-range value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{FieldSchema, ParameterError, ParameterKind, ParameterSchema, ParametersBuilder};
+    ///
+    /// let schema = ParameterSchema::new()
+    ///     .field("host", FieldSchema::new(ParameterKind::String).required())
+    ///     .field("port", FieldSchema::new(ParameterKind::Integer).range(1.0, 65535.0));
+    ///
+    /// let params = ParametersBuilder::new().schema(schema.clone()).add("host", "db").add("port", 5432).try_build();
+    /// assert!(params.is_ok());
+    ///
+    /// match ParametersBuilder::new().schema(schema).add("port", 0).try_build() {
+    ///     Err(ParameterError::ValidationFailed(violations)) => assert_eq!(violations.len(), 2),
+    ///     other => panic!("unexpected result: {:?}", other),
+    /// }
+    /// ```
+    pub fn try_build(self) -> Result<Parameters> {
+        if let Some(schema) = &self.schema {
+            schema.validate(&self.params)?;
+        }
+        Ok(self.params)
+    }
 }
\ No newline at end of file
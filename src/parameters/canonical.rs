@@ -0,0 +1,171 @@
+use std::fmt::Write;
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use super::{core::Parameters, json::value_to_json};
+
+/// Write `json` with object keys sorted and no whitespace, so equal content always encodes the same.
+///
+/// With `normalize_numbers`, floats without a fractional part are written as
+/// integers and `-0.0` as `0`, so `30` and `30.0` encode alike.
+pub(crate) fn write_canonical(json: &Value, normalize_numbers: bool, out: &mut String) {
+    match json {
+        Value::Object(object) => {
+            let mut entries: Vec<_> = object.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(value, normalize_numbers, out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, normalize_numbers, out);
+            }
+            out.push(']');
+        }
+        Value::Number(n) if normalize_numbers && n.is_f64() => {
+            let f = n.as_f64().unwrap_or_default();
+            if f.fract() == 0.0 && f.abs() < 9_007_199_254_740_992.0 {
+                let _ = write!(out, "{}", f as i64);
+            } else {
+                out.push_str(&n.to_string());
+            }
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+/// Convert `value` to JSON for the canonical encoding, replacing each
+/// `Secret` with `sha256:` and the hex digest of its exposed value's encoding.
+fn canonical_json(value: &dyn crate::ParameterValue) -> Value {
+    if let Some(inner) = value.exposed() {
+        let mut encoded = String::new();
+        write_canonical(&canonical_json(inner), true, &mut encoded);
+        let mut digest = String::from("sha256:");
+        for byte in Sha256::digest(encoded.as_bytes()) {
+            let _ = write!(digest, "{byte:02x}");
+        }
+        return Value::String(digest);
+    }
+    match value.as_any().downcast_ref::<Parameters>() {
+        Some(nested) => Value::Object(nested.iter()
+            .map(|(key, value)| (String::from(key), canonical_json(value.as_ref())))
+            .collect()),
+        None => value_to_json(value),
+    }
+}
+
+impl Parameters {
+    /// Encode the effective parameters in a stable canonical form.
+    ///
+    /// The encoding is the JSON object of every key `get_or_default` would
+    /// resolve, explicit values taking precedence over defaults, written as
+    /// UTF-8 with:
+    ///
+    /// - no whitespace between tokens,
+    /// - object keys, including those of nested `Parameters`, sorted by their UTF-8 bytes,
+    /// - strings escaped as `serde_json` escapes them,
+    /// - integers in decimal, and floats without a fractional part (below 2^53
+    ///   in magnitude) written as integers, so `30` and `30.0` encode alike;
+    ///   other floats use their shortest round-trip form, and NaN and infinities are `null`.
+    ///
+    /// Values are converted as in `to_json_unredacted`, so the bytes include
+    /// the values of sensitive keys and should be treated like the values
+    /// themselves. A `Secret` is encoded as the string `sha256:` followed by
+    /// the hex SHA-256 digest of its value's canonical encoding, so rotating
+    /// it changes the bytes without revealing it.
+    ///
+    /// # Returns
+    ///
+    /// The encoded bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new().with("b", 1.0).with("a", "x");
+    /// params.set_default("c", true);
+    ///
+    /// assert_eq!(params.canonical_bytes(), br#"{"a":"x","b":1,"c":true}"#);
+    /// ```
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        let effective: Map<String, Value> = self.defaults.iter()
+            .chain(self.iter())
+            .map(|(key, value)| (String::from(key), canonical_json(value.as_ref())))
+            .collect();
+
+        let mut out = String::new();
+        write_canonical(&Value::Object(effective), true, &mut out);
+        out.into_bytes()
+    }
+
+    /// The SHA-256 digest of `canonical_bytes`.
+    ///
+    /// Two parameter sets with the same effective values have the same hash,
+    /// whatever their insertion order, storage backend, or numeric widths, so
+    /// it works as a cache key for the effective configuration.
+    ///
+    /// # Returns
+    ///
+    /// The 32-byte digest; format it with `{:02x}` per byte for a hex string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let a = Parameters::new().with("workers", 4u8).with("name", "api");
+    /// let b = Parameters::with_hash_backend().with("name", "api").with("workers", 4i64);
+    ///
+    /// assert_eq!(a.content_hash(), b.content_hash());
+    /// assert_ne!(a.content_hash(), a.clone().with("workers", 5).content_hash());
+    /// ```
+    pub fn content_hash(&self) -> [u8; 32] {
+        Sha256::digest(self.canonical_bytes()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use crate::Secret;
+    use super::*;
+
+    #[test]
+    fn test_numbers_and_nesting_are_normalized() {
+        let mut out = String::new();
+        write_canonical(&json!({ "z": [1.5, -0.0, 2e20, 1e300], "a": { "y": 3.0, "x": "é\n" } }), true, &mut out);
+        assert_eq!(out, r#"{"a":{"x":"é\n","y":3},"z":[1.5,0,2e20,1e300]}"#);
+
+        let mut plain = String::new();
+        write_canonical(&json!({ "y": 3.0 }), false, &mut plain);
+        assert_eq!(plain, r#"{"y":3.0}"#);
+
+        let mut params = Parameters::new().with("port", 80);
+        params.set_default("port", 8080);
+        params.set_default("host", "localhost");
+        assert_eq!(params.content_hash(), Parameters::new().with("host", "localhost").with("port", 80).content_hash());
+    }
+
+    #[test]
+    fn test_secrets_are_encoded_as_digests() {
+        let secret = |value: &str| Parameters::new().with("db", Parameters::new().with("password", Secret::new(value.to_string())));
+        let bytes = String::from_utf8(secret("hunter2").canonical_bytes()).unwrap();
+
+        assert!(bytes.starts_with(r#"{"db":{"password":"sha256:"#), "{bytes}");
+        assert!(!bytes.contains("hunter2"));
+        assert_eq!(secret("hunter2").content_hash(), secret("hunter2").content_hash());
+        assert_ne!(secret("hunter2").content_hash(), secret("hunter3").content_hash());
+    }
+}
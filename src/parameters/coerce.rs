@@ -0,0 +1,359 @@
+use std::time::Duration;
+use num_traits::NumCast;
+use serde_json::Value;
+use crate::{
+    error::ParameterError,
+    value::{as_number, as_str, parse_duration, ByteSize, HumanDuration, Number, ParameterValue},
+    Result,
+};
+use super::core::Parameters;
+
+/// Whether `T` is a floating-point type, judged by whether it can hold one half.
+fn is_float<T: NumCast>() -> bool {
+    T::from(0.5f64).and_then(|half| half.to_f64()) == Some(0.5)
+}
+
+/// Convert `number` to `T` if no information is lost, allowing only the
+/// rounding of a float to a narrower float type.
+fn cast_exact<T: NumCast>(number: Number) -> Option<T> {
+    match number {
+        Number::Int(i) => T::from(i).filter(|cast| cast.to_i128() == Some(i)),
+        Number::Float(f) if f.is_nan() => T::from(f).filter(|_| is_float::<T>()),
+        Number::Float(f) => T::from(f).filter(|cast| {
+            cast.to_f64() == Some(f)
+                || (is_float::<T>() && cast.to_f32() == Some(f as f32) && (f as f32).is_finite())
+        }),
+    }
+}
+
+fn number_of(value: &dyn ParameterValue) -> Option<Number> {
+    if let Some(number) = as_number(value) {
+        return Some(number);
+    }
+    if let Some(Value::Number(n)) = value.as_any().downcast_ref::<Value>() {
+        return n.as_i64().map(|i| Number::Int(i.into()))
+            .or_else(|| n.as_u64().map(|u| Number::Int(u.into())))
+            .or_else(|| n.as_f64().map(Number::Float));
+    }
+    None
+}
+
+impl Parameters {
+    /// Report a successful parse of a string value as a lenient coercion.
+    fn parsed<T>(&self, key: &str, target: &str, parsed: Option<T>) -> Option<T> {
+        if parsed.is_some() {
+            self.warn(key, &format!("parsed {} from a string value", target));
+        }
+        parsed
+    }
+
+    /// Get a value as `i64`, whatever integer type it was stored as.
+    ///
+    /// Integers of any width are converted when they fit, floats when they
+    /// have no fractional part, and strings when they parse as an integer.
+    /// Parsing a string raises a warning through `on_warning` subscribers.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// The value as `i64`, or `None` if the key is missing or the value is not a representable integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("port", 8080u16).with("workers", "4").with("ratio", 0.5);
+    ///
+    /// assert_eq!(params.get_i64("port"), Some(8080));
+    /// assert_eq!(params.get_i64("workers"), Some(4));
+    /// assert_eq!(params.get_i64("ratio"), None);
+    /// ```
+    pub fn get_i64(&self, key: &str) -> Option<i64> {
+        let value = self.lookup(key)?.as_ref();
+        match number_of(value) {
+            Some(Number::Int(i)) => i64::try_from(i).ok(),
+            Some(Number::Float(f)) if f.fract() == 0.0 && f >= i64::MIN as f64 && f < i64::MAX as f64 => Some(f as i64),
+            Some(Number::Float(_)) => None,
+            None => self.parsed(key, "i64", as_str(value)?.trim().parse().ok()),
+        }
+    }
+
+    /// Get a value as `f64`, whatever numeric type it was stored as.
+    ///
+    /// Integers and floats of any width are converted, and strings when they
+    /// parse as a number.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// The value as `f64`, or `None` if the key is missing or the value is not numeric.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("timeout", 30).with("ratio", "0.25");
+    ///
+    /// assert_eq!(params.get_f64("timeout"), Some(30.0));
+    /// assert_eq!(params.get_f64("ratio"), Some(0.25));
+    /// ```
+    pub fn get_f64(&self, key: &str) -> Option<f64> {
+        let value = self.lookup(key)?.as_ref();
+        match number_of(value) {
+            Some(number) => Some(number.as_f64()),
+            None => self.parsed(key, "f64", as_str(value)?.trim().parse().ok()),
+        }
+    }
+
+    /// Get a numeric value as any numeric type, whatever type it was stored as.
+    ///
+    /// A `u32` can be read as an `i64`, an `f64` as an `f32` or a whole `f64`
+    /// as a `u16`, as long as the value survives the conversion: integers must
+    /// fit and keep their value, and floats read as integers must be whole.
+    /// Floats read as `f32` are rounded to the nearest `f32` within its range.
+    /// Strings are not parsed; use `try_get_parse` for those.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the converted value, `ParameterError::KeyNotFound`
+    /// if the key is missing, `TypeMismatch` if the value is not a number, or
+    /// `ConversionFailed` if it does not fit in `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("workers", 8u32).with("ratio", 0.5f64).with("budget", 3e9);
+    ///
+    /// assert_eq!(params.get_num::<i64>("workers").unwrap(), 8);
+    /// assert_eq!(params.get_num::<f32>("ratio").unwrap(), 0.5);
+    /// assert_eq!(params.get_num::<u64>("budget").unwrap(), 3_000_000_000);
+    /// assert!(params.get_num::<i32>("budget").is_err());
+    /// assert!(params.get_num::<u8>("ratio").is_err());
+    /// ```
+    pub fn get_num<T: NumCast>(&self, key: &str) -> Result<T> {
+        let value = self.lookup(key).ok_or_else(|| self.missing_key(key))?;
+        let number = number_of(value.as_ref()).ok_or_else(|| ParameterError::TypeMismatch {
+            expected: "number",
+            actual: value.type_name(),
+        })?;
+        cast_exact(number).ok_or_else(|| {
+            self.conversion_failed(key, value.as_ref(), std::any::type_name::<T>(), "value out of range or not representable")
+        })
+    }
+
+    /// Get a value as `bool`.
+    ///
+    /// Besides stored `bool`s, the integers `0` and `1` and the strings
+    /// `true`/`false`, `yes`/`no`, `on`/`off` and `1`/`0` (in any case) are
+    /// accepted.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// The value as `bool`, or `None` if the key is missing or the value is not boolean-like.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("debug", "yes").with("cache", 0u8).with("tls", true);
+    ///
+    /// assert_eq!(params.get_bool("debug"), Some(true));
+    /// assert_eq!(params.get_bool("cache"), Some(false));
+    /// assert_eq!(params.get_bool("tls"), Some(true));
+    /// ```
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        let value = self.lookup(key)?.as_ref();
+        let any = value.as_any();
+        if let Some(b) = any.downcast_ref::<bool>().copied().or_else(|| any.downcast_ref::<Value>().and_then(Value::as_bool)) {
+            return Some(b);
+        }
+        match number_of(value) {
+            Some(Number::Int(0)) => return Some(false),
+            Some(Number::Int(1)) => return Some(true),
+            Some(_) => return None,
+            None => {}
+        }
+        let parsed = match as_str(value)?.trim().to_ascii_lowercase().as_str() {
+            "true" | "yes" | "on" | "1" => Some(true),
+            "false" | "no" | "off" | "0" => Some(false),
+            _ => None,
+        };
+        self.parsed(key, "bool", parsed)
+    }
+
+    /// Get a value as a `Duration`.
+    ///
+    /// Stored `Duration`s and `HumanDuration`s are returned as is, numbers
+    /// are read as seconds, and strings such as `"250ms"` or `"2h30m"` are
+    /// parsed with `parse_duration`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// The value as a `Duration`, or `None` if the key is missing or the value is not a duration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new()
+    ///     .with("timeout", "2h30m")
+    ///     .with("retry_delay", Duration::from_millis(250))
+    ///     .with("ttl", 60);
+    ///
+    /// assert_eq!(params.get_duration("timeout"), Some(Duration::from_secs(9000)));
+    /// assert_eq!(params.get_duration("retry_delay"), Some(Duration::from_millis(250)));
+    /// assert_eq!(params.get_duration("ttl"), Some(Duration::from_secs(60)));
+    /// ```
+    pub fn get_duration(&self, key: &str) -> Option<Duration> {
+        let value = self.lookup(key)?.as_ref();
+        let any = value.as_any();
+        if let Some(duration) = any.downcast_ref::<Duration>().copied().or_else(|| any.downcast_ref::<HumanDuration>().map(|d| d.0)) {
+            return Some(duration);
+        }
+        match number_of(value) {
+            Some(Number::Int(secs)) => u64::try_from(secs).ok().map(Duration::from_secs),
+            Some(Number::Float(secs)) => Duration::try_from_secs_f64(secs).ok(),
+            None => parse_duration(as_str(value)?).ok(),
+        }
+    }
+
+    /// Get a size in bytes.
+    ///
+    /// Stored `ByteSize`s are returned as is, non-negative integers are read
+    /// as bytes, and strings such as `"64KB"` or `"10MiB"` are parsed as a
+    /// `ByteSize`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes, or `None` if the key is missing or the value is not a size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{ByteSize, Parameters};
+    ///
+    /// let params = Parameters::new()
+    ///     .with("buffer", "64KiB")
+    ///     .with("cache", ByteSize(1 << 30))
+    ///     .with("chunk", 512);
+    ///
+    /// assert_eq!(params.get_bytes("buffer"), Some(65_536));
+    /// assert_eq!(params.get_bytes("cache"), Some(1 << 30));
+    /// assert_eq!(params.get_bytes("chunk"), Some(512));
+    /// ```
+    pub fn get_bytes(&self, key: &str) -> Option<u64> {
+        let value = self.lookup(key)?.as_ref();
+        if let Some(size) = value.as_any().downcast_ref::<ByteSize>() {
+            return Some(size.as_u64());
+        }
+        match number_of(value) {
+            Some(Number::Int(bytes)) => u64::try_from(bytes).ok(),
+            Some(Number::Float(_)) => None,
+            None => as_str(value)?.parse::<ByteSize>().ok().map(|size| size.as_u64()),
+        }
+    }
+
+    /// Borrow a string value, whether it was stored as `String`, `&str` or a JSON string.
+    ///
+    /// Unlike `get_string`, this does not format non-string values.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// The string, or `None` if the key is missing or the value is not a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("host", "localhost").with("name", "api".to_string()).with("port", 80);
+    ///
+    /// assert_eq!(params.get_str("host"), Some("localhost"));
+    /// assert_eq!(params.get_str("name"), Some("api"));
+    /// assert_eq!(params.get_str("port"), None);
+    /// ```
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        as_str(self.lookup(key)?.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn test_coercion_edges() {
+        let params = Parameters::new()
+            .with("big", u64::MAX)
+            .with("whole", 3.0f32)
+            .with("json", json!(7))
+            .with("flag", json!(false))
+            .with("padded", " 12 ")
+            .with("word", "maybe");
+
+        assert_eq!(params.get_i64("big"), None);
+        assert_eq!(params.get_f64("big"), Some(u64::MAX as f64));
+        assert_eq!(params.get_i64("whole"), Some(3));
+        assert_eq!(params.get_i64("json"), Some(7));
+        assert_eq!(params.get_bool("flag"), Some(false));
+        assert_eq!(params.get_i64("padded"), Some(12));
+        assert_eq!(params.get_bool("word"), None);
+        assert_eq!(params.get_i64("missing"), None);
+        assert_eq!(params.get_duration("padded"), Some(std::time::Duration::from_secs(12)));
+        assert_eq!(params.get_duration("word"), None);
+    }
+
+    #[test]
+    fn test_get_num_is_exact() {
+        let params = Parameters::new()
+            .with("small", -3i8)
+            .with("big", u64::MAX)
+            .with("precise", 9_007_199_254_740_993i64)
+            .with("json", json!(2.5))
+            .with("huge", 1e300)
+            .with("text", "12");
+
+        assert_eq!(params.get_num::<i128>("big").unwrap(), u64::MAX as i128);
+        assert_eq!(params.get_num::<f32>("small").unwrap(), -3.0);
+        assert_eq!(params.get_num::<f64>("json").unwrap(), 2.5);
+        assert!(params.get_num::<u32>("small").is_err());
+        assert!(params.get_num::<i64>("big").is_err());
+        assert!(params.get_num::<f64>("precise").is_err());
+        assert!(params.get_num::<f32>("huge").is_err());
+        assert!(matches!(params.get_num::<i32>("text"), Err(ParameterError::TypeMismatch { expected: "number", .. })));
+    }
+}
@@ -0,0 +1,166 @@
+use std::{mem, sync::Arc};
+use crate::value::{BoolVec, FloatVec, IntVec, ParameterValue, StrVec};
+use super::{core::Parameters, store::Store};
+
+/// When to compact automatically, and how many mutations have happened since the last compaction.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CompactionPolicy {
+    every: Option<usize>,
+    mutations: usize,
+}
+
+/// What a call to [`Parameters::compact`] did.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompactionReport {
+    /// The number of explicit values and defaults after compaction.
+    pub entries: usize,
+    /// Bytes of unused string and vector capacity released by values.
+    pub reclaimed_bytes: usize,
+}
+
+/// Release the spare capacity of a uniquely owned value, returning the bytes freed.
+fn shrink_value(value: &mut Arc<dyn ParameterValue>) -> usize {
+    let Some(value) = Arc::get_mut(value) else {
+        return 0;
+    };
+    let any = value.as_any_mut();
+    if let Some(text) = any.downcast_mut::<String>() {
+        let before = text.capacity();
+        text.shrink_to_fit();
+        return before - text.capacity();
+    }
+    if let Some(nested) = any.downcast_mut::<Parameters>() {
+        return nested.compact().reclaimed_bytes;
+    }
+
+    macro_rules! int_vec {
+        ($($t:ty),+) => {
+            $(if let Some(items) = any.downcast_mut::<IntVec<$t>>() {
+                let before = items.0.capacity();
+                items.0.shrink_to_fit();
+                return (before - items.0.capacity()) * mem::size_of::<$t>();
+            })+
+        };
+    }
+    int_vec!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    macro_rules! list {
+        ($($t:ty => $item:ty),+) => {
+            $(if let Some(items) = any.downcast_mut::<$t>() {
+                let before = items.0.capacity();
+                items.0.shrink_to_fit();
+                return (before - items.0.capacity()) * mem::size_of::<$item>();
+            })+
+        };
+    }
+    list!(StrVec => String, FloatVec => f64, BoolVec => bool);
+    0
+}
+
+/// Rebuild a map from its entries, which packs it tightly, and shrink its values.
+fn compact_map(map: &mut Arc<Store>) -> usize {
+    let Some(map) = Arc::get_mut(map) else {
+        return 0;
+    };
+    let mut reclaimed = 0;
+    let mut rebuilt = map.new_like();
+    rebuilt.extend(mem::take(map)
+        .into_iter()
+        .map(|(key, mut value)| {
+            reclaimed += shrink_value(&mut value);
+            (key, value)
+        }));
+    *map = rebuilt;
+    reclaimed
+}
+
+impl Parameters {
+    /// Rebuild the internal storage tightly after heavy insert and remove churn.
+    ///
+    /// The maps are rebuilt from their entries and the spare capacity
+    /// of `String` and list values and nested `Parameters` is
+    /// released. Storage shared with a clone is left alone, since copying it
+    /// would use more memory rather than less. The savings from repacking the
+    /// map nodes themselves are not counted in the report.
+    ///
+    /// # Returns
+    ///
+    /// A `CompactionReport` with the remaining entry count and the bytes released.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new();
+    /// let mut name = String::with_capacity(1024);
+    /// name.push_str("api");
+    /// params.insert("name", name);
+    ///
+    /// let report = params.compact();
+    /// assert_eq!(report.entries, 1);
+    /// assert!(report.reclaimed_bytes >= 1000);
+    /// ```
+    pub fn compact(&mut self) -> CompactionReport {
+        self.compaction.mutations = 0;
+        let reclaimed_bytes = compact_map(&mut self.map) + compact_map(&mut self.defaults);
+        CompactionReport {
+            entries: self.map.len() + self.defaults.len(),
+            reclaimed_bytes,
+        }
+    }
+
+    /// Compact automatically once every `mutations` inserts and removals.
+    ///
+    /// Pass `None` to turn automatic compaction off, which is the default.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutations` - How many mutations to allow between compactions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new();
+    /// params.compact_every(Some(3));
+    /// params.insert("a", 1);
+    /// params.insert("b", 2);
+    /// assert_eq!(params.mutations_since_compact(), 2);
+    /// params.remove("a");
+    /// assert_eq!(params.mutations_since_compact(), 0);
+    /// ```
+    pub fn compact_every(&mut self, mutations: Option<usize>) {
+        self.compaction.every = mutations.filter(|&n| n > 0);
+    }
+
+    /// The number of inserts and removals since the last compaction, for custom compaction policies.
+    pub fn mutations_since_compact(&self) -> usize {
+        self.compaction.mutations
+    }
+
+    /// Count a mutation, compacting if the automatic threshold is reached.
+    pub(crate) fn record_mutation(&mut self) {
+        self.compaction.mutations += 1;
+        if self.compaction.every.is_some_and(|every| self.compaction.mutations >= every) {
+            self.compact();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_storage_is_left_alone() {
+        let mut params = Parameters::new().with("scores", IntVec::<u32>(Vec::with_capacity(64)));
+        params.get_mut::<IntVec<u32>>("scores").unwrap().0.push(1);
+        let snapshot = params.clone();
+
+        assert_eq!(params.compact().reclaimed_bytes, 0);
+        drop(snapshot);
+        assert_eq!(params.compact().reclaimed_bytes, 63 * 4);
+        assert_eq!(params.get::<IntVec<u32>>("scores").unwrap().0, vec![1]);
+    }
+}
@@ -1,26 +1,109 @@
-use std::{
-    collections::BTreeMap, sync::Arc
-};
+use std::{collections::{BTreeMap, HashMap}, str::FromStr, sync::{mpsc::{self, Receiver}, Arc}};
 use crate::{
     error::ParameterError,
-    value::ParameterValue, Result,
+    value::{as_str, share, ParameterValue, Secret}, Result,
+};
+use super::{
+    access::AccessLog,
+    alias::Aliases,
+    compact::CompactionPolicy,
+    diff::same_value,
+    events::{ChangeBatch, ChangeNotifier, ParameterChange},
+    journal::Journal,
+    key::Key,
+    limits::{CapacityLimits, EvalLimits},
+    meta::MetaTable,
+    optional::NullPolicy,
+    parsers::ParserRegistry,
+    rename::RenamePolicy,
+    redact::Redaction,
+    store::{IntoIter, Iter, StorageBackend, Store},
+    ttl::Expiry,
+    warnings::WarningEmitter,
 };
 
-#[derive(Debug, Default)]
+/// A set of typed values stored by key, with optional defaults.
+///
+/// # Redaction
+///
+/// Output meant for people masks the values of keys marked with
+/// `mark_sensitive`, and every `Secret`, as `***`: `Display`, `Debug`,
+/// `fmt_pretty`, `get_string`, `to_string_map`, `to_json`, GraphQL output and
+/// error messages.
+///
+/// The data sinks are lossless instead, so that what they write loads back
+/// as the same values: `Serialize`, `to_json_unredacted`, `to_msgpack`,
+/// `to_bincode`, `to_js_value`, bundles, patches and `ParameterStore::save`
+/// keep sensitive values and expose `Secret`s. Only send their output to
+/// destinations trusted with the secrets. `canonical_bytes` encodes a digest
+/// of each `Secret` instead, so hashes change when a secret is rotated.
+#[derive(Default)]
 pub struct Parameters {
-    map: BTreeMap<String, Arc<dyn ParameterValue>>,
+    pub(crate) map: Arc<Store>,
+    pub(crate) defaults: Arc<Store>,
+    pub(crate) notifier: ChangeNotifier,
+    pub(crate) redaction: Redaction,
+    pub(crate) warnings: WarningEmitter,
+    pub(crate) compaction: CompactionPolicy,
+    pub(crate) eval_limits: EvalLimits,
+    pub(crate) capacity_limits: CapacityLimits,
+    pub(crate) journal: Journal,
+    pub(crate) access: AccessLog,
+    pub(crate) meta: MetaTable,
+    pub(crate) aliases: Aliases,
+    pub(crate) null_policy: NullPolicy,
+    pub(crate) expiry: Expiry,
+    pub(crate) parsers: ParserRegistry,
+    pub(crate) rename_policy: RenamePolicy,
 }
 
+/// Cloning is O(1): the clone shares the stored values until either side
+/// is modified, at which point the modified side copies its map. Change and
+/// warning subscribers are not copied, nor are changes and accesses recorded by tracking.
 impl Clone for Parameters {
     fn clone(&self) -> Self {
         Self {
-            map: self.map.iter()
-                .map(|(k, v)| (k.clone(), v.clone_arc()))
-                .collect()
+            map: self.map.clone(),
+            defaults: self.defaults.clone(),
+            notifier: ChangeNotifier::default(),
+            redaction: self.redaction.clone(),
+            warnings: WarningEmitter::default(),
+            compaction: self.compaction.clone(),
+            eval_limits: self.eval_limits,
+            capacity_limits: self.capacity_limits,
+            journal: Journal { enabled: self.journal.enabled, changes: Vec::new() },
+            access: self.access.fresh(),
+            meta: self.meta.clone(),
+            aliases: self.aliases.clone(),
+            null_policy: self.null_policy,
+            expiry: self.expiry.clone(),
+            parsers: self.parsers.clone(),
+            rename_policy: self.rename_policy,
+        }
+    }
+}
+
+/// Two `Parameters` are equal when they hold the same keys with values that
+/// compare equal through `ParameterValue::eq_dyn`, for both explicit values
+/// and defaults. Expired values, subscribers and sensitive-key patterns are ignored.
+impl PartialEq for Parameters {
+    fn eq(&self, other: &Self) -> bool {
+        fn same(a: &Store, b: &Store) -> bool {
+            a.len() == b.len()
+                && a.iter().all(|(key, va)| b.get(key).is_some_and(|vb| same_value(va, vb)))
         }
+        let explicit = if self.expiry.is_empty() && other.expiry.is_empty() {
+            Arc::ptr_eq(&self.map, &other.map) || same(&self.map, &other.map)
+        } else {
+            self.len() == other.len()
+                && self.iter().all(|(key, va)| other.stored(key).is_some_and(|vb| same_value(va, vb)))
+        };
+        explicit && (Arc::ptr_eq(&self.defaults, &other.defaults) || same(&self.defaults, &other.defaults))
     }
 }
 
+impl Eq for Parameters {}
+
 impl Parameters {
     /// Create a new empty `Parameters` instance.
     ///
@@ -34,13 +117,111 @@ impl Parameters {
         Self::default()
     }
 
+    /// Create an empty `Parameters` stored in the given backend.
+    ///
+    /// Every method behaves the same whichever backend is chosen, except
+    /// that iteration, display and serialization follow the backend's order.
+    /// Sets built from another set (`merge`, `flatten`, `map_values`, ...)
+    /// keep the backend of the set they start from.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend` - The map implementation to use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{Parameters, StorageBackend};
+    ///
+    /// let params = Parameters::with_backend(StorageBackend::Hash).with("port", 8080);
+    /// assert_eq!(params.backend(), StorageBackend::Hash);
+    /// assert_eq!(params.get::<i32>("port"), Some(&8080));
+    /// ```
+    pub fn with_backend(backend: StorageBackend) -> Self {
+        Self {
+            map: Arc::new(Store::new(backend)),
+            defaults: Arc::new(Store::new(backend)),
+            ..Self::default()
+        }
+    }
+
+    /// Create an empty `Parameters` backed by a `HashMap`, for faster lookups in large sets.
+    ///
+    /// Iteration order is unspecified, and `iter_prefix` scans every key.
+    pub fn with_hash_backend() -> Self {
+        Self::with_backend(StorageBackend::Hash)
+    }
+
+    /// Create an empty `Parameters` backed by an `IndexMap`, which iterates in insertion order.
+    ///
+    /// Removing a key shifts the later keys down, which is O(n).
+    #[cfg(feature = "indexmap")]
+    pub fn with_indexmap_backend() -> Self {
+        Self::with_backend(StorageBackend::Insertion)
+    }
+
+    /// Create an empty `Parameters` whose keys are matched ignoring case, as
+    /// for HTTP headers or Windows environment variables.
+    ///
+    /// Every lookup, insert and removal treats `Content-Type` and
+    /// `content-type` as the same key, and so do sensitive-key patterns. A key
+    /// keeps the casing it was first inserted with, which iteration, display
+    /// and serialization show. Aliases still match exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{Parameters, StorageBackend};
+    ///
+    /// let mut params = Parameters::new_case_insensitive().with("Content-Type", "text/html");
+    /// params.insert("CONTENT-TYPE", "application/json");
+    ///
+    /// assert_eq!(params.get_str("content-type"), Some("application/json"));
+    /// assert_eq!(params.keys().collect::<Vec<_>>(), ["Content-Type"]);
+    /// assert_eq!(params.backend(), StorageBackend::CaseInsensitive);
+    /// ```
+    pub fn new_case_insensitive() -> Self {
+        let mut params = Self::with_backend(StorageBackend::CaseInsensitive);
+        params.redaction.fold_case = true;
+        params.expiry.fold_case = true;
+        params
+    }
+
+    /// The storage backend holding the explicit values.
+    pub fn backend(&self) -> StorageBackend {
+        self.map.backend()
+    }
+
+    /// The explicit values for modification, copied first if a clone shares them.
+    pub(crate) fn map_mut(&mut self) -> &mut Store {
+        Arc::make_mut(&mut self.map)
+    }
+
+    /// The registered defaults for modification, copied first if a clone shares them.
+    pub(crate) fn defaults_mut(&mut self) -> &mut Store {
+        Arc::make_mut(&mut self.defaults)
+    }
+
     /// Insert a key-value pair into the `Parameters`.
     ///
+    /// Booleans and integers from -128 to 1023 reuse shared preallocated
+    /// storage, and floats and strings of up to 23 bytes reuse the storage of
+    /// an equal value inserted recently on the same thread, so inserting them
+    /// does not allocate; other values take one allocation. A value that would
+    /// cross the capacity limits set with `set_capacity_limits` is not stored:
+    /// the set is left unchanged, a warning is raised for `on_warning`
+    /// subscribers and `None` is returned. Use `checked_insert` to get the
+    /// error instead, which tells a rejected write apart from a new key.
+    ///
     /// # Arguments
     ///
-    /// * `key` - A key that can be converted into a `String`.
+    /// * `key` - A key that can be converted into a `Key`, such as a `&str` or `String`.
     /// * `value` - A value that implements the `ParameterValue` trait.
     ///
+    /// # Returns
+    ///
+    /// The value previously stored under the key, or `None` if the key was not present.
+    ///
     /// # Examples
     ///
     /// ```
@@ -55,15 +236,139 @@ impl Parameters {
     ///         "my_value".to_string()
     ///     }
     /// }
+    ///
+    /// impl parameterx::DisplayValue for MyValue {}
     /// let mut params = Parameters::new();
-    /// params.insert("key", MyValue);
+    /// assert!(params.insert("key", MyValue).is_none());
+    /// assert!(params.insert("key", MyValue).is_some());
     /// ```
-    pub fn insert<K, V>(&mut self, key: K, value: V)
+    pub fn insert<K, V>(&mut self, key: K, value: V) -> Option<Arc<dyn ParameterValue>>
     where
-        K: Into<String>,
+        K: Into<Key>,
         V: ParameterValue + 'static,
     {
-        self.map.insert(key.into(), Arc::new(value));
+        self.insert_arc(key, share(value))
+    }
+
+    /// Insert a value wrapped in a [`Secret`], so it is masked in every string and JSON rendering.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A key that can be converted into a `Key`, such as a `&str` or `String`.
+    /// * `value` - The sensitive value; read it back with `get::<Secret<T>>(key)`.
+    ///
+    /// # Returns
+    ///
+    /// The value previously stored under the key, or `None` if the key was not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{Parameters, Secret};
+    ///
+    /// let mut params = Parameters::new();
+    /// params.insert_secret("password", "hunter2");
+    ///
+    /// assert_eq!(params.get_string("password"), Some("***".to_string()));
+    /// assert_eq!(params.get::<Secret<&str>>("password").unwrap().expose(), &"hunter2");
+    /// ```
+    pub fn insert_secret<K, T>(&mut self, key: K, value: T) -> Option<Arc<dyn ParameterValue>>
+    where
+        K: Into<Key>,
+        T: ParameterValue + Clone + PartialEq + 'static,
+    {
+        self.insert(key, Secret::new(value))
+    }
+
+    /// Insert a key-value pair only if the key is not already present.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A key that can be converted into a `Key`, such as a `&str` or `String`.
+    /// * `value` - A value that implements the `ParameterValue` trait.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the value was inserted, or `ParameterError::DuplicateKey` if the key already exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new();
+    /// assert!(params.try_insert("port", 8080).is_ok());
+    /// assert!(params.try_insert("port", 9090).is_err());
+    /// assert_eq!(params.get::<i32>("port"), Some(&8080));
+    /// ```
+    pub fn try_insert<K, V>(&mut self, key: K, value: V) -> Result<()>
+    where
+        K: Into<Key>,
+        V: ParameterValue + 'static,
+    {
+        let key: Key = key.into();
+        if self.is_stored(self.canonical_key(&key)) {
+            return Err(ParameterError::DuplicateKey(key.into()));
+        }
+        self.try_insert_arc(key, share(value))?;
+        Ok(())
+    }
+
+    /// Insert as `insert` does, raising a warning instead of the error when the capacity limits reject `value`.
+    pub(crate) fn insert_arc(&mut self, key: impl Into<Key>, value: Arc<dyn ParameterValue>) -> Option<Arc<dyn ParameterValue>> {
+        let key = key.into();
+        match self.try_insert_arc(key.clone(), value) {
+            Ok(old) => old,
+            Err(err) => {
+                self.warnings.emit(&key, &format!("not stored; {}", err));
+                None
+            }
+        }
+    }
+
+    /// Store `value` under `key`, or its alias target, unless that would cross the capacity limits.
+    pub(crate) fn try_insert_arc(&mut self, key: Key, value: Arc<dyn ParameterValue>) -> Result<Option<Arc<dyn ParameterValue>>> {
+        let key = self.dealias_write(&key).unwrap_or(key);
+        self.check_limits(&key, value.as_ref())?;
+        let expired = self.expiry.is_expired(&key);
+        self.expiry.clear(&key);
+        let old = self.map_mut().insert(key.clone(), value.clone()).filter(|_| !expired);
+        self.track(&key, old.as_ref(), Some(&value));
+        self.notifier.record(&key, old.clone(), Some(value));
+        self.record_mutation();
+        Ok(old)
+    }
+
+    /// Remove a key from the `Parameters`, returning its value if it was present.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the removed value, or `None` if the key was not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new().with("key", 1);
+    /// assert!(params.remove("key").is_some());
+    /// assert!(!params.contains_key("key"));
+    /// ```
+    pub fn remove(&mut self, key: &str) -> Option<Arc<dyn ParameterValue>> {
+        let target = self.dealias_write(key);
+        let key = target.as_deref().unwrap_or(key);
+        let expired = self.expiry.is_expired(key);
+        let old = self.map_mut().remove(key);
+        self.expiry.clear(key);
+        let old = old.filter(|_| !expired)?;
+        self.track(key, Some(&old), None);
+        self.notifier.record(key, Some(old.clone()), None);
+        self.record_mutation();
+        Some(old)
     }
 
     /// Get a reference to a value of type `T` associated with the given key.
@@ -91,15 +396,63 @@ impl Parameters {
     ///     }
     /// }
     ///
+    /// impl parameterx::DisplayValue for MyValue {}
+    ///
     /// let mut params = Parameters::new();
     /// params.insert("key", MyValue);
     /// let value: Option<&MyValue> = params.get("key");
     /// ```
     pub fn get<T: 'static>(&self, key: &str) -> Option<&T> {
-        self.map.get(key)
+        self.lookup(key)
             .and_then(|value| value.as_any().downcast_ref::<T>())
     }
 
+    /// Get a mutable reference to a value of type `T` associated with the given key.
+    ///
+    /// An alias resolves to its target. If the value is shared with a clone of
+    /// these `Parameters` (or a snapshot handed out elsewhere), it is copied
+    /// first with `clone_arc`, so other holders never observe the mutation.
+    ///
+    /// Writes through the returned reference bypass the write-side checks:
+    /// change subscribers are not notified, change tracking does not record
+    /// them and the capacity limits are not enforced. Use `insert` or
+    /// `checked_insert` with the new value where those matter.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing a mutable reference to the value if found and of type `T`, or `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new().with("requests", 0u64);
+    /// if let Some(count) = params.get_mut::<u64>("requests") {
+    ///     *count += 1;
+    /// }
+    /// assert_eq!(params.get::<u64>("requests"), Some(&1));
+    /// ```
+    pub fn get_mut<T: 'static>(&mut self, key: &str) -> Option<&mut T> {
+        let target = self.dealias_write(key);
+        let key = target.as_deref().unwrap_or(key);
+        if self.expiry.is_expired(key) {
+            return None;
+        }
+        let value = self.map_mut().get_mut(key)?;
+        if !value.as_any().is::<T>() {
+            return None;
+        }
+        if Arc::get_mut(value).is_none() {
+            *value = value.clone_arc();
+        }
+        Arc::get_mut(value)?.as_any_mut().downcast_mut::<T>()
+    }
+
     /// Get a reference to a value of type `T` associated with the given key, or return an error if not found.
     ///
     /// # Arguments
@@ -125,16 +478,59 @@ impl Parameters {
     ///     }
     /// }
     ///
+    /// impl parameterx::DisplayValue for MyValue {}
+    ///
     /// let mut params = Parameters::new();
     /// params.insert("key", MyValue);
     /// let value: Result<&MyValue, _> = params.get_required("key");
     /// ```
     pub fn get_required<T: 'static>(&self, key: &str) -> Result<&T> {
-        self.get(key).ok_or_else(|| ParameterError::KeyNotFound(key.to_string()))
+        self.get(key).ok_or_else(|| self.missing_key(key))
+    }
+
+    /// Check that every one of the given keys has a value.
+    ///
+    /// Unlike calling `get_required` key by key, this reports all the missing
+    /// keys at once, in the order they were given.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys that must be present.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if all keys are present, or `ParameterError::MissingKeys` listing every missing key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{ParameterError, Parameters};
+    ///
+    /// let params = Parameters::new().with("host", "localhost");
+    ///
+    /// let err = params.require(["host", "port", "user"]).unwrap_err();
+    /// assert!(matches!(&err, ParameterError::MissingKeys(keys) if keys == &["port", "user"]));
+    /// assert_eq!(err.to_string(), "Missing required parameters: port, user");
+    /// ```
+    pub fn require<'a, I>(&self, keys: I) -> Result<()>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let missing: Vec<String> = keys.into_iter()
+            .filter(|key| !self.contains_key(key))
+            .map(str::to_string)
+            .collect();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(ParameterError::MissingKeys(missing))
+        }
     }
 
     /// Get the string representation of the value associated with the given key.
     ///
+    /// Values under keys marked with `mark_sensitive` are returned as `***`.
+    ///
     /// # Arguments
     ///
     /// * `key` - A string slice that holds the key.
@@ -158,12 +554,14 @@ impl Parameters {
     ///     }
     /// }
     ///
+    /// impl parameterx::DisplayValue for MyValue {}
+    ///
     /// let mut params = Parameters::new();
     /// params.insert("key", MyValue);
     /// let value: Option<String> = params.get_string("key");
     /// ```
     pub fn get_string(&self, key: &str) -> Option<String> {
-        self.map.get(key).map(|value| value.to_string())
+        self.lookup(key).map(|value| self.redaction.render(key, value.as_ref()))
     }
 
     /// Check if the `Parameters` contains the given key.
@@ -185,11 +583,15 @@ impl Parameters {
     /// let exists: bool = params.contains_key("key");
     /// ```
     pub fn contains_key(&self, key: &str) -> bool {
-        self.map.contains_key(key)
+        self.is_stored(self.canonical_key(key))
     }
 
     /// Try to get a value of type `T` associated with the given key, converting from a `String` if necessary.
     ///
+    /// The stored value's own string form is converted, so a key marked with
+    /// `mark_sensitive` or holding a `Secret` converts its real value rather
+    /// than the mask. Conversion errors still show the mask.
+    ///
     /// # Arguments
     ///
     /// * `key` - A string slice that holds the key.
@@ -212,17 +614,75 @@ impl Parameters {
         T: TryFrom<String>,
         T::Error: std::error::Error + Send + Sync + 'static,
     {
-        self.get_string(key)
-            .ok_or_else(|| ParameterError::KeyNotFound(key.to_string()))?
+        let value = self.lookup(key).ok_or_else(|| self.missing_key(key))?;
+        value.exposed().unwrap_or(value.as_ref()).to_string()
             .try_into()
-            .map_err(|e| ParameterError::ConversionFailed(Box::new(e)))
+            .map_err(|e| self.conversion_failed(key, value.as_ref(), std::any::type_name::<T>(), e))
+    }
+
+    /// Parse the value associated with the given key into any `FromStr` type.
+    ///
+    /// The value's string representation is parsed, so this works for values
+    /// stored as strings as well as for other types, e.g. an `i32` read back
+    /// as a `u16`. A `Secret` is parsed from the value it wraps.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the parsed value, `ParameterError::KeyNotFound` if the key is missing, or
+    /// `ParameterError::ConversionFailed` naming the key if parsing failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::IpAddr;
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("bind", "127.0.0.1").with("port", "80x");
+    ///
+    /// let bind: IpAddr = params.try_get_parse("bind").unwrap();
+    /// assert!(bind.is_loopback());
+    ///
+    /// let err = params.try_get_parse::<u16>("port").unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "Type conversion failed for port: cannot convert \"80x\" (stored as &str) to u16: invalid digit found in string",
+    /// );
+    /// ```
+    pub fn try_get_parse<T>(&self, key: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        let value = self.lookup(key).ok_or_else(|| self.missing_key(key))?;
+        let exposed = value.exposed().unwrap_or(value.as_ref());
+        let parsed = match as_str(exposed) {
+            Some(text) => text.parse(),
+            None => exposed.to_string().parse(),
+        };
+        parsed.map_err(|e| self.conversion_failed(key, value.as_ref(), std::any::type_name::<T>(), e))
+    }
+
+    /// A `ConversionFailed` error for `value`, masking it if the key is sensitive.
+    pub(crate) fn conversion_failed(
+        &self,
+        key: &str,
+        value: &dyn ParameterValue,
+        target: &'static str,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> ParameterError {
+        let raw = self.redaction.render(key, value);
+        ParameterError::conversion_failed(key, &raw, value.type_name(), target, source)
     }
 
     /// Insert a key-value pair into the `Parameters` and return the modified `Parameters`.
     ///
     /// # Arguments
     ///
-    /// * `key` - A key that can be converted into a `String`.
+    /// * `key` - A key that can be converted into a `Key`, such as a `&str` or `String`.
     /// * `value` - A value that implements the `ParameterValue` trait.
     ///
     /// # Returns
@@ -242,12 +702,13 @@ impl Parameters {
     ///         "MyValue".into()
     ///     }
     /// }
+    /// impl parameterx::DisplayValue for MyValue {}
     ///
     /// let params = Parameters::new().with("key", MyValue);
     /// ```
     pub fn with<K, V>(mut self, key: K, value: V) -> Self
     where
-        K: Into<String>,
+        K: Into<Key>,
         V: ParameterValue + 'static,
     {
         self.insert(key, value);
@@ -270,7 +731,138 @@ impl Parameters {
     /// params1.merge(params2);
     /// ```
     pub fn merge(&mut self, other: Parameters) {
-        self.map.extend(other.map);
+        self.defaults_mut().extend(other.defaults.iter().map(|(k, v)| (k.clone(), v.clone())));
+        self.redaction.extend(&other.redaction);
+        self.meta.extend(&other.meta);
+        self.aliases.extend(&other.aliases);
+        self.batch(|params| {
+            for (key, value) in other {
+                params.insert_arc(key, value);
+            }
+        });
+    }
+
+    /// Receive the changes to every key starting with `prefix` on a channel.
+    ///
+    /// Each insert, update and removal of a matching key is sent once its
+    /// mutation completes, in the coalesced form listeners receive. Pass an
+    /// exact key to watch just that key, or `""` to watch every key. Dropping
+    /// the receiver ends the subscription.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The key or key prefix to watch, such as `limits.`.
+    ///
+    /// # Returns
+    ///
+    /// The receiving end of the channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{ChangeKind, Parameters};
+    ///
+    /// let mut params = Parameters::new().with("log_level", "info");
+    /// let changes = params.subscribe("limits.");
+    ///
+    /// params.insert("log_level", "debug");
+    /// params.insert("limits.rps", 100);
+    ///
+    /// let change = changes.try_recv().unwrap();
+    /// assert_eq!((change.key.as_str(), change.kind), ("limits.rps", ChangeKind::Added));
+    /// assert!(changes.try_recv().is_err());
+    /// ```
+    pub fn subscribe(&mut self, prefix: impl Into<String>) -> Receiver<ParameterChange> {
+        let (sender, receiver) = mpsc::channel();
+        self.notifier.subscribe_prefix(prefix.into(), sender);
+        receiver
+    }
+
+    /// Register a callback invoked after each mutation with the changes it made.
+    ///
+    /// Changes are delivered once the mutation completes: a `merge` of many keys,
+    /// or every mutation made inside [`Parameters::batch`], produces a single
+    /// `ChangeBatch` rather than one callback per key.
+    ///
+    /// # Arguments
+    ///
+    /// * `listener` - A callback receiving the coalesced `ChangeBatch`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new();
+    /// params.on_change(|batch| {
+    ///     for key in batch.keys() {
+    ///         println!("changed: {}", key);
+    ///     }
+    /// });
+    /// params.insert("key", 1);
+    /// ```
+    pub fn on_change<F>(&mut self, listener: F)
+    where
+        F: Fn(&ChangeBatch) + Send + Sync + 'static,
+    {
+        self.notifier.subscribe(Arc::new(listener));
+    }
+
+    /// Register a callback invoked for every change to a single key.
+    ///
+    /// Hooks fire after the mutation completes, once per key per batch, in the
+    /// same delivery pass as [`Parameters::on_change`] listeners.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to watch.
+    /// * `hook` - A callback receiving the coalesced `ParameterChange` for that key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new();
+    /// params.on_key_change("log_level", |change| {
+    ///     println!("log level is now {:?}", change.new);
+    /// });
+    /// params.insert("log_level", "debug");
+    /// ```
+    pub fn on_key_change<K, F>(&mut self, key: K, hook: F)
+    where
+        K: Into<String>,
+        F: Fn(&ParameterChange) + Send + Sync + 'static,
+    {
+        self.notifier.subscribe_key(key.into(), Arc::new(hook));
+    }
+
+    /// Run several mutations as one batch, notifying subscribers once at the end.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A closure performing the mutations.
+    ///
+    /// # Returns
+    ///
+    /// The value returned by the closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new();
+    /// params.batch(|p| {
+    ///     p.insert("host", "localhost");
+    ///     p.insert("port", 8080);
+    /// });
+    /// ```
+    pub fn batch<R>(&mut self, f: impl FnOnce(&mut Parameters) -> R) -> R {
+        self.notifier.begin();
+        let result = f(self);
+        self.notifier.end();
+        result
     }
 
     /// Get an iterator over the keys in the `Parameters`.
@@ -289,8 +881,8 @@ impl Parameters {
     ///     println!("{}", key);
     /// }
     /// ```
-    pub fn keys(&self) -> impl Iterator<Item = &String> {
-        self.map.keys()
+    pub fn keys(&self) -> impl Iterator<Item = &Key> {
+        self.iter().map(|(key, _)| key)
     }
 
     /// Get an iterator over the key-value pairs in the `Parameters`.
@@ -309,12 +901,65 @@ impl Parameters {
     ///     println!("{}: {:?}", key, value);
     /// }
     /// ```
-    pub fn iter(&self) -> impl Iterator<Item = (&String, &Arc<dyn ParameterValue>)> {
-        self.map.iter()
+    pub fn iter(&self) -> impl Iterator<Item = (&Key, &Arc<dyn ParameterValue>)> {
+        self.map.iter().skip_expired(&self.expiry)
+    }
+
+    /// Get an iterator over the values in the `Parameters`, in the order of the storage backend.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("a", 1).with("b", 2);
+    /// let total: i32 = params.values().filter_map(|v| v.as_any().downcast_ref::<i32>()).sum();
+    /// assert_eq!(total, 3);
+    /// ```
+    pub fn values(&self) -> impl Iterator<Item = &Arc<dyn ParameterValue>> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// The number of explicitly set keys. Registered defaults are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new().with("a", 1);
+    /// params.set_default("b", 2);
+    /// assert_eq!(params.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        if self.expiry.is_empty() {
+            return self.map.len();
+        }
+        self.iter().count()
+    }
+
+    /// Whether no keys are explicitly set. Registered defaults are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// assert!(Parameters::new().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
     }
 
     /// Convert the `Parameters` to a JSON value.
     ///
+    /// Keys marked with `mark_sensitive` are rendered as `"***"`; use
+    /// `to_json_unredacted` for trusted sinks.
+    ///
     /// # Returns
     ///
     /// A `Result` containing the JSON value if successful, or a `ParameterError` if conversion failed.
@@ -328,11 +973,32 @@ impl Parameters {
     /// let json = params.to_json().unwrap();
     /// ```
     pub fn to_json(&self) -> Result<serde_json::Value> {
-        let mut map = serde_json::Map::new();
-        for (key, value) in self.iter() {
-            map.insert(key.clone(), value.to_json()?);
-        }
-        Ok(serde_json::Value::Object(map))
+        self.json_with(Some(&self.redaction))
+    }
+
+    /// Convert the explicitly set values to a plain string map, as `get_string` renders them.
+    ///
+    /// Keys marked with `mark_sensitive` are rendered as `"***"`. Defaults are not included.
+    ///
+    /// # Returns
+    ///
+    /// A map from each key to its string representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("port", 8080).with("debug", true);
+    ///
+    /// let map = params.to_string_map();
+    /// assert_eq!(map, BTreeMap::from([("debug".to_string(), "true".to_string()), ("port".to_string(), "8080".to_string())]));
+    /// ```
+    pub fn to_string_map(&self) -> BTreeMap<String, String> {
+        self.iter()
+            .map(|(key, value)| (String::from(key), self.redaction.render(key, value.as_ref())))
+            .collect()
     }
 }
 
@@ -349,12 +1015,96 @@ impl From<&str> for Parameters {
     }
 }
 
-impl FromIterator<(String, String)> for Parameters {
-    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+/// Every entry is stored as a `String` value.
+impl From<HashMap<String, String>> for Parameters {
+    fn from(map: HashMap<String, String>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+/// Every entry is stored as a `String` value.
+impl From<BTreeMap<String, String>> for Parameters {
+    fn from(map: BTreeMap<String, String>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+/// Collecting typed pairs stores each value as it is, so `(String, String)`
+/// pairs give `String` values and `(&str, i32)` pairs `i32` values.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::Parameters;
+///
+/// let params: Parameters = (1..=3).map(|i| (format!("worker{i}"), i * 10)).collect();
+/// assert_eq!(params.get::<i32>("worker2"), Some(&20));
+/// ```
+impl<K, V> FromIterator<(K, V)> for Parameters
+where
+    K: Into<Key>,
+    V: ParameterValue + 'static,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
         let mut params = Parameters::new();
-        for (key, value) in iter {
-            params.insert(key, value);
-        }
+        params.extend(iter);
         params
     }
-}
\ No newline at end of file
+}
+
+/// Extending inserts every pair in a single batch, so change listeners are
+/// notified once.
+impl<K, V> Extend<(K, V)> for Parameters
+where
+    K: Into<Key>,
+    V: ParameterValue + 'static,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.batch(|params| {
+            for (key, value) in iter {
+                params.insert(key, value);
+            }
+        });
+    }
+}
+
+impl<K: Into<Key>> FromIterator<(K, Arc<dyn ParameterValue>)> for Parameters {
+    fn from_iter<I: IntoIterator<Item = (K, Arc<dyn ParameterValue>)>>(iter: I) -> Self {
+        let mut params = Parameters::new();
+        params.extend(iter);
+        params
+    }
+}
+
+/// Extending inserts every pair in a single batch, so change listeners are
+/// notified once.
+impl<K: Into<Key>> Extend<(K, Arc<dyn ParameterValue>)> for Parameters {
+    fn extend<I: IntoIterator<Item = (K, Arc<dyn ParameterValue>)>>(&mut self, iter: I) {
+        self.batch(|params| {
+            for (key, value) in iter {
+                params.insert_arc(key, value);
+            }
+        });
+    }
+}
+
+/// Owning iteration yields the explicitly set keys and values in the order
+/// of the storage backend; defaults are dropped.
+impl IntoIterator for Parameters {
+    type Item = (Key, Arc<dyn ParameterValue>);
+    type IntoIter = IntoIter;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        self.drop_expired();
+        Arc::unwrap_or_clone(self.map).into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Parameters {
+    type Item = (&'a Key, &'a Arc<dyn ParameterValue>);
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.iter().skip_expired(&self.expiry)
+    }
+}
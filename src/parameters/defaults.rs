@@ -0,0 +1,175 @@
+use std::sync::Arc;
+use crate::value::{share, ParameterValue};
+use super::{core::Parameters, key::Key, store::Store};
+
+/// Where a resolved value came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueSource {
+    /// The value was set explicitly with `insert` or one of its variants.
+    Explicit,
+    /// No explicit value exists and the registered default was used.
+    Default,
+}
+
+impl Parameters {
+    /// Register a default value for a key, kept separately from explicit values.
+    ///
+    /// Defaults are only consulted by the `*_or_default` accessors; `get`,
+    /// `contains_key` and iteration see explicit values only.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A key that can be converted into a `String`.
+    /// * `value` - A value that implements the `ParameterValue` trait.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new();
+    /// params.set_default("port", 8080);
+    /// assert!(!params.contains_key("port"));
+    /// assert_eq!(params.get_or_default::<i32>("port"), Some(&8080));
+    /// ```
+    pub fn set_default<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<Key>,
+        V: ParameterValue + 'static,
+    {
+        self.defaults_mut().insert(key.into(), share(value));
+    }
+
+    /// Get a value of type `T`, falling back to the registered default when the key has no explicit value.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing a reference to the explicit value if set, otherwise to the default,
+    /// or `None` if neither exists or the resolved value is not a `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new().with("port", 9090);
+    /// params.set_default("port", 8080);
+    /// assert_eq!(params.get_or_default::<i32>("port"), Some(&9090));
+    /// ```
+    pub fn get_or_default<T: 'static>(&self, key: &str) -> Option<&T> {
+        self.resolve(key)
+            .and_then(|(value, _)| value.as_any().downcast_ref::<T>())
+    }
+
+    /// Report whether the value for a key was set explicitly or comes from a default.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// The `ValueSource` of the resolved value, or `None` if the key has neither.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{Parameters, ValueSource};
+    ///
+    /// let mut params = Parameters::new();
+    /// params.set_default("port", 8080);
+    /// assert_eq!(params.value_source("port"), Some(ValueSource::Default));
+    /// params.insert("port", 9090);
+    /// assert_eq!(params.value_source("port"), Some(ValueSource::Explicit));
+    /// ```
+    pub fn value_source(&self, key: &str) -> Option<ValueSource> {
+        self.resolve(key).map(|(_, source)| source)
+    }
+
+    /// Get a `Parameters` holding only the registered defaults.
+    ///
+    /// Like a clone, the view keeps sensitive-key patterns, aliases, metadata
+    /// and limits. Defaults never expire, so no time to live carries over.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new().with("host", "localhost");
+    /// params.set_default("port", 8080);
+    /// let defaults = params.defaults();
+    /// assert!(defaults.contains_key("port"));
+    /// assert!(!defaults.contains_key("host"));
+    /// ```
+    pub fn defaults(&self) -> Parameters {
+        Parameters {
+            map: self.defaults.clone(),
+            defaults: Arc::new(Store::new(self.backend())),
+            expiry: self.expiry.cleared(),
+            ..self.clone()
+        }
+    }
+
+    /// Get a `Parameters` holding only the explicitly set values, without defaults.
+    ///
+    /// Like a clone, the view keeps sensitive-key patterns, aliases, metadata,
+    /// limits and the time to live of each value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new().with("host", "localhost");
+    /// params.set_default("port", 8080);
+    /// let explicit = params.explicit();
+    /// assert!(explicit.contains_key("host"));
+    /// assert!(explicit.get_or_default::<i32>("port").is_none());
+    /// ```
+    pub fn explicit(&self) -> Parameters {
+        Parameters {
+            defaults: Arc::new(Store::new(self.backend())),
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn resolve(&self, key: &str) -> Option<(&Arc<dyn ParameterValue>, ValueSource)> {
+        let key = self.dealias(key);
+        let resolved = self.stored(key)
+            .map(|value| (value, ValueSource::Explicit))
+            .or_else(|| self.defaults.get(key).map(|value| (value, ValueSource::Default)));
+        self.access.record(key, resolved.is_some());
+        resolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::*;
+
+    #[test]
+    fn test_views_keep_redaction_aliases_and_expiry() {
+        let mut params = Parameters::new().with("api_key", "k3y").with("host", "a");
+        params.insert_with_ttl("token", "t", Duration::ZERO);
+        params.set_default("password", "p4ss");
+        params.mark_sensitive("api_key");
+        params.mark_sensitive("password");
+        params.alias("server", "host");
+
+        let explicit = params.explicit();
+        assert_eq!(explicit.to_json().unwrap()["api_key"], "***");
+        assert_eq!(explicit.get::<&str>("server"), Some(&"a"));
+        assert!(!explicit.contains_key("token"));
+        assert_eq!(explicit.len(), 2);
+
+        let defaults = params.defaults();
+        assert_eq!(defaults.get_string("password").as_deref(), Some("***"));
+        assert_eq!(defaults.len(), 1);
+    }
+}
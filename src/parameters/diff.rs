@@ -0,0 +1,192 @@
+use std::sync::Arc;
+use crate::value::ParameterValue;
+use super::core::Parameters;
+
+/// The keys that differ between two `Parameters` instances.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParametersDiff {
+    /// Keys present only in the newer set.
+    pub added: Vec<String>,
+    /// Keys present only in the older set.
+    pub removed: Vec<String>,
+    /// Keys present in both sets whose values differ.
+    pub changed: Vec<String>,
+}
+
+impl ParametersDiff {
+    /// Whether the two sets hold the same keys and values.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// The total number of differing keys.
+    pub fn len(&self) -> usize {
+        self.added.len() + self.removed.len() + self.changed.len()
+    }
+}
+
+pub(crate) fn same_value(a: &Arc<dyn ParameterValue>, b: &Arc<dyn ParameterValue>) -> bool {
+    Arc::ptr_eq(a, b) || a.eq_dyn(b.as_ref())
+}
+
+impl Parameters {
+    /// Compare these parameters (the older set) against `other` (the newer set).
+    ///
+    /// A key counts as changed when its values are not equal according to
+    /// `ParameterValue::eq_dyn`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The `Parameters` to compare against.
+    ///
+    /// # Returns
+    ///
+    /// A `ParametersDiff` listing added, removed, and changed keys, each in key order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let before = Parameters::new().with("a", 1).with("b", 2);
+    /// let after = Parameters::new().with("b", 3).with("c", 4);
+    ///
+    /// let diff = before.diff(&after);
+    /// assert_eq!(diff.added, vec!["c".to_string()]);
+    /// assert_eq!(diff.removed, vec!["a".to_string()]);
+    /// assert_eq!(diff.changed, vec!["b".to_string()]);
+    /// ```
+    pub fn diff(&self, other: &Parameters) -> ParametersDiff {
+        let mut diff = ParametersDiff::default();
+        for (key, value) in self.iter() {
+            match other.stored(key) {
+                None => diff.removed.push(String::from(key)),
+                Some(new) if !same_value(value, new) => diff.changed.push(String::from(key)),
+                Some(_) => {}
+            }
+        }
+        diff.added = other.keys()
+            .filter(|key| !self.is_stored(key))
+            .map(String::from)
+            .collect();
+        diff
+    }
+
+    /// Replace the explicit values with `new`, touching only the keys that differ.
+    ///
+    /// Unchanged keys keep their existing values, so only the keys in the
+    /// returned diff fire change hooks, delivered as a single batch. Registered
+    /// defaults are left as they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `new` - The freshly loaded parameters.
+    ///
+    /// # Returns
+    ///
+    /// A `ParametersDiff` describing the keys that were added, removed, or changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new().with("a", 1).with("b", 2);
+    /// let diff = params.reload_from(Parameters::new().with("a", 1).with("b", 3).with("c", 4));
+    ///
+    /// assert_eq!(diff.changed, vec!["b".to_string()]);
+    /// assert_eq!(diff.added, vec!["c".to_string()]);
+    /// assert!(diff.removed.is_empty());
+    /// ```
+    pub fn reload_from(&mut self, mut new: Parameters) -> ParametersDiff {
+        let diff = self.diff(&new);
+        self.batch(|params| {
+            for key in &diff.removed {
+                params.remove(key);
+            }
+            for key in diff.added.iter().chain(&diff.changed) {
+                if let Some(value) = new.map_mut().remove(key) {
+                    params.insert_arc(key.clone(), value);
+                }
+            }
+        });
+        diff
+    }
+
+    /// Swap in `new` whole, keeping the change and warning listeners and
+    /// notifying them once of the keys whose values differ.
+    pub(crate) fn replace_keeping_listeners(&mut self, new: Parameters) -> Parameters {
+        let diff = self.diff(&new);
+        let mut previous = std::mem::replace(self, new);
+        std::mem::swap(&mut self.notifier, &mut previous.notifier);
+        std::mem::swap(&mut self.warnings, &mut previous.warnings);
+
+        self.notifier.begin();
+        for key in diff.removed.iter().chain(&diff.added).chain(&diff.changed) {
+            let (old, new) = (previous.stored(key).cloned(), self.stored(key).cloned());
+            self.track(key, old.as_ref(), new.as_ref());
+            self.notifier.record(key, old, new);
+        }
+        self.notifier.end();
+        previous
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{atomic::{AtomicUsize, Ordering}, Mutex};
+    use super::*;
+
+    #[test]
+    fn test_diff_detects_type_changes() {
+        let before = Parameters::new().with("port", 8080).with("host", "a");
+        let after = Parameters::new().with("port", "8080").with("host", "a".to_string());
+
+        let diff = before.diff(&after);
+        assert_eq!(diff.changed, vec!["host".to_string(), "port".to_string()]);
+        assert!(before.diff(&before.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_rotating_a_nested_secret_is_detected() {
+        let nested = |password: &str| {
+            let mut db = Parameters::new().with("user", "app");
+            db.insert_secret("password", password.to_string());
+            Parameters::new().with("db", db)
+        };
+        let mut params = nested("old");
+        assert_eq!(params, nested("old"));
+        assert_ne!(params, nested("new"));
+
+        let diff = params.reload_from(nested("new"));
+        assert_eq!(diff.changed, vec!["db".to_string()]);
+        assert_eq!(params, nested("new"));
+    }
+
+    #[test]
+    fn test_reload_fires_hooks_only_for_changed_keys() {
+        let mut params: Parameters = (0..300)
+            .map(|i| (format!("key{i}"), format!("{i}")))
+            .collect();
+
+        let fired = Arc::new(Mutex::new(Vec::new()));
+        for i in 0..300 {
+            let fired = fired.clone();
+            params.on_key_change(format!("key{i}"), move |change| {
+                fired.lock().unwrap().push(change.key.clone());
+            });
+        }
+        let batches = Arc::new(AtomicUsize::new(0));
+        let counter = batches.clone();
+        params.on_change(move |_| { counter.fetch_add(1, Ordering::SeqCst); });
+
+        let mut new = params.clone();
+        new.insert("key7", "changed".to_string());
+        let diff = params.reload_from(new);
+
+        assert_eq!(diff.changed, vec!["key7".to_string()]);
+        assert_eq!(*fired.lock().unwrap(), vec!["key7".to_string()]);
+        assert_eq!(batches.load(Ordering::SeqCst), 1);
+        assert_eq!(params.get_string("key7"), Some("changed".to_string()));
+    }
+}
@@ -0,0 +1,246 @@
+use std::{any::Any, fmt::{self, Write}, sync::Arc};
+use crate::{value::{ParameterValue, MASK}, Result};
+use super::{core::Parameters, key::Key, redact::Redaction};
+
+/// Strip module paths from a type name, e.g. `alloc::vec::Vec<alloc::string::String>` becomes `Vec<String>`.
+pub(crate) fn short_type_name(name: &str) -> String {
+    let mut short = String::with_capacity(name.len());
+    let mut segment = String::new();
+    for c in name.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            segment.push(c);
+        } else {
+            short.push_str(segment.rsplit("::").next().unwrap_or_default());
+            segment.clear();
+            short.push(c);
+        }
+    }
+    short.push_str(segment.rsplit("::").next().unwrap_or_default());
+    short
+}
+
+fn compact(value: &dyn ParameterValue, policy: &Redaction, out: &mut String) {
+    match value.as_any().downcast_ref::<Parameters>() {
+        Some(nested) => {
+            let policy = policy.joined(&nested.redaction);
+            out.push('{');
+            for (i, (key, value)) in nested.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                let _ = write!(out, "{} = ", key);
+                compact_entry(key, value.as_ref(), &policy, out);
+            }
+            out.push('}');
+        }
+        None => out.push_str(&value.to_string()),
+    }
+}
+
+fn compact_entry(key: &str, value: &dyn ParameterValue, policy: &Redaction, out: &mut String) {
+    if policy.is_sensitive(key) {
+        out.push_str(MASK);
+    } else {
+        compact(value, policy, out);
+    }
+}
+
+/// Debug-formats `Parameters` as a map while masking sensitive keys at every depth.
+struct DebugMap<'a>(&'a Parameters, &'a Redaction);
+
+impl fmt::Debug for DebugMap<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let DebugMap(params, policy) = *self;
+        f.write_str("Parameters ")?;
+        let mut map = f.debug_map();
+        for (key, value) in params.iter() {
+            if policy.is_sensitive(key) {
+                map.entry(key, &format_args!("{MASK}"));
+            } else if let Some(nested) = value.as_any().downcast_ref::<Parameters>() {
+                let policy = policy.joined(&nested.redaction);
+                map.entry(key, &DebugMap(nested, &policy));
+            } else {
+                map.entry(key, value);
+            }
+        }
+        map.finish()
+    }
+}
+
+/// Lists one `key = value (type)` line per key, in key order. Nested
+/// `Parameters` are shown inline as `{key = value, ...}` and sensitive keys as `***`.
+impl fmt::Display for Parameters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.display_with(&self.redaction))
+    }
+}
+
+/// Nested sets compare structurally, through `PartialEq`, so a changed value
+/// is seen even when it renders as `***`.
+impl ParameterValue for Parameters {
+    fn to_string(&self) -> String {
+        ToString::to_string(self)
+    }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    fn clone_arc(&self) -> Arc<dyn ParameterValue> {
+        Arc::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn eq_dyn(&self, other: &dyn ParameterValue) -> bool {
+        other.as_any().downcast_ref::<Self>().is_some_and(|other| self == other)
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value> {
+        Parameters::to_json(self)
+    }
+}
+
+/// Prints the stored values as a map, e.g. `Parameters {"port": 8080}`; use
+/// `{:#?}` for one key per line.
+impl fmt::Debug for Parameters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&DebugMap(self, &self.redaction), f)
+    }
+}
+
+impl Parameters {
+    /// The `Display` listing, masking the keys `policy` marks sensitive.
+    pub(crate) fn display_with(&self, policy: &Redaction) -> String {
+        let mut out = String::new();
+        for (i, (key, value)) in self.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            let _ = write!(out, "{} = ", key);
+            compact_entry(key, value.as_ref(), policy, &mut out);
+            let _ = write!(out, " ({})", short_type_name(value.type_name()));
+        }
+        out
+    }
+
+    /// Render a multi-line, aligned listing for logs and diagnostics.
+    ///
+    /// Keys are sorted and their values aligned; nested `Parameters` are
+    /// expanded with indentation, and defaults that are not overridden are
+    /// listed with a `[default]` marker.
+    ///
+    /// # Returns
+    ///
+    /// The rendered listing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new()
+    ///     .with("name", "api")
+    ///     .with("db", Parameters::new().with("port", 5432));
+    /// params.set_default("workers", 4);
+    ///
+    /// assert_eq!(params.fmt_pretty(), "\
+    /// db:
+    ///   port = 5432 (i32)
+    /// name    = api (&str)
+    /// workers = 4 (i32) [default]
+    /// ");
+    /// ```
+    pub fn fmt_pretty(&self) -> String {
+        let mut out = String::new();
+        self.write_pretty(&mut out, 0, &self.redaction);
+        out
+    }
+
+    /// Every value as a string under its dotted path, with nested `Parameters`
+    /// expanded and sensitive keys masked, in key order.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn rendered_leaves(&self) -> Vec<(String, String)> {
+        let mut leaves = Vec::new();
+        self.collect_leaves("", &self.redaction, &mut leaves);
+        leaves
+    }
+
+    #[cfg(feature = "tracing")]
+    fn collect_leaves(&self, prefix: &str, policy: &Redaction, out: &mut Vec<(String, String)>) {
+        for (key, value) in self.iter() {
+            let path = format!("{prefix}{key}");
+            match value.as_any().downcast_ref::<Parameters>().filter(|_| !policy.is_sensitive(key)) {
+                Some(nested) => nested.collect_leaves(&format!("{path}."), &policy.joined(&nested.redaction), out),
+                None => out.push((path, policy.render(key, value.as_ref()))),
+            }
+        }
+    }
+
+    fn write_pretty(&self, out: &mut String, depth: usize, policy: &Redaction) {
+        let indent = "  ".repeat(depth);
+        let mut rows: Vec<(&Key, &dyn ParameterValue, bool)> = self.iter()
+            .map(|(key, value)| (key, value.as_ref(), false))
+            .chain(self.defaults.iter()
+                .filter(|(key, _)| !self.is_stored(key))
+                .map(|(key, value)| (key, value.as_ref(), true)))
+            .collect();
+        rows.sort_by(|a, b| a.0.cmp(b.0));
+
+        let width = rows.iter()
+            .filter(|(_, value, _)| !value.as_any().is::<Parameters>())
+            .map(|(key, _, _)| key.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        for (key, value, is_default) in rows {
+            if let Some(nested) = value.as_any().downcast_ref::<Parameters>().filter(|_| !policy.is_sensitive(key)) {
+                let _ = writeln!(out, "{indent}{key}:");
+                nested.write_pretty(out, depth + 1, &policy.joined(&nested.redaction));
+                continue;
+            }
+            let _ = write!(out, "{indent}{key:<width$} = {} ({})", policy.render(key, value), short_type_name(value.type_name()));
+            if is_default {
+                out.push_str(" [default]");
+            }
+            out.push('\n');
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IntVec;
+    use super::*;
+
+    #[test]
+    fn test_short_type_name() {
+        assert_eq!(short_type_name("alloc::string::String"), "String");
+        assert_eq!(short_type_name("&str"), "&str");
+        assert_eq!(
+            short_type_name("std::collections::HashMap<alloc::string::String, parameterx::value::values::IntVec<i32>>"),
+            "HashMap<String, IntVec<i32>>",
+        );
+    }
+
+    #[test]
+    fn test_display_and_debug() {
+        let params = Parameters::new()
+            .with("name", "api".to_string())
+            .with("scores", IntVec(vec![1, 2]))
+            .with("db", Parameters::new().with("port", 5432));
+
+        assert_eq!(format!("{}", params), "\
+db = {port = 5432} (Parameters)
+name = api (String)
+scores = [1, 2] (IntVec<i32>)");
+        assert_eq!(format!("{:?}", Parameters::new().with("port", 1)), r#"Parameters {"port": 1}"#);
+        assert_eq!(format!("{:#?}", Parameters::new().with("port", 1)), "Parameters {\n    \"port\": 1,\n}");
+    }
+}
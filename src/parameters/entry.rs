@@ -0,0 +1,159 @@
+use std::sync::Arc;
+use crate::{value::{share, ParameterValue}, Result};
+use super::{core::Parameters, key::Key};
+
+/// A view into a single key of a `Parameters`, which is either occupied or vacant.
+///
+/// Constructed by [`Parameters::entry`]. Mutations made through an entry notify
+/// change subscribers exactly like `insert` and `remove`.
+pub enum Entry<'a> {
+    Occupied(OccupiedEntry<'a>),
+    Vacant(VacantEntry<'a>),
+}
+
+/// An entry for a key that already holds a value.
+pub struct OccupiedEntry<'a> {
+    params: &'a mut Parameters,
+    key: Key,
+}
+
+/// An entry for a key that holds no value.
+pub struct VacantEntry<'a> {
+    params: &'a mut Parameters,
+    key: Key,
+}
+
+impl<'a> Entry<'a> {
+    pub(crate) fn new(params: &'a mut Parameters, key: Key) -> Self {
+        if params.is_stored(&key) {
+            Entry::Occupied(OccupiedEntry { params, key })
+        } else {
+            Entry::Vacant(VacantEntry { params, key })
+        }
+    }
+
+    /// The key this entry refers to.
+    pub fn key(&self) -> &str {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Insert `default` if the entry is vacant, and return the stored value.
+    ///
+    /// Fails with `ParameterError::LimitExceeded` if `default` would cross the capacity limits.
+    pub fn or_insert<V>(self, default: V) -> Result<&'a Arc<dyn ParameterValue>>
+    where
+        V: ParameterValue + 'static,
+    {
+        self.or_insert_with(|| default)
+    }
+
+    /// Insert the result of `default` if the entry is vacant, and return the stored value.
+    ///
+    /// `default` is only called when the key is absent.
+    pub fn or_insert_with<V, F>(self, default: F) -> Result<&'a Arc<dyn ParameterValue>>
+    where
+        V: ParameterValue + 'static,
+        F: FnOnce() -> V,
+    {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_ref()),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+}
+
+impl<'a> OccupiedEntry<'a> {
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Get the stored value.
+    pub fn get(&self) -> &Arc<dyn ParameterValue> {
+        &self.params.map[&self.key]
+    }
+
+    /// Get the stored value as a `T`, or `None` if it has a different type.
+    pub fn get_as<T: 'static>(&self) -> Option<&T> {
+        self.get().as_any().downcast_ref::<T>()
+    }
+
+    /// Replace the stored value, returning the previous one.
+    ///
+    /// Fails with `ParameterError::LimitExceeded`, leaving the value in place,
+    /// if `value` would cross the capacity limits.
+    pub fn insert<V>(&mut self, value: V) -> Result<Arc<dyn ParameterValue>>
+    where
+        V: ParameterValue + 'static,
+    {
+        let old = self.get().clone();
+        self.params.try_insert_arc(self.key.clone(), share(value))?;
+        Ok(old)
+    }
+
+    /// Remove the entry, returning the stored value.
+    ///
+    /// The value is returned even if its time-to-live ran out after the entry was taken.
+    pub fn remove(self) -> Arc<dyn ParameterValue> {
+        let old = self.get().clone();
+        self.params.remove(&self.key);
+        old
+    }
+
+    /// Convert the entry into a reference to the stored value with the entry's lifetime.
+    pub fn into_ref(self) -> &'a Arc<dyn ParameterValue> {
+        let params: &'a Parameters = self.params;
+        &params.map[&self.key]
+    }
+}
+
+impl<'a> VacantEntry<'a> {
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Store `value` under the entry's key and return a reference to it.
+    ///
+    /// Fails with `ParameterError::LimitExceeded`, storing nothing, if `value`
+    /// would cross the capacity limits.
+    pub fn insert<V>(self, value: V) -> Result<&'a Arc<dyn ParameterValue>>
+    where
+        V: ParameterValue + 'static,
+    {
+        let params: &'a mut Parameters = self.params;
+        params.try_insert_arc(self.key.clone(), share(value))?;
+        Ok(&params.map[&self.key])
+    }
+}
+
+impl Parameters {
+    /// Get the entry for a key, for in-place conditional initialization.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A key that can be converted into a `String`.
+    ///
+    /// # Returns
+    ///
+    /// An `Entry` that is either occupied or vacant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new().with("retries", 5);
+    /// params.entry("retries").or_insert(3).unwrap();
+    /// params.entry("timeout").or_insert_with(|| 30).unwrap();
+    ///
+    /// assert_eq!(params.get::<i32>("retries"), Some(&5));
+    /// assert_eq!(params.get::<i32>("timeout"), Some(&30));
+    /// ```
+    pub fn entry<K: Into<Key>>(&mut self, key: K) -> Entry<'_> {
+        let key = key.into();
+        let key = self.dealias_write(&key).unwrap_or(key);
+        Entry::new(self, key)
+    }
+}
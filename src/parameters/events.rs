@@ -0,0 +1,245 @@
+use std::{collections::BTreeMap, fmt, sync::{mpsc::Sender, Arc}};
+use crate::value::ParameterValue;
+
+/// The kind of modification applied to a single key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Updated,
+    Removed,
+}
+
+/// A single key modification, carrying the value before and after the change.
+#[derive(Debug, Clone)]
+pub struct ParameterChange {
+    pub key: String,
+    pub kind: ChangeKind,
+    pub old: Option<Arc<dyn ParameterValue>>,
+    pub new: Option<Arc<dyn ParameterValue>>,
+}
+
+/// All changes produced by one mutation, coalesced per key.
+///
+/// A key that changes several times within the same batch appears once, with
+/// the value it had before the batch started and the value it ended up with.
+#[derive(Debug, Clone, Default)]
+pub struct ChangeBatch {
+    changes: Vec<ParameterChange>,
+}
+
+impl ChangeBatch {
+    /// The coalesced changes, ordered by key.
+    pub fn changes(&self) -> &[ParameterChange] {
+        &self.changes
+    }
+
+    /// The keys touched by this batch.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.changes.iter().map(|change| change.key.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    fn coalesce(pending: Vec<ParameterChange>) -> Self {
+        let mut merged: BTreeMap<String, ParameterChange> = BTreeMap::new();
+        for change in pending {
+            match merged.get_mut(&change.key) {
+                Some(existing) => existing.new = change.new,
+                None => {
+                    merged.insert(change.key.clone(), change);
+                }
+            }
+        }
+
+        let changes = merged.into_values()
+            .filter_map(|mut change| {
+                change.kind = match (&change.old, &change.new) {
+                    (None, Some(_)) => ChangeKind::Added,
+                    (Some(_), Some(_)) => ChangeKind::Updated,
+                    (Some(_), None) => ChangeKind::Removed,
+                    (None, None) => return None,
+                };
+                Some(change)
+            })
+            .collect();
+
+        Self { changes }
+    }
+}
+
+type Listener = Arc<dyn Fn(&ChangeBatch) + Send + Sync>;
+type KeyHook = Arc<dyn Fn(&ParameterChange) + Send + Sync>;
+
+/// Collects changes while a mutation is in progress and delivers them to
+/// subscribers once it completes.
+#[derive(Default)]
+pub(crate) struct ChangeNotifier {
+    listeners: Vec<Listener>,
+    key_hooks: BTreeMap<String, Vec<KeyHook>>,
+    /// Channels receiving the changes to keys starting with their prefix.
+    channels: Vec<(String, Sender<ParameterChange>)>,
+    depth: usize,
+    pending: Vec<ParameterChange>,
+}
+
+impl fmt::Debug for ChangeNotifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChangeNotifier")
+            .field("listeners", &self.listeners.len())
+            .field("key_hooks", &self.key_hooks.len())
+            .field("channels", &self.channels.len())
+            .finish()
+    }
+}
+
+impl ChangeNotifier {
+    pub(crate) fn subscribe(&mut self, listener: Listener) {
+        self.listeners.push(listener);
+    }
+
+    pub(crate) fn subscribe_key(&mut self, key: String, hook: KeyHook) {
+        self.key_hooks.entry(key).or_default().push(hook);
+    }
+
+    pub(crate) fn subscribe_prefix(&mut self, prefix: String, sender: Sender<ParameterChange>) {
+        self.channels.push((prefix, sender));
+    }
+
+    pub(crate) fn begin(&mut self) {
+        self.depth += 1;
+    }
+
+    pub(crate) fn end(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+        if self.depth == 0 {
+            self.flush();
+        }
+    }
+
+    /// The number of changes collected so far in the current batch, to pass to `discard_since`.
+    pub(crate) fn mark(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Forget the changes collected since `mark`, so they are never delivered.
+    pub(crate) fn discard_since(&mut self, mark: usize) {
+        self.pending.truncate(mark);
+    }
+
+    pub(crate) fn record(
+        &mut self,
+        key: &str,
+        old: Option<Arc<dyn ParameterValue>>,
+        new: Option<Arc<dyn ParameterValue>>,
+    ) {
+        if self.listeners.is_empty() && self.key_hooks.is_empty() && self.channels.is_empty() {
+            return;
+        }
+        let kind = match (&old, &new) {
+            (None, _) => ChangeKind::Added,
+            (Some(_), Some(_)) => ChangeKind::Updated,
+            (Some(_), None) => ChangeKind::Removed,
+        };
+        self.pending.push(ParameterChange { key: key.to_string(), kind, old, new });
+        if self.depth == 0 {
+            self.flush();
+        }
+    }
+
+    fn flush(&mut self) {
+        let batch = ChangeBatch::coalesce(std::mem::take(&mut self.pending));
+        if batch.is_empty() {
+            return;
+        }
+        for change in batch.changes() {
+            for hook in self.key_hooks.get(&change.key).into_iter().flatten() {
+                hook(change);
+            }
+        }
+        for listener in &self.listeners {
+            listener(&batch);
+        }
+        // A failed send means the receiver was dropped, so the channel is no longer needed.
+        self.channels.retain(|(prefix, sender)| {
+            batch.changes().iter()
+                .filter(|change| change.key.starts_with(prefix.as_str()))
+                .all(|change| sender.send(change.clone()).is_ok())
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use crate::Parameters;
+    use super::*;
+
+    fn recorder(params: &mut Parameters) -> Arc<Mutex<Vec<ChangeBatch>>> {
+        let batches = Arc::new(Mutex::new(Vec::new()));
+        let sink = batches.clone();
+        params.on_change(move |batch| sink.lock().unwrap().push(batch.clone()));
+        batches
+    }
+
+    #[test]
+    fn test_merge_delivers_single_batch() {
+        let mut params = Parameters::new().with("a", 1);
+        let batches = recorder(&mut params);
+
+        let other: Parameters = (0..200)
+            .map(|i| (format!("key{i}"), format!("{i}")))
+            .collect();
+        params.merge(other.with("a", 2));
+
+        let batches = batches.lock().unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].len(), 201);
+        let a = batches[0].changes().iter().find(|c| c.key == "a").unwrap();
+        assert_eq!(a.kind, ChangeKind::Updated);
+    }
+
+    #[test]
+    fn test_batch_coalesces_repeated_keys() {
+        let mut params = Parameters::new();
+        let batches = recorder(&mut params);
+
+        params.batch(|p| {
+            p.insert("x", 1);
+            p.insert("x", 2);
+            p.insert("tmp", true);
+            p.remove("tmp");
+        });
+
+        let batches = batches.lock().unwrap();
+        assert_eq!(batches.len(), 1);
+        let changes = batches[0].changes();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].kind, ChangeKind::Added);
+        assert_eq!(changes[0].new.as_ref().unwrap().to_string(), "2");
+    }
+
+    #[test]
+    fn test_subscriptions_filter_and_end_when_dropped() {
+        let mut params = Parameters::new();
+        let all = params.subscribe("");
+        let dropped = params.subscribe("db.");
+        drop(dropped);
+
+        params.merge(Parameters::new().with("db.host", "a").with("port", 1));
+        params.remove("port");
+
+        let kinds: Vec<(String, ChangeKind)> = all.try_iter().map(|change| (change.key, change.kind)).collect();
+        assert_eq!(kinds, [
+            ("db.host".to_string(), ChangeKind::Added),
+            ("port".to_string(), ChangeKind::Added),
+            ("port".to_string(), ChangeKind::Removed),
+        ]);
+        assert_eq!(params.notifier.channels.len(), 1);
+    }
+}
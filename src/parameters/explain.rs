@@ -0,0 +1,162 @@
+use std::{fmt, sync::Arc};
+use crate::value::ParameterValue;
+use super::{core::Parameters, display::short_type_name, redact::Redaction};
+
+/// What a layer contributed to the resolution of a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayerOutcome {
+    /// The layer defined the key and its value was used.
+    Selected,
+    /// The layer defined the key but a higher-priority layer won.
+    Shadowed,
+    /// The layer does not define the key.
+    Absent,
+}
+
+/// One layer consulted while resolving a key, in priority order.
+#[derive(Debug, Clone)]
+pub struct ExplainStep {
+    /// The layer's name, such as `explicit`, `default` or `overlay:acme`.
+    pub layer: String,
+    /// The layer's value as text, masked if the key is sensitive.
+    pub value: Option<String>,
+    /// The stored type of the layer's value.
+    pub type_name: Option<&'static str>,
+    pub outcome: LayerOutcome,
+}
+
+/// The resolved value of a key and the layer-by-layer trail that produced it.
+///
+/// Returned by [`Parameters::explain`] and `TenantParameters::explain`; its
+/// `Display` renders the trail for support tooling and logs.
+#[derive(Debug, Clone)]
+pub struct Explanation {
+    pub key: String,
+    /// The value that won, or `None` if no layer defines the key.
+    pub value: Option<Arc<dyn ParameterValue>>,
+    pub steps: Vec<ExplainStep>,
+}
+
+impl Explanation {
+    /// Build an explanation from layers listed from highest to lowest priority.
+    pub(crate) fn from_layers<'a, I>(key: &str, layers: I) -> Self
+    where
+        I: IntoIterator<Item = (String, Option<&'a Arc<dyn ParameterValue>>, &'a Redaction)>,
+    {
+        let mut value: Option<Arc<dyn ParameterValue>> = None;
+        let steps = layers.into_iter()
+            .map(|(layer, found, policy)| {
+                let outcome = match found {
+                    None => LayerOutcome::Absent,
+                    Some(_) if value.is_some() => LayerOutcome::Shadowed,
+                    Some(found) => {
+                        value = Some(found.clone());
+                        LayerOutcome::Selected
+                    }
+                };
+                ExplainStep {
+                    layer,
+                    value: found.map(|found| policy.render(key, found.as_ref())),
+                    type_name: found.map(|found| found.type_name()),
+                    outcome,
+                }
+            })
+            .collect();
+        Self { key: key.to_string(), value, steps }
+    }
+
+    /// The step whose value was used.
+    pub fn winner(&self) -> Option<&ExplainStep> {
+        self.steps.iter().find(|step| step.outcome == LayerOutcome::Selected)
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.winner() {
+            Some(winner) => write!(f, "{} resolved from {}", self.key, winner.layer)?,
+            None => write!(f, "{} is not set", self.key)?,
+        }
+        for step in &self.steps {
+            let marker = match step.outcome {
+                LayerOutcome::Selected => "*",
+                LayerOutcome::Shadowed | LayerOutcome::Absent => "-",
+            };
+            write!(f, "\n  {} {}: ", marker, step.layer)?;
+            match (&step.value, step.type_name) {
+                (Some(value), Some(type_name)) => write!(f, "{} ({})", value, short_type_name(type_name))?,
+                _ => f.write_str("not set")?,
+            }
+            if step.outcome == LayerOutcome::Shadowed {
+                f.write_str(", shadowed")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Parameters {
+    /// Explain how a key resolves: the explicit value first, then the registered default.
+    ///
+    /// This describes what `get_or_default` returns: an alias is followed to
+    /// its target, and an expired value counts as not set.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// An `Explanation` with the winning value and every layer consulted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{LayerOutcome, Parameters};
+    ///
+    /// let mut params = Parameters::new().with("port", 9090);
+    /// params.set_default("port", 8080);
+    ///
+    /// let explanation = params.explain("port");
+    /// assert_eq!(explanation.winner().unwrap().layer, "explicit");
+    /// assert_eq!(explanation.steps[1].outcome, LayerOutcome::Shadowed);
+    /// assert_eq!(explanation.to_string(), "\
+    /// port resolved from explicit
+    ///   * explicit: 9090 (i32)
+    ///   - default: 8080 (i32), shadowed");
+    /// ```
+    pub fn explain(&self, key: &str) -> Explanation {
+        let target = self.canonical_key(key);
+        Explanation::from_layers(key, [
+            ("explicit".to_string(), self.stored(target), &self.redaction),
+            ("default".to_string(), self.defaults.get(target), &self.redaction),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_and_sensitive_keys() {
+        let mut params = Parameters::new();
+        params.set_default("token", "abc");
+        params.mark_sensitive("token");
+
+        let explanation = params.explain("token");
+        assert_eq!(format!("{}", explanation), "token resolved from default\n  - explicit: not set\n  * default: *** (&str)");
+        assert_eq!(format!("{}", params.explain("other")), "other is not set\n  - explicit: not set\n  - default: not set");
+        assert!(params.explain("other").value.is_none());
+    }
+
+    #[test]
+    fn test_aliases_and_expired_values() {
+        let mut params = Parameters::new().with("new", 1);
+        params.alias("old", "new");
+        params.insert_with_ttl("token", "t", std::time::Duration::ZERO);
+
+        assert_eq!(params.explain("old").winner().unwrap().layer, "explicit");
+        assert!(params.explain("token").value.is_none());
+    }
+}
@@ -0,0 +1,165 @@
+use std::{net::{IpAddr, SocketAddr}, path::PathBuf};
+use crate::{
+    error::ParameterError,
+    value::{as_str, ByteSize, HumanDuration},
+    Result,
+};
+use super::core::Parameters;
+
+/// A type that can be read out of `Parameters` by key, as used by `params_get!`.
+///
+/// References `&T` borrow a value stored as `T`, and `&str` borrows any
+/// string value. Numbers, `bool`, `char`, `String` and the crate's parsed
+/// value types are converted with `try_get_parse`, so they also accept
+/// string values. `Option<T>` reads a missing key as `None`.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{FromParameter, Parameters};
+///
+/// let params = Parameters::new().with("port", "8080");
+/// assert_eq!(u16::from_parameter(&params, "port").unwrap(), 8080);
+/// assert_eq!(Option::<u16>::from_parameter(&params, "workers").unwrap(), None);
+/// ```
+pub trait FromParameter<'a>: Sized {
+    fn from_parameter(params: &'a Parameters, key: &str) -> Result<Self>;
+}
+
+impl<'a, T: 'static> FromParameter<'a> for &'a T {
+    fn from_parameter(params: &'a Parameters, key: &str) -> Result<Self> {
+        let value = params.lookup(key).ok_or_else(|| params.missing_key(key))?;
+        value.as_any().downcast_ref::<T>().ok_or_else(|| ParameterError::TypeMismatch {
+            expected: std::any::type_name::<T>(),
+            actual: value.type_name(),
+        })
+    }
+}
+
+impl<'a> FromParameter<'a> for &'a str {
+    fn from_parameter(params: &'a Parameters, key: &str) -> Result<Self> {
+        let value = params.lookup(key).ok_or_else(|| params.missing_key(key))?;
+        as_str(value.as_ref()).ok_or_else(|| ParameterError::TypeMismatch {
+            expected: "string",
+            actual: value.type_name(),
+        })
+    }
+}
+
+impl<'a, T: FromParameter<'a>> FromParameter<'a> for Option<T> {
+    fn from_parameter(params: &'a Parameters, key: &str) -> Result<Self> {
+        match T::from_parameter(params, key) {
+            Err(ParameterError::KeyNotFound { .. }) => Ok(None),
+            other => other.map(Some),
+        }
+    }
+}
+
+macro_rules! parsed {
+    ($($t:ty),+ $(,)?) => {
+        $(impl<'a> FromParameter<'a> for $t {
+            fn from_parameter(params: &'a Parameters, key: &str) -> Result<Self> {
+                params.try_get_parse(key)
+            }
+        })+
+    };
+}
+
+parsed!(
+    i8, i16, i32, i64, i128, isize,
+    u8, u16, u32, u64, u128, usize,
+    f32, f64, bool, char, String,
+    PathBuf, IpAddr, SocketAddr, ByteSize, HumanDuration,
+);
+
+/// Collects the failures of a `params_get!` expansion, so every missing key is reported at once.
+#[doc(hidden)]
+#[derive(Default)]
+pub struct Collector {
+    missing: Vec<String>,
+    first: Option<ParameterError>,
+}
+
+impl Collector {
+    pub fn take<T>(&mut self, key: &str, result: Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(ParameterError::KeyNotFound { .. }) => {
+                self.missing.push(key.to_string());
+                None
+            }
+            Err(error) => {
+                self.first.get_or_insert(error);
+                None
+            }
+        }
+    }
+
+    /// `MissingKeys` listing every missing key, or else the first other error.
+    pub fn finish<T>(self, values: impl FnOnce() -> T) -> Result<T> {
+        if !self.missing.is_empty() {
+            return Err(ParameterError::MissingKeys(self.missing));
+        }
+        match self.first {
+            Some(error) => Err(error),
+            None => Ok(values()),
+        }
+    }
+}
+
+/// Unwraps a tuple of values that a `Collector` has checked are all present.
+#[doc(hidden)]
+pub trait UnwrapAll {
+    type Output;
+    fn unwrap_all(self) -> Self::Output;
+}
+
+macro_rules! unwrap_all {
+    ($(($($t:ident),+)),+ $(,)?) => {
+        $(impl<$($t),+> UnwrapAll for ($(Option<$t>,)+) {
+            type Output = ($($t,)+);
+
+            #[allow(non_snake_case)]
+            fn unwrap_all(self) -> Self::Output {
+                let ($($t,)+) = self;
+                ($($t.expect("checked by Collector::finish"),)+)
+            }
+        })+
+    };
+}
+
+unwrap_all!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+    (A, B, C, D, E, F, G),
+    (A, B, C, D, E, F, G, H),
+    (A, B, C, D, E, F, G, H, I),
+    (A, B, C, D, E, F, G, H, I, J),
+    (A, B, C, D, E, F, G, H, I, J, K),
+    (A, B, C, D, E, F, G, H, I, J, K, L),
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::params_get;
+    use super::*;
+
+    #[test]
+    fn test_errors_are_aggregated() {
+        let params = Parameters::new().with("host", "db").with("port", "x").with("tags", vec![1, 2]);
+
+        let result: Result<(&str, u16, u8, u8)> = params_get!(params, "host", "user", "port", "pass");
+        assert!(matches!(result, Err(ParameterError::MissingKeys(keys)) if keys == ["user", "pass"]));
+
+        let result: Result<(&str, u16)> = params_get!(params, "host", "port");
+        assert!(matches!(result, Err(ParameterError::ConversionFailed { .. })));
+
+        let tags: Result<&Vec<i32>> = params_get!(params, "tags");
+        assert_eq!(tags.unwrap(), &[1, 2]);
+        assert!(matches!(<&i64>::from_parameter(&params, "host"), Err(ParameterError::TypeMismatch { .. })));
+    }
+}
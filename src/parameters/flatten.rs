@@ -0,0 +1,186 @@
+use std::{collections::BTreeMap, sync::Arc};
+use crate::{error::ParameterError, value::ParameterValue, Result};
+use super::{core::Parameters, store::StorageBackend};
+
+/// A partially built tree of dotted keys.
+enum Node {
+    Leaf(Arc<dyn ParameterValue>),
+    Branch(BTreeMap<String, Node>),
+}
+
+impl Node {
+    fn into_value(self, backend: StorageBackend) -> Arc<dyn ParameterValue> {
+        match self {
+            Node::Leaf(value) => value,
+            Node::Branch(children) => {
+                let mut params = Parameters::with_backend(backend);
+                for (key, node) in children {
+                    params.map_mut().insert(key, node.into_value(backend));
+                }
+                Arc::new(params)
+            }
+        }
+    }
+}
+
+impl Parameters {
+    /// Flatten nested `Parameters` into a single level with dotted keys.
+    ///
+    /// Only nested `Parameters` are descended into; other values, including
+    /// JSON arrays and objects, are kept as they are. Empty nested
+    /// `Parameters` are kept as values. Defaults are not included.
+    ///
+    /// # Returns
+    ///
+    /// A new `Parameters` whose keys are paths such as `db.pool.size`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new()
+    ///     .with("db", Parameters::new().with("host", "localhost").with("pool", Parameters::new().with("size", 4)))
+    ///     .with("debug", true);
+    ///
+    /// let flat = params.flatten();
+    /// assert_eq!(flat.keys().collect::<Vec<_>>(), ["db.host", "db.pool.size", "debug"]);
+    /// assert_eq!(flat.get::<i32>("db.pool.size"), Some(&4));
+    /// ```
+    pub fn flatten(&self) -> Parameters {
+        fn walk(prefix: &str, params: &Parameters, flat: &mut Parameters) {
+            let child = |key: &str| if prefix.is_empty() { key.to_string() } else { format!("{}.{}", prefix, key) };
+            if !prefix.is_empty() {
+                flat.redaction.extend(&params.redaction.with_prefix(&format!("{}.", prefix)));
+            }
+            for (key, value) in params.iter() {
+                match value.as_any().downcast_ref::<Parameters>() {
+                    Some(nested) if !nested.is_empty() => walk(&child(key), nested, flat),
+                    _ => {
+                        flat.map_mut().insert(child(key), value.clone());
+                    }
+                }
+            }
+        }
+
+        let mut flat = Parameters::with_backend(self.backend());
+        flat.redaction = self.redaction.clone();
+        walk("", self, &mut flat);
+        flat
+    }
+
+    /// Nest dotted keys into nested `Parameters`, the inverse of `flatten`.
+    ///
+    /// Values already stored as nested `Parameters` are merged with dotted
+    /// keys sharing their prefix. Defaults are not included.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the nested `Parameters`, or `ParameterError::MergeConflict`
+    /// if a key is both a value and the prefix of another key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let flat = Parameters::new().with("db.host", "localhost").with("db.port", 5432);
+    ///
+    /// let nested = flat.unflatten().unwrap();
+    /// let db = nested.get::<Parameters>("db").unwrap();
+    /// assert_eq!(db.get::<i32>("port"), Some(&5432));
+    /// assert_eq!(nested.flatten(), flat);
+    /// ```
+    pub fn unflatten(&self) -> Result<Parameters> {
+        let flat = self.flatten();
+        let mut root: BTreeMap<String, Node> = BTreeMap::new();
+        for (key, value) in Arc::unwrap_or_clone(flat.map) {
+            let mut segments: Vec<&str> = key.split('.').collect();
+            let last = segments.pop().unwrap_or_default();
+            let mut branch = &mut root;
+            for segment in segments {
+                let node = branch.entry(segment.to_string()).or_insert_with(|| Node::Branch(BTreeMap::new()));
+                branch = match node {
+                    Node::Branch(children) => children,
+                    Node::Leaf(_) => return Err(ParameterError::MergeConflict(String::from(key))),
+                };
+            }
+            if branch.contains_key(last) {
+                return Err(ParameterError::MergeConflict(String::from(key)));
+            }
+            branch.insert(last.to_string(), Node::Leaf(value));
+        }
+
+        let mut nested = Parameters::with_backend(self.backend());
+        nested.redaction = self.redaction.clone();
+        for (key, node) in root {
+            nested.map_mut().insert(key, node.into_value(self.backend()));
+        }
+        Ok(nested)
+    }
+
+    /// Split keys at the first `separator` and group them by the part before it.
+    ///
+    /// Keys without the separator are grouped under the empty string.
+    ///
+    /// # Arguments
+    ///
+    /// * `separator` - The text separating a prefix from the rest of a key, such as `_` or `.`.
+    ///
+    /// # Returns
+    ///
+    /// A map from each prefix to the `Parameters` holding the rest of its keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let env = Parameters::new().with("DB_HOST", "db").with("DB_PORT", "5432").with("CACHE_TTL", "60").with("DEBUG", "1");
+    /// let groups = env.group_by_prefix("_");
+    ///
+    /// assert_eq!(groups.keys().collect::<Vec<_>>(), ["", "CACHE", "DB"]);
+    /// assert_eq!(groups["DB"].get::<&str>("PORT"), Some(&"5432"));
+    /// assert!(groups[""].contains_key("DEBUG"));
+    /// ```
+    pub fn group_by_prefix(&self, separator: &str) -> BTreeMap<String, Parameters> {
+        let mut groups: BTreeMap<String, Parameters> = BTreeMap::new();
+        for (key, value) in self.iter() {
+            let (prefix, rest) = key.split_once(separator).unwrap_or(("", key));
+            groups.entry(prefix.to_string()).or_insert_with(|| Parameters::with_backend(self.backend())).map_mut().insert(rest.to_string(), value.clone());
+        }
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unflatten_merges_and_detects_conflicts() {
+        let mut params = Parameters::new()
+            .with("db", Parameters::new().with("host", "a"))
+            .with("db.port", 1)
+            .with("empty", Parameters::new());
+        params.insert("db.pool.size", 4);
+
+        let nested = params.unflatten().unwrap();
+        let db = nested.get::<Parameters>("db").unwrap();
+        assert_eq!(db.keys().collect::<Vec<_>>(), ["host", "pool", "port"]);
+        assert!(nested.get::<Parameters>("empty").unwrap().is_empty());
+
+        let conflict = Parameters::new().with("a", 1).with("a.b", 2).unflatten();
+        assert!(matches!(conflict, Err(ParameterError::MergeConflict(key)) if key == "a.b"));
+    }
+
+    #[test]
+    fn test_flatten_keeps_nested_sensitive_keys_masked() {
+        let mut inner = Parameters::new().with("password", "pw");
+        inner.mark_sensitive("password");
+        let flat = Parameters::new().with("db", inner).flatten();
+
+        assert!(flat.is_sensitive("db.password"));
+        assert_eq!(flat.get_string("db.password"), Some("***".to_string()));
+    }
+}
@@ -0,0 +1,116 @@
+use std::{ops::Deref, sync::Arc};
+use crate::{error::ParameterError, value::ParameterValue, Result};
+use super::{core::Parameters, key::Key};
+
+/// A `Parameters` set that can no longer be modified.
+///
+/// Every read of `Parameters` is available through `Deref`. The mutation
+/// methods kept here always fail with `ParameterError::Frozen`, so code that
+/// still tries to change the set at runtime gets an error it must handle
+/// rather than silently altering validated parameters. Cloning is O(1) and
+/// every clone is frozen too.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{ParameterError, Parameters};
+///
+/// let frozen = Parameters::new().with("workers", 4).freeze();
+/// assert_eq!(frozen.get::<i32>("workers"), Some(&4));
+///
+/// let err = frozen.clone().insert("workers", 8).unwrap_err();
+/// assert!(matches!(err, ParameterError::Frozen(key) if key == "workers"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrozenParameters {
+    params: Arc<Parameters>,
+}
+
+impl FrozenParameters {
+    /// Always fails; the set is frozen.
+    pub fn insert<K, V>(&mut self, key: K, _value: V) -> Result<Option<Arc<dyn ParameterValue>>>
+    where
+        K: Into<Key>,
+        V: ParameterValue + 'static,
+    {
+        Err(ParameterError::Frozen(String::from(key.into())))
+    }
+
+    /// Always fails; the set is frozen.
+    pub fn remove(&mut self, key: &str) -> Result<Option<Arc<dyn ParameterValue>>> {
+        Err(ParameterError::Frozen(key.to_string()))
+    }
+
+    /// Always fails, naming the first key of `other`; the set is frozen.
+    pub fn merge(&mut self, other: Parameters) -> Result<()> {
+        let key = other.keys().chain(other.defaults.keys()).next().map(String::from);
+        Err(ParameterError::Frozen(key.unwrap_or_default()))
+    }
+
+    /// Always fails; the set is frozen.
+    pub fn set_default<K, V>(&mut self, key: K, _value: V) -> Result<()>
+    where
+        K: Into<Key>,
+        V: ParameterValue + 'static,
+    {
+        Err(ParameterError::Frozen(String::from(key.into())))
+    }
+
+    /// An independent, mutable copy of the parameters. The frozen set is unaffected.
+    pub fn to_parameters(&self) -> Parameters {
+        Parameters::clone(&self.params)
+    }
+}
+
+impl Deref for FrozenParameters {
+    type Target = Parameters;
+
+    fn deref(&self) -> &Parameters {
+        &self.params
+    }
+}
+
+impl AsRef<Parameters> for FrozenParameters {
+    fn as_ref(&self) -> &Parameters {
+        &self.params
+    }
+}
+
+impl From<Parameters> for FrozenParameters {
+    fn from(params: Parameters) -> Self {
+        params.freeze()
+    }
+}
+
+impl Parameters {
+    /// Seal these parameters against further modification.
+    ///
+    /// Listeners and subscriptions are kept but will never fire again.
+    ///
+    /// # Returns
+    ///
+    /// A `FrozenParameters` holding these parameters.
+    pub fn freeze(self) -> FrozenParameters {
+        FrozenParameters {
+            params: Arc::new(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frozen_rejects_mutation_and_copies_are_independent() {
+        let mut frozen = FrozenParameters::from(Parameters::new().with("a", 1));
+        assert!(matches!(frozen.remove("a"), Err(ParameterError::Frozen(key)) if key == "a"));
+        assert!(frozen.merge(Parameters::new().with("b", 2)).is_err());
+        assert!(frozen.set_default("c", 3).is_err());
+
+        let mut copy = frozen.to_parameters();
+        copy.insert("a", 2);
+        assert_eq!(frozen.get::<i32>("a"), Some(&1));
+        assert_eq!(format!("{}", ParameterError::Frozen("a".into())), "Parameters are frozen: cannot modify a");
+    }
+}
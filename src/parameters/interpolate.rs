@@ -0,0 +1,201 @@
+use crate::{error::ParameterError, value::{as_str, Secret}, Result};
+use super::{core::Parameters, limits::EvalBudget};
+
+impl Parameters {
+    /// Expand the `${key}` references in `text`, following references inside
+    /// referenced string values.
+    ///
+    /// `stack` holds the keys being expanded, innermost last, to detect cycles.
+    /// `spliced_secret` is set when the value of a `Secret` was written into the result.
+    fn expand(&self, text: &str, stack: &mut Vec<String>, budget: &mut EvalBudget, spliced_secret: &mut bool) -> Result<String> {
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find('$') {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+            if let Some(escaped) = after.strip_prefix("${") {
+                out.push_str("${");
+                rest = escaped;
+                continue;
+            }
+            let Some(end) = after.strip_prefix('{').and_then(|inner| inner.find('}')) else {
+                out.push('$');
+                rest = after;
+                continue;
+            };
+            let reference = after[1..=end].trim();
+            rest = &after[end + 2..];
+
+            budget.step()?;
+            if let Some(first) = stack.iter().position(|key| key == reference) {
+                let mut cycle = stack[first..].to_vec();
+                cycle.push(reference.to_string());
+                return Err(ParameterError::InterpolationCycle(cycle));
+            }
+            let (value, _) = self.resolve(reference).ok_or_else(|| ParameterError::UnresolvedReference {
+                key: stack.last().cloned(),
+                reference: reference.to_string(),
+            })?;
+            let value = match value.exposed() {
+                Some(inner) => {
+                    *spliced_secret = true;
+                    inner
+                }
+                None => value.as_ref(),
+            };
+            match as_str(value) {
+                Some(nested) => {
+                    budget.enter()?;
+                    stack.push(reference.to_string());
+                    out.push_str(&self.expand(nested, stack, budget, spliced_secret)?);
+                    stack.pop();
+                    budget.leave();
+                }
+                None => out.push_str(&value.to_string()),
+            }
+            budget.check_output(out.len())?;
+        }
+        out.push_str(rest);
+        budget.check_output(out.len())?;
+        Ok(out)
+    }
+
+    /// Expand `${key}` references in a template using these parameters.
+    ///
+    /// References resolve to explicit values or defaults. String values are
+    /// expanded in turn, other values use their string form. A `Secret` is
+    /// spliced in with its real value, as a connection string needs, so the
+    /// result must be handled like the secret itself. `$${` writes a literal
+    /// `${`. The work done is bounded by `eval_limits`.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - The text holding `${key}` references.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the expanded text, `ParameterError::UnresolvedReference` if a
+    /// referenced key is missing, `ParameterError::InterpolationCycle` if values refer to each
+    /// other in a loop, or `ParameterError::EvalLimitExceeded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new()
+    ///     .with("host", "db.internal")
+    ///     .with("port", 5432)
+    ///     .with("addr", "${host}:${port}");
+    ///
+    /// assert_eq!(params.render("postgres://${addr}/app").unwrap(), "postgres://db.internal:5432/app");
+    /// assert_eq!(params.render("cost: $5, literal: $${host}").unwrap(), "cost: $5, literal: ${host}");
+    /// ```
+    pub fn render(&self, template: &str) -> Result<String> {
+        self.expand(template, &mut Vec::new(), &mut self.eval_limits.budget(), &mut false)
+    }
+
+    /// Replace every explicit string value holding `${key}` references with its expansion.
+    ///
+    /// Values are expanded as by `render` and stored as `String`s, or as
+    /// `Secret<String>`s when a `Secret` was spliced in, so the expansion stays
+    /// masked like the secret. Either every value is expanded or, on error,
+    /// none is changed.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of values that changed, or the first error, as for `render`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{ParameterError, Parameters};
+    ///
+    /// let mut params = Parameters::new()
+    ///     .with("user", "app")
+    ///     .with("url", "postgres://${user}@${host}/main");
+    /// params.set_default("host", "localhost");
+    ///
+    /// assert_eq!(params.interpolate().unwrap(), 1);
+    /// assert_eq!(params.get_str("url"), Some("postgres://app@localhost/main"));
+    ///
+    /// params.insert("a", "${b}");
+    /// params.insert("b", "${a}");
+    /// assert!(matches!(params.interpolate(), Err(ParameterError::InterpolationCycle(_))));
+    /// ```
+    pub fn interpolate(&mut self) -> Result<usize> {
+        let mut expanded = Vec::new();
+        for (key, value) in self.iter() {
+            let Some(text) = as_str(value.as_ref()).filter(|text| text.contains("${")) else {
+                continue;
+            };
+            let mut stack = vec![String::from(key)];
+            let mut spliced_secret = false;
+            let result = self.expand(text, &mut stack, &mut self.eval_limits.budget(), &mut spliced_secret)?;
+            if result != text {
+                expanded.push((key.clone(), result, spliced_secret));
+            }
+        }
+
+        let changed = expanded.len();
+        self.batch(|params| {
+            for (key, text, spliced_secret) in expanded {
+                if spliced_secret {
+                    params.insert(key, Secret::new(text));
+                } else {
+                    params.insert(key, text);
+                }
+            }
+        });
+        Ok(changed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EvalLimits;
+    use super::*;
+
+    #[test]
+    fn test_errors_leave_values_unchanged() {
+        let mut params = Parameters::new().with("a", "${b}").with("b", "${missing}").with("c", "${c}");
+        match params.interpolate() {
+            Err(ParameterError::UnresolvedReference { key, reference }) => {
+                assert_eq!((key.as_deref(), reference.as_str()), (Some("b"), "missing"));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert_eq!(params.get_str("a"), Some("${b}"));
+
+        match params.render("${c}") {
+            Err(error @ ParameterError::InterpolationCycle(_)) => assert_eq!(format!("{}", error), "Interpolation cycle: c -> c"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert_eq!(params.render("${ open").unwrap(), "${ open");
+    }
+
+    #[test]
+    fn test_secrets_are_spliced_and_stay_masked() {
+        let mut params = Parameters::new().with("dsn", "pg://u:${pw}@h");
+        params.insert_secret("pw", "hunter2");
+
+        assert_eq!(params.render("pg://u:${pw}@h").unwrap(), "pg://u:hunter2@h");
+        assert_eq!(params.interpolate().unwrap(), 1);
+        assert_eq!(params.get_string("dsn").as_deref(), Some("***"));
+        assert_eq!(params.get::<Secret<String>>("dsn").unwrap().expose(), "pg://u:hunter2@h");
+    }
+
+    #[test]
+    fn test_limits_bound_expansion() {
+        let mut params = Parameters::new()
+            .with("x0", "ab")
+            .with("x1", "${x0}${x0}${x0}${x0}")
+            .with("x2", "${x1}${x1}${x1}${x1}");
+        params.set_eval_limits(EvalLimits::new().max_output_len(16));
+        assert!(matches!(params.render("${x2}"), Err(ParameterError::EvalLimitExceeded { limit: "output length", .. })));
+
+        params.set_eval_limits(EvalLimits::new().max_depth(2));
+        assert!(matches!(params.render("${x2}"), Err(ParameterError::EvalLimitExceeded { limit: "depth", .. })));
+        assert_eq!(params.render("${x1}").unwrap(), "ab".repeat(4));
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+use crate::value::ParameterValue;
+use super::{core::Parameters, events::ChangeKind};
+
+/// One modification recorded while change tracking is enabled.
+///
+/// Values are kept in their string form, with sensitive keys masked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackedChange {
+    pub key: String,
+    pub kind: ChangeKind,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Journal {
+    pub(crate) enabled: bool,
+    pub(crate) changes: Vec<TrackedChange>,
+}
+
+impl Parameters {
+    /// Enable change tracking, so every insert, overwrite and removal is logged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{ChangeKind, Parameters};
+    ///
+    /// let mut params = Parameters::new().with("timeout", 30).with_tracking();
+    /// params.insert("timeout", 5);
+    /// params.remove("timeout");
+    ///
+    /// let changes = params.changes();
+    /// assert_eq!(changes.len(), 2);
+    /// assert_eq!(changes[0].kind, ChangeKind::Updated);
+    /// assert_eq!((changes[0].old.as_deref(), changes[0].new.as_deref()), (Some("30"), Some("5")));
+    /// assert_eq!(changes[1].kind, ChangeKind::Removed);
+    /// ```
+    pub fn with_tracking(mut self) -> Self {
+        self.set_tracking(true);
+        self
+    }
+
+    /// Turn change tracking on or off. Recorded changes are kept either way.
+    pub fn set_tracking(&mut self, enabled: bool) {
+        self.journal.enabled = enabled;
+    }
+
+    pub fn is_tracking(&self) -> bool {
+        self.journal.enabled
+    }
+
+    /// The changes recorded since tracking was enabled or `clear_changes` was last called, oldest first.
+    ///
+    /// Clones start with tracking in the same state but no recorded changes.
+    pub fn changes(&self) -> &[TrackedChange] {
+        &self.journal.changes
+    }
+
+    /// Discard the recorded changes, returning them.
+    pub fn clear_changes(&mut self) -> Vec<TrackedChange> {
+        std::mem::take(&mut self.journal.changes)
+    }
+
+    pub(crate) fn track(&mut self, key: &str, old: Option<&Arc<dyn ParameterValue>>, new: Option<&Arc<dyn ParameterValue>>) {
+        if !self.journal.enabled {
+            return;
+        }
+        let kind = match (old, new) {
+            (None, _) => ChangeKind::Added,
+            (Some(_), Some(_)) => ChangeKind::Updated,
+            (Some(_), None) => ChangeKind::Removed,
+        };
+        let render = |value: &Arc<dyn ParameterValue>| self.redaction.render(key, value.as_ref());
+        let change = TrackedChange {
+            key: key.to_string(),
+            kind,
+            old: old.map(render),
+            new: new.map(render),
+        };
+        self.journal.changes.push(change);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracking_masks_secrets_and_rolls_back() {
+        let mut params = Parameters::new().with_tracking();
+        params.insert_secret("db.password", "hunter2".to_string());
+        let _ = params.transaction(|tx| {
+            tx.insert("db.password", "changed".to_string());
+            Err::<(), _>("abort")
+        });
+
+        assert_eq!(params.changes(), [TrackedChange {
+            key: "db.password".to_string(),
+            kind: ChangeKind::Added,
+            old: None,
+            new: Some("***".to_string()),
+        }]);
+        assert_eq!(params.clear_changes().len(), 1);
+        assert!(params.changes().is_empty());
+        assert!(params.clone().is_tracking());
+    }
+}
@@ -0,0 +1,162 @@
+use std::sync::Arc;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+use crate::{error::ParameterError, value::ParameterValue, Result};
+use super::core::Parameters;
+
+/// Convert a stored value to JSON, keeping numbers, bools, strings, lists and nested
+/// `Parameters` typed and falling back to the string representation otherwise.
+///
+/// Unlike `ParameterValue::to_json` this never fails and is lossless: nested
+/// `Parameters` are not redacted and `Secret`s are exposed.
+pub(crate) fn value_to_json(value: &dyn ParameterValue) -> Value {
+    if let Some(inner) = value.exposed() {
+        return value_to_json(inner);
+    }
+    if let Some(params) = value.as_any().downcast_ref::<Parameters>() {
+        return Value::Object(params.json_object());
+    }
+    value.to_json().unwrap_or_else(|_| Value::String(value.to_string()))
+}
+
+/// Convert JSON into a stored value: objects become nested `Parameters`,
+/// scalars their natural Rust type, and arrays or null stay as raw JSON.
+pub(crate) fn json_to_value(json: Value) -> Arc<dyn ParameterValue> {
+    match json {
+        Value::Bool(b) => Arc::new(b),
+        Value::String(s) => Arc::new(s),
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                Arc::new(i)
+            } else if let Some(u) = n.as_u64() {
+                Arc::new(u)
+            } else {
+                Arc::new(n.as_f64().unwrap_or(f64::NAN))
+            }
+        }
+        Value::Object(object) => Arc::new(object_to_parameters(object)),
+        other => Arc::new(other),
+    }
+}
+
+fn object_to_parameters(object: Map<String, Value>) -> Parameters {
+    let mut params = Parameters::new();
+    for (key, value) in object {
+        params.insert_arc(key, json_to_value(value));
+    }
+    params
+}
+
+impl Parameters {
+    /// Convert to a JSON object without failing, using `value_to_json` for each value.
+    pub(crate) fn json_object(&self) -> Map<String, Value> {
+        self.iter()
+            .map(|(key, value)| (String::from(key), value_to_json(value.as_ref())))
+            .collect()
+    }
+
+    /// Build `Parameters` from a JSON object.
+    ///
+    /// Nested objects become nested `Parameters`; strings, integers, floats and
+    /// bools are stored as `String`, `i64` (or `u64`), `f64` and `bool`. Arrays
+    /// and nulls are kept as `serde_json::Value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - A JSON value, which must be an object.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Parameters`, or a `ParameterError::TypeMismatch` if `json` is not an object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    /// use serde_json::json;
+    ///
+    /// let params = Parameters::from_json(json!({ "port": 8080, "db": { "host": "x" } })).unwrap();
+    /// assert_eq!(params.get::<i64>("port"), Some(&8080));
+    /// assert!(params.get::<Parameters>("db").is_some());
+    /// ```
+    pub fn from_json(json: Value) -> Result<Parameters> {
+        match json {
+            Value::Object(object) => Ok(object_to_parameters(object)),
+            other => Err(ParameterError::TypeMismatch {
+                expected: "object",
+                actual: json_type_name(&other),
+            }),
+        }
+    }
+}
+
+pub(crate) fn json_type_name(json: &Value) -> &'static str {
+    match json {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// `Parameters` serialize as a map in the same form as `to_json_unredacted`:
+/// serialization is a lossless sink, so sensitive keys and `Secret`s keep
+/// their real values. Serialize `to_json()` instead where the output is meant
+/// to be read by people, such as an API response or a log line.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::Parameters;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Job {
+///     name: String,
+///     params: Parameters,
+/// }
+///
+/// let job = Job { name: "resize".to_string(), params: Parameters::new().with("width", 640) };
+/// let text = serde_json::to_string(&job).unwrap();
+/// assert_eq!(text, r#"{"name":"resize","params":{"width":640}}"#);
+///
+/// let job: Job = serde_json::from_str(&text).unwrap();
+/// assert_eq!(job.params.get::<i64>("width"), Some(&640));
+/// ```
+impl Serialize for Parameters {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.to_json_unredacted().map_err(serde::ser::Error::custom)?.serialize(serializer)
+    }
+}
+
+/// `Parameters` deserialize from any map, converted as described in [`Parameters::from_json`].
+impl<'de> Deserialize<'de> for Parameters {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        Parameters::from_json(Value::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn test_serde_round_trip_and_errors() {
+        let mut params = Parameters::new()
+            .with("tags", vec!["a".to_string(), "b".to_string()])
+            .with("db", Parameters::new().with("port", 5432).with("password", "x".to_string()));
+        params.mark_sensitive("password");
+        params.insert_secret("token", "t".to_string());
+
+        let value = serde_json::to_value(&params).unwrap();
+        assert_eq!(value, json!({ "tags": ["a", "b"], "db": { "port": 5432, "password": "x" }, "token": "t" }));
+        let back: Parameters = serde_json::from_value(value).unwrap();
+        assert_eq!(back.get::<Parameters>("db").unwrap().get::<i64>("port"), Some(&5432));
+
+        let error = serde_json::from_str::<Parameters>("[1, 2]").unwrap_err();
+        assert!(error.to_string().contains("expected object"), "{}", error);
+    }
+}
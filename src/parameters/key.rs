@@ -0,0 +1,225 @@
+use std::{
+    borrow::Borrow,
+    collections::HashSet,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, LazyLock, RwLock,
+    },
+};
+use crate::value::DisplayValue;
+
+/// Names registered with [`intern_keys`], shared by every `Parameters` that uses them.
+static INTERNED: LazyLock<RwLock<HashSet<Arc<str>>>> = LazyLock::new(RwLock::default);
+
+/// Set once a name is registered, so keys are only looked up when interning is in use.
+static INTERNING: AtomicBool = AtomicBool::new(false);
+
+/// Register key names whose storage is shared by every `Parameters`.
+///
+/// A key equal to a registered name is stored as a reference to the single
+/// shared copy instead of its own allocation, which saves memory when many
+/// parameter sets use the same names. Only registered names are shared, so
+/// the interner cannot grow with arbitrary keys; names stay registered for
+/// the life of the process.
+///
+/// # Arguments
+///
+/// * `names` - The key names to share.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{intern_keys, Parameters};
+///
+/// intern_keys(["host", "port"]);
+///
+/// let a = Parameters::new().with("host", "a.internal");
+/// let b = Parameters::new().with("host", "b.internal");
+/// assert!(a.keys().next().unwrap().ptr_eq(b.keys().next().unwrap()));
+/// ```
+pub fn intern_keys<I, S>(names: I)
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut interned = INTERNED.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+    for name in names {
+        if !interned.contains(name.as_ref()) {
+            interned.insert(Arc::from(name.as_ref()));
+        }
+    }
+    INTERNING.store(!interned.is_empty(), Ordering::Release);
+}
+
+/// A parameter key: an immutable, cheaply cloned string.
+///
+/// Keys compare, hash and order like the `str` they hold, and dereference to
+/// it, so most code can treat them as string slices. Names registered with
+/// [`intern_keys`] share one allocation across all `Parameters`.
+#[derive(Clone)]
+pub struct Key(Arc<str>);
+
+impl Key {
+    /// The key as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether two keys share the same storage, as interned keys do.
+    pub fn ptr_eq(&self, other: &Key) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// The registered copy of `name`, if interning is in use and the name is registered.
+    fn interned(name: &str) -> Option<Key> {
+        if !INTERNING.load(Ordering::Acquire) {
+            return None;
+        }
+        let interned = INTERNED.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+        interned.get(name).cloned().map(Key)
+    }
+}
+
+impl From<String> for Key {
+    fn from(name: String) -> Self {
+        Key::interned(&name).unwrap_or_else(|| Key(Arc::from(name)))
+    }
+}
+
+impl From<&str> for Key {
+    fn from(name: &str) -> Self {
+        Key::interned(name).unwrap_or_else(|| Key(Arc::from(name)))
+    }
+}
+
+impl From<&String> for Key {
+    fn from(name: &String) -> Self {
+        Key::from(name.as_str())
+    }
+}
+
+impl From<Key> for String {
+    fn from(key: Key) -> Self {
+        key.as_str().to_owned()
+    }
+}
+
+impl From<&Key> for String {
+    fn from(key: &Key) -> Self {
+        key.as_str().to_owned()
+    }
+}
+
+impl Deref for Key {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Key {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Key {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self)
+    }
+}
+
+impl DisplayValue for Key {}
+
+impl PartialEq for Key {
+    fn eq(&self, other: &Key) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Key {}
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, other: &Key) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Key {
+    fn cmp(&self, other: &Key) -> std::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+/// Hashes like the `str` it holds, so maps keyed by `Key` can be queried with `&str`.
+impl Hash for Key {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl PartialEq<str> for Key {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Key {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for Key {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<Key> for str {
+    fn eq(&self, other: &Key) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<Key> for &str {
+    fn eq(&self, other: &Key) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl PartialEq<Key> for String {
+    fn eq(&self, other: &Key) -> bool {
+        self == other.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_registered_names_are_shared() {
+        intern_keys(["key_test_shared"]);
+        assert!(Key::from("key_test_shared").ptr_eq(&Key::from("key_test_shared".to_string())));
+        assert!(!Key::from("key_test_other").ptr_eq(&Key::from("key_test_other")));
+        assert_eq!(Key::from("key_test_other"), "key_test_other");
+        assert_eq!(format!("[{:>4}]", Key::from("a")), "[   a]");
+    }
+}
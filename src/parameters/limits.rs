@@ -0,0 +1,426 @@
+use std::{mem::size_of, sync::Arc};
+use crate::{error::ParameterError, value::{share, ParameterValue}, Result};
+use super::{core::Parameters, key::Key};
+
+/// Bounds on the work done when expanding values, so parameters from
+/// semi-trusted sources cannot stall the resolver.
+///
+/// Interpolation and other evaluated features check their work against
+/// these limits and fail with `ParameterError::EvalLimitExceeded` as soon as
+/// one is crossed, rather than truncating the result.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{EvalLimits, Parameters};
+///
+/// let mut params = Parameters::new();
+/// params.set_eval_limits(EvalLimits::new().max_steps(500).max_depth(4));
+/// assert_eq!(params.eval_limits().steps(), 500);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EvalLimits {
+    steps: usize,
+    output_len: usize,
+    depth: usize,
+}
+
+impl Default for EvalLimits {
+    /// 10 000 steps, 64 KiB of output and 16 levels of nesting.
+    fn default() -> Self {
+        Self {
+            steps: 10_000,
+            output_len: 64 * 1024,
+            depth: 16,
+        }
+    }
+}
+
+impl EvalLimits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit the number of evaluation steps, such as references resolved.
+    pub fn max_steps(mut self, steps: usize) -> Self {
+        self.steps = steps;
+        self
+    }
+
+    /// Limit the length of any single evaluated value, in bytes.
+    pub fn max_output_len(mut self, bytes: usize) -> Self {
+        self.output_len = bytes;
+        self
+    }
+
+    /// Limit how deeply evaluations may nest, such as a reference to a value that itself holds references.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn output_len(&self) -> usize {
+        self.output_len
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Start tracking one evaluation against these limits.
+    pub fn budget(&self) -> EvalBudget {
+        EvalBudget {
+            limits: *self,
+            steps: 0,
+            depth: 0,
+        }
+    }
+}
+
+/// The work spent so far by one evaluation, checked against its `EvalLimits`.
+///
+/// Custom evaluated values can use a budget to honour the same limits as
+/// the built-in features.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{EvalLimits, ParameterError};
+///
+/// let mut budget = EvalLimits::new().max_steps(2).budget();
+/// budget.step().unwrap();
+/// budget.step().unwrap();
+/// assert!(matches!(budget.step(), Err(ParameterError::EvalLimitExceeded { limit: "steps", max: 2 })));
+/// ```
+#[derive(Debug, Clone)]
+pub struct EvalBudget {
+    limits: EvalLimits,
+    steps: usize,
+    depth: usize,
+}
+
+impl EvalBudget {
+    fn exceeded(limit: &'static str, max: usize) -> ParameterError {
+        ParameterError::EvalLimitExceeded { limit, max }
+    }
+
+    /// Count one step, failing once more than `max_steps` have been taken.
+    pub fn step(&mut self) -> Result<()> {
+        self.steps += 1;
+        if self.steps > self.limits.steps {
+            return Err(Self::exceeded("steps", self.limits.steps));
+        }
+        Ok(())
+    }
+
+    /// Enter one level of nesting, failing beyond `max_depth`. Pair with `leave`.
+    pub fn enter(&mut self) -> Result<()> {
+        if self.depth >= self.limits.depth {
+            return Err(Self::exceeded("depth", self.limits.depth));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    pub fn leave(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+    }
+
+    /// Check the length of a value being produced against `max_output_len`.
+    pub fn check_output(&self, len: usize) -> Result<()> {
+        if len > self.limits.output_len {
+            return Err(Self::exceeded("output length", self.limits.output_len));
+        }
+        Ok(())
+    }
+
+    /// The number of steps taken so far.
+    pub fn steps_taken(&self) -> usize {
+        self.steps
+    }
+}
+
+impl Parameters {
+    /// Set the limits applied when these parameters expand interpolated or evaluated values.
+    ///
+    /// # Arguments
+    ///
+    /// * `limits` - The step, output and nesting limits.
+    pub fn set_eval_limits(&mut self, limits: EvalLimits) {
+        self.eval_limits = limits;
+    }
+
+    /// The limits applied when expanding values; `EvalLimits::default()` unless changed.
+    pub fn eval_limits(&self) -> &EvalLimits {
+        &self.eval_limits
+    }
+}
+
+/// Bounds on the size of a parameter set, for sets filled from untrusted callers.
+///
+/// Limits are enforced on every write. `checked_insert` and the fallible
+/// bulk writes, such as `apply_overrides`, fail with
+/// `ParameterError::LimitExceeded`. Infallible writes, such as `insert`,
+/// `merge`, `extend` and `entry`, skip a value that would cross a limit and
+/// raise a warning for `on_warning` subscribers instead. `check_capacity`
+/// checks a set built before its limits were set. No limit is set by default.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{CapacityLimits, ParameterError, Parameters};
+///
+/// let mut params = Parameters::new();
+/// params.set_capacity_limits(CapacityLimits::new().max_keys(2).max_value_string_len(16));
+///
+/// params.checked_insert("name", "report").unwrap();
+/// let err = params.checked_insert("query", "x".repeat(100)).unwrap_err();
+/// assert!(matches!(err, ParameterError::LimitExceeded { limit: "value length", max: 16, actual: 100, .. }));
+///
+/// params.insert("query", "x".repeat(100));
+/// assert!(!params.contains_key("query"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CapacityLimits {
+    keys: Option<usize>,
+    value_string_len: Option<usize>,
+}
+
+impl CapacityLimits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit the number of explicit keys.
+    pub fn max_keys(mut self, keys: usize) -> Self {
+        self.keys = Some(keys);
+        self
+    }
+
+    /// Limit the length of each value's string form, in bytes. A `Secret` is
+    /// measured by its exposed value.
+    pub fn max_value_string_len(mut self, bytes: usize) -> Self {
+        self.value_string_len = Some(bytes);
+        self
+    }
+
+    pub fn keys(&self) -> Option<usize> {
+        self.keys
+    }
+
+    pub fn value_string_len(&self) -> Option<usize> {
+        self.value_string_len
+    }
+}
+
+fn limit_exceeded(key: &str, limit: &'static str, max: usize, actual: usize) -> ParameterError {
+    ParameterError::LimitExceeded { key: key.to_string(), limit, max, actual }
+}
+
+/// The length of a value's string form, measuring a `Secret` by its exposed value.
+fn string_len(value: &dyn ParameterValue) -> usize {
+    value.exposed().unwrap_or(value).to_string().len()
+}
+
+/// The approximate bytes held by one entry: its key, the value itself and its string form.
+fn entry_usage(key: &Key, value: &Arc<dyn ParameterValue>) -> usize {
+    let data = match value.as_any().downcast_ref::<Parameters>() {
+        Some(nested) => nested.approx_memory_usage(),
+        None => string_len(value.as_ref()),
+    };
+    size_of::<Key>() + key.len() + size_of::<Arc<dyn ParameterValue>>() + size_of_val(value.as_ref()) + data
+}
+
+impl Parameters {
+    /// Set the limits enforced on writes and by `check_capacity`.
+    ///
+    /// # Arguments
+    ///
+    /// * `limits` - The key count and value length limits.
+    pub fn set_capacity_limits(&mut self, limits: CapacityLimits) {
+        self.capacity_limits = limits;
+    }
+
+    /// The capacity limits; none are set unless changed.
+    pub fn capacity_limits(&self) -> &CapacityLimits {
+        &self.capacity_limits
+    }
+
+    fn check_value_len(&self, key: &str, value: &dyn ParameterValue) -> Result<()> {
+        match self.capacity_limits.value_string_len {
+            Some(max) if !value.as_any().is::<Parameters>() => {
+                let len = string_len(value);
+                if len > max {
+                    return Err(limit_exceeded(key, "value length", max, len));
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Check that storing `value` under `key` keeps the set within its capacity limits.
+    ///
+    /// Replacing the value of an existing key never counts against `max_keys`.
+    pub(crate) fn check_limits(&self, key: &str, value: &dyn ParameterValue) -> Result<()> {
+        if let Some(max) = self.capacity_limits.keys {
+            if !self.is_stored(key) {
+                let len = self.len();
+                if len >= max {
+                    return Err(limit_exceeded(key, "keys", max, len + 1));
+                }
+            }
+        }
+        self.check_value_len(key, value)
+    }
+
+    /// Insert a value only if the set stays within its capacity limits.
+    ///
+    /// Replacing the value of an existing key never counts against
+    /// `max_keys`. Nested parameters are not measured against
+    /// `max_value_string_len`; check them with their own limits.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A key that can be converted into a `Key`, such as a `&str` or `String`.
+    /// * `value` - A value that implements the `ParameterValue` trait.
+    ///
+    /// # Returns
+    ///
+    /// The value previously stored under the key, or `ParameterError::LimitExceeded`
+    /// without changing anything if a limit would be crossed.
+    pub fn checked_insert<K, V>(&mut self, key: K, value: V) -> Result<Option<Arc<dyn ParameterValue>>>
+    where
+        K: Into<Key>,
+        V: ParameterValue + 'static,
+    {
+        self.try_insert_arc(key.into(), share(value))
+    }
+
+    /// Check the whole set against its capacity limits, such as after
+    /// building it with `from_json` or `extend`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())`, or `ParameterError::LimitExceeded` for the first limit crossed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{CapacityLimits, Parameters};
+    ///
+    /// let mut params = Parameters::from_json(serde_json::json!({"a": 1, "b": 2, "c": 3})).unwrap();
+    /// params.set_capacity_limits(CapacityLimits::new().max_keys(2));
+    /// assert_eq!(params.check_capacity().unwrap_err().to_string(), "Capacity limit exceeded for c: keys is limited to 2, got 3");
+    /// ```
+    pub fn check_capacity(&self) -> Result<()> {
+        if let Some(max) = self.capacity_limits.keys {
+            let len = self.len();
+            if len > max {
+                let key = self.keys().nth(max).map(Key::as_str).unwrap_or_default();
+                return Err(limit_exceeded(key, "keys", max, len));
+            }
+        }
+        for (key, value) in self.iter() {
+            self.check_value_len(key, value.as_ref())?;
+        }
+        Ok(())
+    }
+
+    /// Estimate the memory held by the explicit values and defaults, in bytes.
+    ///
+    /// Each entry counts its key, the value's own size and the length of its
+    /// string form, which stands in for heap data such as string contents.
+    /// Nested parameters are measured recursively; shared values are counted
+    /// once per key. The estimate is meant for bounding and reporting, not
+    /// exact accounting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let small = Parameters::new().with("a", 1);
+    /// let large = Parameters::new().with("a", "x".repeat(10_000));
+    /// assert!(large.approx_memory_usage() > small.approx_memory_usage() + 10_000);
+    /// ```
+    pub fn approx_memory_usage(&self) -> usize {
+        self.map.iter()
+            .chain(self.defaults.iter())
+            .map(|(key, value)| entry_usage(key, value))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budget_depth_and_output() {
+        let mut budget = EvalLimits::new().max_depth(1).max_output_len(8).budget();
+        budget.enter().unwrap();
+        assert!(matches!(budget.enter(), Err(ParameterError::EvalLimitExceeded { limit: "depth", max: 1 })));
+        budget.leave();
+        budget.enter().unwrap();
+
+        assert!(budget.check_output(8).is_ok());
+        let err = budget.check_output(9).unwrap_err();
+        assert_eq!(format!("{}", err), "Evaluation limit exceeded: output length is limited to 8");
+    }
+
+    #[test]
+    fn test_checked_insert_allows_replacing_at_capacity() {
+        let mut params = Parameters::new().with("a", 1);
+        params.set_capacity_limits(CapacityLimits::new().max_keys(1));
+
+        assert!(params.checked_insert("a", 2).unwrap().is_some());
+        let err = params.checked_insert("b", 3).unwrap_err();
+        assert!(matches!(err, ParameterError::LimitExceeded { limit: "keys", max: 1, actual: 2, .. }));
+        assert!(!params.contains_key("b"));
+        assert!(params.clone().check_capacity().is_ok());
+    }
+
+    #[test]
+    fn test_limits_apply_to_every_write() {
+        let mut params = Parameters::new();
+        params.set_capacity_limits(CapacityLimits::new().max_keys(2).max_value_string_len(4));
+        let warnings = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = warnings.clone();
+        params.on_warning(move |warning| sink.lock().unwrap().push(warning.to_string()));
+
+        params.insert_secret("token", "x".repeat(10));
+        params.entry("a").or_insert(1).unwrap();
+        params.merge(Parameters::new().with("b", 2).with("c", 3));
+        assert_eq!(params.keys().map(Key::as_str).collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(warnings.lock().unwrap().len(), 2);
+        assert!(warnings.lock().unwrap()[0].starts_with("token: not stored; Capacity limit exceeded for token: value length"));
+
+        let err = params.apply_overrides(["a=5", "d=1"]).unwrap_err();
+        assert!(matches!(err, ParameterError::LimitExceeded { limit: "keys", .. }));
+        assert_eq!(params.get::<i32>("a"), Some(&1));
+    }
+
+    #[test]
+    fn test_entries_and_inserts_report_rejected_values() {
+        let mut params = Parameters::new().with("a", 1);
+        params.set_capacity_limits(CapacityLimits::new().max_keys(1).max_value_string_len(4));
+
+        assert!(matches!(params.checked_insert("b", 2), Err(ParameterError::LimitExceeded { limit: "keys", .. })));
+        assert!(matches!(params.try_insert("b", 2), Err(ParameterError::LimitExceeded { limit: "keys", .. })));
+        assert!(params.entry("b").or_insert(2).is_err());
+        match params.entry("a") {
+            crate::Entry::Occupied(mut entry) => {
+                assert!(entry.insert("too long").is_err());
+                assert_eq!(entry.insert(5).unwrap().as_any().downcast_ref::<i32>(), Some(&1));
+            }
+            crate::Entry::Vacant(_) => panic!("`a` is stored"),
+        }
+        assert_eq!(params.get::<i32>("a"), Some(&5));
+        assert_eq!(params.len(), 1);
+    }
+}
@@ -0,0 +1,175 @@
+use std::sync::Arc;
+use crate::{error::ParameterError, value::ParameterValue, Result};
+use super::{core::Parameters, diff::same_value};
+
+/// How [`Parameters::merge_with`] treats keys present in both sets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergeStrategy {
+    /// Values from the incoming set replace existing ones, like [`Parameters::merge`].
+    #[default]
+    Overwrite,
+    /// Existing values are kept; only new keys are added.
+    KeepExisting,
+    /// Fail with `ParameterError::MergeConflict` if a key holds different values in both sets.
+    ErrorOnConflict,
+    /// Nested `Parameters` values are merged recursively; other values are overwritten.
+    Deep,
+}
+
+impl Parameters {
+    /// Merge another `Parameters` instance into this one using the given strategy.
+    ///
+    /// With `ErrorOnConflict`, every key is checked before anything is applied,
+    /// so a failed merge leaves `self` untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Another `Parameters` instance.
+    /// * `strategy` - How to resolve keys present in both sets.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `ParameterError::MergeConflict` naming the first conflicting key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{MergeStrategy, Parameters};
+    ///
+    /// let mut params = Parameters::new()
+    ///     .with("db", Parameters::new().with("host", "localhost").with("port", 5432));
+    /// let overrides = Parameters::new()
+    ///     .with("db", Parameters::new().with("host", "db.internal"));
+    ///
+    /// params.merge_with(overrides, MergeStrategy::Deep).unwrap();
+    /// let db = params.get::<Parameters>("db").unwrap();
+    /// assert_eq!(db.get::<&str>("host"), Some(&"db.internal"));
+    /// assert_eq!(db.get::<i32>("port"), Some(&5432));
+    /// ```
+    pub fn merge_with(&mut self, mut other: Parameters, strategy: MergeStrategy) -> Result<()> {
+        match strategy {
+            MergeStrategy::Overwrite => self.merge(other),
+            MergeStrategy::KeepExisting => {
+                for (key, value) in Arc::unwrap_or_clone(std::mem::take(&mut other.defaults)) {
+                    self.defaults_mut().insert_if_absent(key, value);
+                }
+                self.merge_resolve(other, |_, existing, _| existing.clone());
+            }
+            MergeStrategy::ErrorOnConflict => {
+                let conflict = other.iter()
+                    .find(|(key, value)| self.stored(key).is_some_and(|existing| !same_value(existing, value)));
+                if let Some((key, _)) = conflict {
+                    return Err(ParameterError::MergeConflict(String::from(key)));
+                }
+                self.merge(other);
+            }
+            MergeStrategy::Deep => {
+                self.defaults_mut().extend(Arc::unwrap_or_clone(std::mem::take(&mut other.defaults)));
+                self.merge_resolve(other, |_, existing, incoming| deep_merge(existing, incoming));
+            }
+        }
+        Ok(())
+    }
+
+    /// Merge another `Parameters` instance, deciding each conflict with a callback.
+    ///
+    /// Keys only present in `other` are inserted as-is. For keys present in both,
+    /// `resolve` receives the key, the existing value, and the incoming value, and
+    /// returns the value to keep. Defaults of `other` are not merged.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Another `Parameters` instance.
+    /// * `resolve` - A callback choosing the value for a conflicting key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new().with("workers", 4);
+    /// params.merge_resolve(Parameters::new().with("workers", 8), |_key, a, b| {
+    ///     let larger = a.as_any().downcast_ref::<i32>() > b.as_any().downcast_ref::<i32>();
+    ///     if larger { a.clone() } else { b.clone() }
+    /// });
+    /// assert_eq!(params.get::<i32>("workers"), Some(&8));
+    /// ```
+    pub fn merge_resolve<F>(&mut self, mut other: Parameters, mut resolve: F)
+    where
+        F: FnMut(&str, &Arc<dyn ParameterValue>, &Arc<dyn ParameterValue>) -> Arc<dyn ParameterValue>,
+    {
+        self.redaction.extend(&other.redaction);
+        other.drop_expired();
+        self.batch(|params| {
+            for (key, incoming) in Arc::unwrap_or_clone(other.map) {
+                let value = match params.stored(&key) {
+                    Some(existing) => {
+                        let resolved = resolve(&key, existing, &incoming);
+                        if Arc::ptr_eq(&resolved, existing) {
+                            continue;
+                        }
+                        resolved
+                    }
+                    None => incoming,
+                };
+                params.insert_arc(key, value);
+            }
+        });
+    }
+}
+
+fn deep_merge(existing: &Arc<dyn ParameterValue>, incoming: &Arc<dyn ParameterValue>) -> Arc<dyn ParameterValue> {
+    let nested = (
+        existing.as_any().downcast_ref::<Parameters>(),
+        incoming.as_any().downcast_ref::<Parameters>(),
+    );
+    match nested {
+        (Some(existing), Some(incoming)) => {
+            let mut merged = existing.clone();
+            merged.merge_resolve(incoming.clone(), |_, a, b| deep_merge(a, b));
+            Arc::new(merged)
+        }
+        _ => incoming.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base() -> Parameters {
+        Parameters::new().with("host", "localhost").with("port", 5432)
+    }
+
+    #[test]
+    fn test_keep_existing() {
+        let mut params = base();
+        params.merge_with(Parameters::new().with("port", 6543).with("user", "admin"), MergeStrategy::KeepExisting).unwrap();
+        assert_eq!(params.get::<i32>("port"), Some(&5432));
+        assert_eq!(params.get::<&str>("user"), Some(&"admin"));
+    }
+
+    #[test]
+    fn test_error_on_conflict_is_atomic() {
+        let mut params = base();
+        let incoming = Parameters::new().with("host", "localhost").with("port", 6543).with("user", "admin");
+        let err = params.merge_with(incoming, MergeStrategy::ErrorOnConflict).unwrap_err();
+        assert!(matches!(err, ParameterError::MergeConflict(key) if key == "port"));
+        assert!(!params.contains_key("user"));
+    }
+
+    #[test]
+    fn test_deep_merge_recurses() {
+        let mut params = Parameters::new()
+            .with("db", base().with("pool", Parameters::new().with("max", 10).with("min", 1)));
+        let incoming = Parameters::new()
+            .with("db", Parameters::new().with("pool", Parameters::new().with("max", 32)));
+        params.merge_with(incoming, MergeStrategy::Deep).unwrap();
+
+        let db = params.get::<Parameters>("db").unwrap();
+        let pool = db.get::<Parameters>("pool").unwrap();
+        assert_eq!(db.get::<&str>("host"), Some(&"localhost"));
+        assert_eq!(pool.get::<i32>("max"), Some(&32));
+        assert_eq!(pool.get::<i32>("min"), Some(&1));
+    }
+}
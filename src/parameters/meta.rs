@@ -0,0 +1,185 @@
+use std::{collections::{BTreeMap, BTreeSet}, fmt::Write, sync::Arc};
+use crate::value::ParameterValue;
+use super::{core::Parameters, display::short_type_name, key::Key};
+
+/// Documentation attached to a key: what it does, its unit and whether it is deprecated.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{Meta, Parameters};
+///
+/// let mut params = Parameters::new();
+/// params.insert_with_meta("timeout_ms", 250, Meta {
+///     description: Some("Upstream request timeout".to_string()),
+///     unit: Some("ms".to_string()),
+///     ..Meta::default()
+/// });
+///
+/// assert_eq!(params.meta("timeout_ms").and_then(|meta| meta.unit.as_deref()), Some("ms"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Meta {
+    pub description: Option<String>,
+    /// The unit of the value, such as `ms` or `MiB`.
+    pub unit: Option<String>,
+    /// The version in which the key was deprecated.
+    pub deprecated_since: Option<String>,
+    /// The key to use instead of a deprecated one.
+    pub replaced_by: Option<String>,
+}
+
+impl Meta {
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated_since.is_some() || self.replaced_by.is_some()
+    }
+}
+
+/// The metadata of each key, shared between clones until one of them changes it.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MetaTable {
+    entries: Arc<BTreeMap<Key, Meta>>,
+}
+
+impl MetaTable {
+    pub(crate) fn extend(&mut self, other: &MetaTable) {
+        if !other.entries.is_empty() {
+            Arc::make_mut(&mut self.entries).extend(other.entries.iter().map(|(key, meta)| (key.clone(), meta.clone())));
+        }
+    }
+
+    pub(crate) fn take(&mut self, key: &str) -> Option<Meta> {
+        if !self.entries.contains_key(key) {
+            return None;
+        }
+        Arc::make_mut(&mut self.entries).remove(key)
+    }
+}
+
+impl Parameters {
+    /// Insert a value together with its metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A key that can be converted into a `Key`, such as a `&str` or `String`.
+    /// * `value` - A value that implements the `ParameterValue` trait.
+    /// * `meta` - The key's description, unit and deprecation.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the previous value if the key was already present.
+    pub fn insert_with_meta<K, V>(&mut self, key: K, value: V, meta: Meta) -> Option<Arc<dyn ParameterValue>>
+    where
+        K: Into<Key>,
+        V: ParameterValue + 'static,
+    {
+        let key = key.into();
+        self.set_meta(key.clone(), meta);
+        self.insert(key, value)
+    }
+
+    /// Attach metadata to a key, whether or not it has a value. Removing the value keeps the metadata.
+    pub fn set_meta<K: Into<Key>>(&mut self, key: K, meta: Meta) -> Option<Meta> {
+        Arc::make_mut(&mut self.meta.entries).insert(key.into(), meta)
+    }
+
+    pub fn meta(&self, key: &str) -> Option<&Meta> {
+        self.meta.entries.get(key)
+    }
+
+    /// Render a reference listing of every key with a value, a default or metadata.
+    ///
+    /// Each key is listed with its value and type, or as unset, followed by
+    /// its description and deprecation notice, in key order. Sensitive values
+    /// are masked.
+    ///
+    /// # Returns
+    ///
+    /// The rendered report.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{Meta, Parameters};
+    ///
+    /// let mut params = Parameters::new();
+    /// params.insert_with_meta("timeout", 30, Meta {
+    ///     description: Some("Request timeout".to_string()),
+    ///     unit: Some("s".to_string()),
+    ///     deprecated_since: Some("2.0".to_string()),
+    ///     replaced_by: Some("request_timeout".to_string()),
+    /// });
+    /// params.set_meta("retries", Meta { description: Some("Attempts per request".to_string()), ..Meta::default() });
+    ///
+    /// assert_eq!(params.describe(), "\
+    /// retries (unset)
+    ///     Attempts per request
+    /// timeout = 30 s (i32)
+    ///     Request timeout
+    ///     Deprecated since 2.0; use request_timeout instead
+    /// ");
+    /// ```
+    pub fn describe(&self) -> String {
+        let keys: BTreeSet<&Key> = self.keys()
+            .chain(self.defaults.keys())
+            .chain(self.meta.entries.keys())
+            .collect();
+
+        let mut out = String::new();
+        for key in keys {
+            let meta = self.meta(key);
+            let unit = meta.and_then(|meta| meta.unit.as_deref());
+            let explicit = self.stored(key).map(|value| (value, false));
+            match explicit.or_else(|| self.defaults.get(key).map(|value| (value, true))) {
+                Some((value, is_default)) => {
+                    let _ = write!(out, "{} = {}", key, self.redaction.render(key, value.as_ref()));
+                    if let Some(unit) = unit {
+                        let _ = write!(out, " {}", unit);
+                    }
+                    let _ = write!(out, " ({})", short_type_name(value.type_name()));
+                    if is_default {
+                        out.push_str(" [default]");
+                    }
+                }
+                None => {
+                    let _ = write!(out, "{} (unset)", key);
+                }
+            }
+            out.push('\n');
+
+            let Some(meta) = meta else {
+                continue;
+            };
+            if let Some(description) = &meta.description {
+                let _ = writeln!(out, "    {}", description);
+            }
+            match (&meta.deprecated_since, &meta.replaced_by) {
+                (Some(since), Some(replacement)) => { let _ = writeln!(out, "    Deprecated since {}; use {} instead", since, replacement); }
+                (Some(since), None) => { let _ = writeln!(out, "    Deprecated since {}", since); }
+                (None, Some(replacement)) => { let _ = writeln!(out, "    Deprecated; use {} instead", replacement); }
+                (None, None) => {}
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meta_survives_clone_merge_and_removal() {
+        let mut base = Parameters::new();
+        base.set_meta("port", Meta { unit: Some("tcp".to_string()), ..Meta::default() });
+        let mut overlay = Parameters::new();
+        overlay.insert_with_meta("host", "a", Meta { replaced_by: Some("hosts".to_string()), ..Meta::default() });
+        base.set_default("port", 80);
+        base.merge(overlay);
+        base.remove("host");
+
+        let copy = base.clone();
+        assert!(copy.meta("host").is_some_and(Meta::is_deprecated));
+        assert_eq!(copy.describe(), "host (unset)\n    Deprecated; use hosts instead\nport = 80 tcp (i32) [default]\n");
+    }
+}
@@ -1,5 +1,73 @@
 mod core;
+mod access;
+mod alias;
 mod builder;
+mod blend;
+mod events;
+mod defaults;
+mod entry;
+mod explain;
+mod flatten;
+mod frozen;
+mod diff;
+mod shard;
+mod snapshot;
+mod suggest;
+mod merge;
+mod json;
+mod journal;
+mod key;
+mod limits;
+mod meta;
+mod coerce;
+mod assign;
+mod compact;
+mod extract;
+mod display;
+mod canonical;
+mod interpolate;
+mod net;
+mod optional;
+mod parsers;
+mod patch;
+mod preview;
+mod pointer;
+mod query;
+mod quota;
+mod redact;
+mod rename;
+mod report;
+mod scope;
+mod store;
+mod transform;
+mod ttl;
+mod warnings;
 
 pub use core::*;
-pub use builder::ParametersBuilder;
\ No newline at end of file
+pub use builder::ParametersBuilder;
+pub use events::{ChangeBatch, ChangeKind, ParameterChange};
+pub use defaults::ValueSource;
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
+pub use frozen::FrozenParameters;
+pub use journal::TrackedChange;
+pub use key::{intern_keys, Key};
+pub use meta::Meta;
+pub use limits::{CapacityLimits, EvalBudget, EvalLimits};
+pub use explain::{ExplainStep, Explanation, LayerOutcome};
+pub use compact::CompactionReport;
+pub use diff::ParametersDiff;
+pub use report::DiffFormat;
+pub use merge::MergeStrategy;
+pub use optional::NullPolicy;
+pub use rename::RenamePolicy;
+pub use extract::FromParameter;
+#[doc(hidden)]
+pub use extract::{Collector, UnwrapAll};
+pub use preview::{MergePreview, MergeWarning};
+pub use quota::QuotaOverage;
+pub use scope::ScopedParameters;
+pub use snapshot::ParametersSnapshot;
+pub use store::StorageBackend;
+pub use warnings::Warning;
+pub(crate) use json::value_to_json;
+pub(crate) use canonical::write_canonical;
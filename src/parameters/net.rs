@@ -0,0 +1,116 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    path::PathBuf,
+    str::FromStr,
+};
+use crate::value::as_str;
+use super::core::Parameters;
+
+impl Parameters {
+    /// A value stored as `T`, or a string value parsed as one.
+    pub(crate) fn get_typed_or_parsed<T: FromStr + Clone + 'static>(&self, key: &str) -> Option<T> {
+        let value = self.lookup(key)?.as_ref();
+        if let Some(typed) = value.as_any().downcast_ref::<T>() {
+            return Some(typed.clone());
+        }
+        as_str(value)?.trim().parse().ok()
+    }
+
+    /// Get an IP address, whether stored as an `IpAddr` or as a string such as `"10.0.0.1"` or `"::1"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// The address, or `None` if the key is missing or the value is not an address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::{IpAddr, Ipv4Addr};
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("bind", "127.0.0.1");
+    /// assert_eq!(params.get_ip_addr("bind"), Some(IpAddr::V4(Ipv4Addr::LOCALHOST)));
+    /// ```
+    pub fn get_ip_addr(&self, key: &str) -> Option<IpAddr> {
+        self.get_typed_or_parsed(key)
+    }
+
+    /// Get a socket address, whether stored as a `SocketAddr` or as a string such as `"0.0.0.0:8080"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// The address, or `None` if the key is missing or the value is not a socket address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::SocketAddr;
+    /// use parameterx::Parameters;
+    ///
+    /// let listen: SocketAddr = "[::1]:9000".parse().unwrap();
+    /// let params = Parameters::new().with("listen", "0.0.0.0:8080").with("admin", listen);
+    ///
+    /// assert_eq!(params.get_socket_addr("listen").map(|addr| addr.port()), Some(8080));
+    /// assert_eq!(params.get_socket_addr("admin"), Some(listen));
+    /// ```
+    pub fn get_socket_addr(&self, key: &str) -> Option<SocketAddr> {
+        self.get_typed_or_parsed(key)
+    }
+
+    /// Get a filesystem path, whether stored as a `PathBuf` or as a string.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// The path, or `None` if the key is missing or the value is not a path or string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("data_dir", PathBuf::from("/var/lib/app"));
+    /// assert_eq!(params.get_path("data_dir"), Some(PathBuf::from("/var/lib/app")));
+    /// assert_eq!(params.get_string("data_dir").as_deref(), Some("/var/lib/app"));
+    /// ```
+    pub fn get_path(&self, key: &str) -> Option<PathBuf> {
+        let value = self.lookup(key)?.as_ref();
+        if let Some(path) = value.as_any().downcast_ref::<PathBuf>() {
+            return Some(path.clone());
+        }
+        as_str(value).map(PathBuf::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv6Addr;
+    use super::*;
+
+    #[test]
+    fn test_typed_and_string_values() {
+        let params = Parameters::new()
+            .with("ip", IpAddr::V6(Ipv6Addr::LOCALHOST))
+            .with("listen", " 10.0.0.1:80 ")
+            .with("bad", "10.0.0.1")
+            .with("port", 80);
+
+        assert_eq!(params.get_string("ip").as_deref(), Some("::1"));
+        assert_eq!(params.get_ip_addr("ip"), Some(IpAddr::V6(Ipv6Addr::LOCALHOST)));
+        assert_eq!(params.get_socket_addr("listen").map(|addr| addr.to_string()).as_deref(), Some("10.0.0.1:80"));
+        assert_eq!(params.get_socket_addr("bad"), None);
+        assert_eq!(params.get_path("port"), None);
+    }
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+use crate::{
+    error::ParameterError,
+    value::{is_null, Null, ParameterValue},
+    Result,
+};
+use super::{core::Parameters, key::Key};
+
+/// What `insert_opt` does with `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NullPolicy {
+    /// Leave the key absent, removing any value it had.
+    #[default]
+    Skip,
+    /// Store an explicit `Null`, so the key is present but empty.
+    Store,
+}
+
+impl Parameters {
+    /// Choose whether `insert_opt` skips `None` values or stores them as `Null`.
+    pub fn set_null_policy(&mut self, policy: NullPolicy) {
+        self.null_policy = policy;
+    }
+
+    pub fn null_policy(&self) -> NullPolicy {
+        self.null_policy
+    }
+
+    /// Insert an optional value.
+    ///
+    /// `Some` is inserted as usual. `None` follows the `NullPolicy`: by
+    /// default the key is left absent, removing any value it had; with
+    /// `NullPolicy::Store` an explicit `Null` is stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A key that can be converted into a `Key`, such as a `&str` or `String`.
+    /// * `value` - The optional value.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the previous value if the key was present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{NullPolicy, Parameters};
+    ///
+    /// let mut params = Parameters::new();
+    /// params.insert_opt("proxy", None::<String>);
+    /// assert!(!params.contains_key("proxy"));
+    ///
+    /// params.set_null_policy(NullPolicy::Store);
+    /// params.insert_opt("proxy", None::<String>);
+    /// assert_eq!(params.get_optional::<String>("proxy").unwrap(), None);
+    /// ```
+    pub fn insert_opt<K, V>(&mut self, key: K, value: Option<V>) -> Option<Arc<dyn ParameterValue>>
+    where
+        K: Into<Key>,
+        V: ParameterValue + 'static,
+    {
+        match (value, self.null_policy) {
+            (Some(value), _) => self.insert(key, value),
+            (None, NullPolicy::Store) => self.insert(key, Null),
+            (None, NullPolicy::Skip) => self.remove(&key.into()),
+        }
+    }
+
+    /// Get an optional value, telling an absent key apart from one that is present but null.
+    ///
+    /// A key holding `Null`, or a JSON `null` loaded with `from_json`, is null.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(Some(value))` for a value of type `T`, `Ok(None)` for a null value,
+    /// `ParameterError::KeyNotFound` if the key is absent, or
+    /// `ParameterError::TypeMismatch` if it holds another type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{Null, ParameterError, Parameters};
+    ///
+    /// let params = Parameters::new().with("timeout", 30).with("proxy", Null);
+    /// assert_eq!(params.get_optional::<i32>("timeout").unwrap(), Some(&30));
+    /// assert_eq!(params.get_optional::<String>("proxy").unwrap(), None);
+    /// assert!(matches!(params.get_optional::<i32>("retries"), Err(ParameterError::KeyNotFound { .. })));
+    /// ```
+    pub fn get_optional<T: 'static>(&self, key: &str) -> Result<Option<&T>> {
+        let value = self.lookup(key).ok_or_else(|| self.missing_key(key))?;
+        if let Some(value) = value.as_any().downcast_ref::<T>() {
+            return Ok(Some(value));
+        }
+        if is_null(value.as_ref()) {
+            return Ok(None);
+        }
+        Err(ParameterError::TypeMismatch {
+            expected: std::any::type_name::<T>(),
+            actual: value.type_name(),
+        })
+    }
+
+    /// Whether the key is present and holds `Null` or a JSON `null`.
+    pub fn is_null(&self, key: &str) -> bool {
+        self.stored(self.canonical_key(key)).is_some_and(|value| is_null(value.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn test_json_null_and_type_mismatch() {
+        let mut params = Parameters::from_json(json!({ "proxy": null, "port": 80 })).unwrap();
+        assert!(params.is_null("proxy"));
+        assert_eq!(params.get_optional::<String>("proxy").unwrap(), None);
+        assert!(matches!(params.get_optional::<String>("port"), Err(ParameterError::TypeMismatch { .. })));
+
+        params.insert("port", 80);
+        params.insert_opt("port", None::<i64>);
+        assert!(!params.contains_key("port"));
+        assert!(Null.eq_dyn(&Null));
+
+        let (n, j) = (Parameters::new().with("proxy", Null), Parameters::new().with("proxy", json!(null)));
+        assert_eq!(n == j, j == n);
+        assert_eq!(n.diff(&j).changed, j.diff(&n).changed);
+    }
+}
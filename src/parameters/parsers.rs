@@ -0,0 +1,130 @@
+use std::{any::{Any, TypeId}, collections::HashMap, error::Error, fmt, sync::Arc};
+use crate::{error::ParameterError, value::as_str, Result};
+use super::core::Parameters;
+
+type BoxError = Box<dyn Error + Send + Sync>;
+type Parser = Arc<dyn Fn(&str) -> std::result::Result<Box<dyn Any>, BoxError> + Send + Sync>;
+
+/// The parsers registered with `register_parser`, by target type, shared between clones.
+#[derive(Clone, Default)]
+pub(crate) struct ParserRegistry {
+    parsers: Arc<HashMap<TypeId, Parser>>,
+}
+
+impl fmt::Debug for ParserRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParserRegistry")
+            .field("parsers", &self.parsers.len())
+            .finish()
+    }
+}
+
+impl Parameters {
+    /// Register how to build a `T` from text, for `get_as::<T>`.
+    ///
+    /// This lets values loaded from text sources, such as environment
+    /// variables or INI files, be read as domain types without every call
+    /// site parsing them. Registering a parser for a type again replaces the
+    /// previous one. Clones made afterwards share the registered parsers.
+    ///
+    /// # Arguments
+    ///
+    /// * `parser` - Converts a value's string form into a `T`, or explains why it cannot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// struct Rgb(u8, u8, u8);
+    ///
+    /// let mut params = Parameters::new().with("accent", "#ff8800").with("muted", "grey");
+    /// params.register_parser(|text: &str| {
+    ///     let hex = text.strip_prefix('#').filter(|hex| hex.len() == 6).ok_or("expected #rrggbb")?;
+    ///     let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16);
+    ///     Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Rgb(channel(0)?, channel(2)?, channel(4)?))
+    /// });
+    ///
+    /// assert_eq!(params.get_as::<Rgb>("accent").unwrap(), Rgb(255, 136, 0));
+    /// assert!(params.get_as::<Rgb>("muted").is_err());
+    /// ```
+    pub fn register_parser<T, E, F>(&mut self, parser: F)
+    where
+        T: 'static,
+        E: Into<BoxError>,
+        F: Fn(&str) -> std::result::Result<T, E> + Send + Sync + 'static,
+    {
+        let parser: Parser = Arc::new(move |text| match parser(text) {
+            Ok(value) => Ok(Box::new(value) as Box<dyn Any>),
+            Err(error) => Err(error.into()),
+        });
+        Arc::make_mut(&mut self.parsers.parsers).insert(TypeId::of::<T>(), parser);
+    }
+
+    /// Whether a parser for `T` has been registered.
+    pub fn has_parser<T: 'static>(&self) -> bool {
+        self.parsers.parsers.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Get a value as a `T`, using the parser registered for `T` when it is stored as another type.
+    ///
+    /// A value stored as a `T` is returned as it is; any other value,
+    /// typically a string, has its string form passed to the parser.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the value, `ParameterError::KeyNotFound` if the
+    /// key is missing, `ConversionFailed` if the parser rejected the value, or
+    /// `TypeMismatch` if the value is not a `T` and no parser is registered.
+    pub fn get_as<T: Clone + 'static>(&self, key: &str) -> Result<T> {
+        let value = self.lookup(key).ok_or_else(|| self.missing_key(key))?;
+        if let Some(value) = value.as_any().downcast_ref::<T>() {
+            return Ok(value.clone());
+        }
+        let parser = self.parsers.parsers.get(&TypeId::of::<T>()).ok_or_else(|| ParameterError::TypeMismatch {
+            expected: std::any::type_name::<T>(),
+            actual: value.type_name(),
+        })?;
+        let parsed = match as_str(value.as_ref()) {
+            Some(text) => parser(text),
+            None => parser(&value.to_string()),
+        };
+        let parsed = parsed.map_err(|error| self.conversion_failed(key, value.as_ref(), std::any::type_name::<T>(), error))?;
+        Ok(*parsed.downcast::<T>().expect("parsers are registered under the type they produce"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DisplayValue;
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Percent(f64);
+
+    impl fmt::Display for Percent {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}%", self.0)
+        }
+    }
+
+    impl DisplayValue for Percent {}
+
+    #[test]
+    fn test_parses_other_types_and_shares_with_clones() {
+        let mut params = Parameters::new().with("ratio", 0.25).with("stored", Percent(10.0));
+        assert!(matches!(params.get_as::<Percent>("ratio"), Err(ParameterError::TypeMismatch { .. })));
+
+        params.register_parser(|text: &str| text.parse::<f64>().map(|ratio| Percent(ratio * 100.0)));
+        let copy = params.clone();
+        assert_eq!(copy.get_as::<Percent>("ratio").unwrap(), Percent(25.0));
+        assert_eq!(copy.get_as::<Percent>("stored").unwrap(), Percent(10.0));
+        assert!(copy.has_parser::<Percent>());
+        assert!(matches!(copy.get_as::<Percent>("missing"), Err(ParameterError::KeyNotFound { .. })));
+    }
+}
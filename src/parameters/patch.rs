@@ -0,0 +1,482 @@
+use std::sync::Arc;
+use serde_json::{json, Map, Value};
+use crate::{error::ParameterError, value::parse_as, Result};
+use super::{
+    core::Parameters,
+    json::json_to_value,
+};
+
+fn invalid(message: impl Into<String>) -> ParameterError {
+    ParameterError::InvalidPatch(message.into())
+}
+
+/// The value to store for a patched member: scalars replacing an existing
+/// value keep its type when they parse as it, anything else is rebuilt from JSON.
+fn patched_value(existing: Option<&Arc<dyn crate::ParameterValue>>, json: Value) -> Arc<dyn crate::ParameterValue> {
+    let text = match &json {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(_) | Value::Bool(_) => Some(json.to_string()),
+        _ => None,
+    };
+    text.zip(existing)
+        .and_then(|(text, existing)| parse_as(existing.as_ref(), &text))
+        .unwrap_or_else(|| json_to_value(json))
+}
+
+fn escape(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Split an RFC 6901 pointer into its unescaped reference tokens.
+pub(crate) fn parse_pointer(pointer: &str) -> Result<Vec<String>> {
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+    let Some(rest) = pointer.strip_prefix('/') else {
+        return Err(invalid(format!("JSON pointer must start with '/': {pointer}")));
+    };
+    Ok(rest.split('/')
+        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+        .collect())
+}
+
+pub(crate) fn array_index(token: &str, len: usize, allow_end: bool) -> Result<usize> {
+    if allow_end && token == "-" {
+        return Ok(len);
+    }
+    let valid = !token.is_empty() && token.bytes().all(|b| b.is_ascii_digit()) && (token == "0" || !token.starts_with('0'));
+    let index: usize = token.parse().ok().filter(|_| valid)
+        .ok_or_else(|| invalid(format!("invalid array index: {token}")))?;
+    let max = if allow_end { len } else { len.saturating_sub(1) };
+    if index > max || (!allow_end && len == 0) {
+        return Err(invalid(format!("array index out of bounds: {token}")));
+    }
+    Ok(index)
+}
+
+fn resolve<'a>(root: &'a Value, tokens: &[String]) -> Option<&'a Value> {
+    tokens.iter().try_fold(root, |node, token| match node {
+        Value::Object(object) => object.get(token),
+        Value::Array(items) => array_index(token, items.len(), false).ok().and_then(|i| items.get(i)),
+        _ => None,
+    })
+}
+
+fn parent_mut<'a>(root: &'a mut Value, tokens: &[String]) -> Result<(&'a mut Value, String)> {
+    let (last, parents) = tokens.split_last()
+        .ok_or_else(|| invalid("the root of the parameters cannot be replaced"))?;
+    let mut node = root;
+    for token in parents {
+        node = match node {
+            Value::Object(object) => object.get_mut(token),
+            Value::Array(items) => {
+                let index = array_index(token, items.len(), false)?;
+                items.get_mut(index)
+            }
+            _ => None,
+        }
+        .ok_or_else(|| invalid(format!("path does not exist: /{}", parents.join("/"))))?;
+    }
+    Ok((node, last.clone()))
+}
+
+fn add(root: &mut Value, tokens: &[String], value: Value) -> Result<()> {
+    let (parent, last) = parent_mut(root, tokens)?;
+    match parent {
+        Value::Object(object) => {
+            object.insert(last, value);
+            Ok(())
+        }
+        Value::Array(items) => {
+            let index = array_index(&last, items.len(), true)?;
+            items.insert(index, value);
+            Ok(())
+        }
+        _ => Err(invalid(format!("cannot add to a scalar at {last}"))),
+    }
+}
+
+fn remove(root: &mut Value, tokens: &[String]) -> Result<Value> {
+    let (parent, last) = parent_mut(root, tokens)?;
+    match parent {
+        Value::Object(object) => object.remove(&last)
+            .ok_or_else(|| invalid(format!("path does not exist: {last}"))),
+        Value::Array(items) => {
+            let index = array_index(&last, items.len(), false)?;
+            Ok(items.remove(index))
+        }
+        _ => Err(invalid(format!("cannot remove from a scalar at {last}"))),
+    }
+}
+
+fn apply_operation(root: &mut Value, operation: &Value) -> Result<()> {
+    let field = |name: &str| operation.get(name)
+        .ok_or_else(|| invalid(format!("patch operation is missing '{name}'")));
+    let pointer = |name: &str| -> Result<Vec<String>> {
+        let text = field(name)?.as_str()
+            .ok_or_else(|| invalid(format!("'{name}' must be a string")))?;
+        parse_pointer(text)
+    };
+
+    let op = field("op")?.as_str().ok_or_else(|| invalid("'op' must be a string"))?;
+    let path = pointer("path")?;
+    match op {
+        "add" => add(root, &path, field("value")?.clone()),
+        "remove" => remove(root, &path).map(|_| ()),
+        "replace" => {
+            remove(root, &path)?;
+            add(root, &path, field("value")?.clone())
+        }
+        "move" => {
+            let from = pointer("from")?;
+            if path.len() > from.len() && path.starts_with(&from) {
+                return Err(invalid("cannot move a value into one of its children"));
+            }
+            let value = remove(root, &from)?;
+            add(root, &path, value)
+        }
+        "copy" => {
+            let from = pointer("from")?;
+            let value = resolve(root, &from)
+                .ok_or_else(|| invalid("'from' path does not exist"))?
+                .clone();
+            add(root, &path, value)
+        }
+        "test" => {
+            let expected = field("value")?;
+            match resolve(root, &path) {
+                Some(actual) if actual == expected => Ok(()),
+                _ => Err(invalid(format!("test failed at /{}", path.join("/")))),
+            }
+        }
+        other => Err(invalid(format!("unknown patch operation: {other}"))),
+    }
+}
+
+fn diff_json(path: &str, old: &Map<String, Value>, new: &Map<String, Value>, ops: &mut Vec<Value>) {
+    for (key, old_value) in old {
+        let child = format!("{path}/{}", escape(key));
+        match (old_value, new.get(key)) {
+            (_, None) => ops.push(json!({ "op": "remove", "path": child })),
+            (Value::Object(a), Some(Value::Object(b))) => diff_json(&child, a, b, ops),
+            (a, Some(b)) if a != b => ops.push(json!({ "op": "replace", "path": child, "value": b })),
+            _ => {}
+        }
+    }
+    for (key, new_value) in new {
+        if !old.contains_key(key) {
+            ops.push(json!({ "op": "add", "path": format!("{path}/{}", escape(key)), "value": new_value }));
+        }
+    }
+}
+
+fn merge_patch_json(old: &Map<String, Value>, new: &Map<String, Value>) -> Map<String, Value> {
+    let mut patch = Map::new();
+    for key in old.keys().filter(|key| !new.contains_key(*key)) {
+        patch.insert(key.clone(), Value::Null);
+    }
+    for (key, new_value) in new {
+        match (old.get(key), new_value) {
+            (Some(Value::Object(a)), Value::Object(b)) => {
+                let nested = merge_patch_json(a, b);
+                if !nested.is_empty() {
+                    patch.insert(key.clone(), Value::Object(nested));
+                }
+            }
+            (Some(old_value), new_value) if old_value == new_value => {}
+            (_, new_value) => {
+                patch.insert(key.clone(), new_value.clone());
+            }
+        }
+    }
+    patch
+}
+
+impl Parameters {
+    /// Produce an RFC 7386 JSON Merge Patch that turns these parameters into `other`.
+    ///
+    /// Removed keys are emitted as `null` and nested `Parameters` only list the
+    /// members that differ. As the RFC notes, a merge patch cannot set a value
+    /// to JSON `null`; such values in `other` would be applied as removals.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The target `Parameters`.
+    ///
+    /// # Returns
+    ///
+    /// A JSON object suitable for [`Parameters::apply_merge_patch`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    /// use serde_json::json;
+    ///
+    /// let before = Parameters::new()
+    ///     .with("debug", true)
+    ///     .with("db", Parameters::new().with("host", "a").with("port", 5432));
+    /// let after = Parameters::new()
+    ///     .with("db", Parameters::new().with("host", "b").with("port", 5432));
+    ///
+    /// assert_eq!(before.to_merge_patch(&after), json!({ "debug": null, "db": { "host": "b" } }));
+    /// ```
+    pub fn to_merge_patch(&self, other: &Parameters) -> Value {
+        Value::Object(merge_patch_json(&self.json_object(), &other.json_object()))
+    }
+
+    /// Produce an RFC 6902 JSON Patch that turns these parameters into `other`.
+    ///
+    /// Nested `Parameters` are diffed recursively; any other changed value is
+    /// replaced as a whole.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The target `Parameters`.
+    ///
+    /// # Returns
+    ///
+    /// A JSON array of patch operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    /// use serde_json::json;
+    ///
+    /// let before = Parameters::new().with("replicas", 2).with("debug", true);
+    /// let after = Parameters::new().with("replicas", 3);
+    ///
+    /// assert_eq!(before.to_json_patch(&after), json!([
+    ///     { "op": "remove", "path": "/debug" },
+    ///     { "op": "replace", "path": "/replicas", "value": 3 },
+    /// ]));
+    /// ```
+    pub fn to_json_patch(&self, other: &Parameters) -> Value {
+        let mut ops = Vec::new();
+        diff_json("", &self.json_object(), &other.json_object(), &mut ops);
+        Value::Array(ops)
+    }
+
+    /// Apply an RFC 6902 JSON Patch.
+    ///
+    /// The patch is applied atomically: if any operation fails, nothing changes.
+    /// Changes are applied at the leaves: nested `Parameters` are updated in
+    /// place and only the values that actually changed are replaced, so every
+    /// other value, including the siblings of a patched one, keeps its Rust
+    /// type. A changed scalar keeps the type of the value it replaces when it
+    /// parses as that type; other values are rebuilt from JSON as described in
+    /// [`Parameters::from_json`].
+    ///
+    /// # Arguments
+    ///
+    /// * `patch` - A JSON array of patch operations.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `ParameterError::InvalidPatch` describing the failing operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    /// use serde_json::json;
+    ///
+    /// let mut params = Parameters::new().with("replicas", 2);
+    /// params.apply_json_patch(&json!([
+    ///     { "op": "test", "path": "/replicas", "value": 2 },
+    ///     { "op": "replace", "path": "/replicas", "value": 3 },
+    /// ])).unwrap();
+    /// assert_eq!(params.get::<i32>("replicas"), Some(&3));
+    /// ```
+    pub fn apply_json_patch(&mut self, patch: &Value) -> Result<()> {
+        let operations = patch.as_array().ok_or_else(|| invalid("a JSON Patch must be an array"))?;
+        let original = self.json_object();
+        let mut document = Value::Object(original.clone());
+        for operation in operations {
+            apply_operation(&mut document, operation)?;
+        }
+        let Value::Object(patched) = document else {
+            return Err(invalid("the patched document is no longer an object"));
+        };
+
+        self.batch(|params| params.reconcile(&original, patched));
+        Ok(())
+    }
+
+    /// Bring these parameters from their JSON form `original` to `patched`,
+    /// descending into nested `Parameters` that stay objects.
+    fn reconcile(&mut self, original: &Map<String, Value>, patched: Map<String, Value>) {
+        for key in original.keys().filter(|key| !patched.contains_key(*key)) {
+            self.remove(key);
+        }
+        for (key, value) in patched {
+            let before = original.get(&key);
+            if before == Some(&value) {
+                continue;
+            }
+            let nested = self.stored(&key).and_then(|existing| existing.as_any().downcast_ref::<Parameters>());
+            match (nested, before, value) {
+                (Some(nested), Some(Value::Object(before)), Value::Object(after)) => {
+                    let mut nested = nested.clone();
+                    nested.reconcile(before, after);
+                    self.insert(key, nested);
+                }
+                (_, _, value) => {
+                    let value = patched_value(self.stored(&key), value);
+                    self.insert_arc(key, value);
+                }
+            }
+        }
+    }
+
+    /// Apply an RFC 7386 JSON Merge Patch.
+    ///
+    /// `null` members remove keys, object members are merged recursively into
+    /// nested `Parameters`, and any other member replaces the stored value,
+    /// keeping its type as `apply_json_patch` does.
+    ///
+    /// # Arguments
+    ///
+    /// * `patch` - A JSON object describing the changes.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `ParameterError::InvalidPatch` if `patch` is not an object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    /// use serde_json::json;
+    ///
+    /// let mut params = Parameters::new()
+    ///     .with("debug", true)
+    ///     .with("db", Parameters::new().with("host", "a").with("port", 5432));
+    /// params.apply_merge_patch(&json!({ "debug": null, "db": { "host": "b" } })).unwrap();
+    ///
+    /// assert!(!params.contains_key("debug"));
+    /// let db = params.get::<Parameters>("db").unwrap();
+    /// assert_eq!(db.get_string("host"), Some("b".to_string()));
+    /// assert_eq!(db.get::<i32>("port"), Some(&5432));
+    /// ```
+    pub fn apply_merge_patch(&mut self, patch: &Value) -> Result<()> {
+        let members = patch.as_object().ok_or_else(|| invalid("a merge patch for parameters must be an object"))?;
+        self.batch(|params| params.merge_members(members));
+        Ok(())
+    }
+
+    fn merge_members(&mut self, members: &Map<String, Value>) {
+        for (key, member) in members {
+            match member {
+                Value::Null => {
+                    self.remove(key);
+                }
+                Value::Object(nested) => {
+                    let mut target = self.get::<Parameters>(key).cloned().unwrap_or_default();
+                    target.merge_members(nested);
+                    self.insert(key.clone(), target);
+                }
+                other => {
+                    let value = patched_value(self.stored(key), other.clone());
+                    self.insert_arc(key.clone(), value);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_patch() {
+        let before = Parameters::new()
+            .with("name", "svc")
+            .with("db", Parameters::new().with("host", "a").with("port", 5432))
+            .with("old", 1);
+        let after = Parameters::new()
+            .with("name", "svc")
+            .with("db", Parameters::new().with("host", "b").with("port", 5432))
+            .with("new", false);
+
+        let patch = before.to_json_patch(&after);
+        let mut patched = before.clone();
+        patched.apply_json_patch(&patch).unwrap();
+
+        assert!(patched.to_json_patch(&after).as_array().unwrap().is_empty());
+        assert_eq!(patched.get::<&str>("name"), Some(&"svc"));
+    }
+
+    #[test]
+    fn test_failed_patch_is_atomic() {
+        let mut params = Parameters::new().with("a", 1);
+        let result = params.apply_json_patch(&json!([
+            { "op": "add", "path": "/b", "value": 2 },
+            { "op": "remove", "path": "/missing" },
+        ]));
+        assert!(matches!(result, Err(ParameterError::InvalidPatch(_))));
+        assert!(!params.contains_key("b"));
+    }
+
+    #[test]
+    fn test_move_copy_and_arrays() {
+        let mut params = Parameters::from_json(json!({ "hosts": ["a", "b"], "x": 1 })).unwrap();
+        params.apply_json_patch(&json!([
+            { "op": "add", "path": "/hosts/-", "value": "c" },
+            { "op": "copy", "from": "/hosts/0", "path": "/primary" },
+            { "op": "move", "from": "/x", "path": "/y" },
+        ])).unwrap();
+
+        assert_eq!(params.get::<Value>("hosts"), Some(&json!(["a", "b", "c"])));
+        assert_eq!(params.get_string("primary"), Some("a".to_string()));
+        assert_eq!(params.get::<i64>("y"), Some(&1));
+        assert!(!params.contains_key("x"));
+    }
+
+    #[test]
+    fn test_patch_keeps_sibling_types() {
+        let mut db = Parameters::new().with("host", "a").with("port", 5432i32).with("tags", vec!["x"]);
+        db.insert_secret("password", "pw");
+        let mut params = Parameters::new().with("db", db).with("replicas", 2u8);
+
+        params.apply_json_patch(&json!([
+            { "op": "replace", "path": "/db/host", "value": "b" },
+            { "op": "replace", "path": "/replicas", "value": 3 },
+        ])).unwrap();
+
+        let db = params.get::<Parameters>("db").unwrap();
+        assert_eq!(db.get::<String>("host").map(String::as_str), Some("b"));
+        assert_eq!(db.get::<i32>("port"), Some(&5432));
+        assert_eq!(db.get::<Vec<&str>>("tags"), Some(&vec!["x"]));
+        assert_eq!(db.get::<crate::Secret<&str>>("password").map(|pw| *pw.expose()), Some("pw"));
+        assert_eq!(params.get::<u8>("replicas"), Some(&3));
+    }
+
+    #[test]
+    fn test_merge_patch_round_trip() {
+        let before = Parameters::new()
+            .with("keep", 1)
+            .with("drop", "x")
+            .with("nested", Parameters::new().with("a", 1).with("b", Parameters::new().with("c", true)));
+        let after = Parameters::new()
+            .with("keep", 1)
+            .with("added", 2.5)
+            .with("nested", Parameters::new().with("a", 2).with("b", Parameters::new().with("c", true)));
+
+        let patch = before.to_merge_patch(&after);
+        assert_eq!(patch, json!({ "drop": null, "added": 2.5, "nested": { "a": 2 } }));
+
+        let mut patched = before.clone();
+        patched.apply_merge_patch(&patch).unwrap();
+        assert_eq!(patched.to_merge_patch(&after), json!({}));
+        assert_eq!(patched.get::<i32>("keep"), Some(&1));
+    }
+
+    #[test]
+    fn test_pointer_escaping() {
+        assert_eq!(parse_pointer("/a~1b/c~0d").unwrap(), vec!["a/b", "c~d"]);
+        assert!(parse_pointer("a").is_err());
+    }
+}
@@ -0,0 +1,98 @@
+use serde_json::Value;
+use crate::value::{BoolVec, FloatVec, IntVec, ParameterValue, StrVec};
+use super::{
+    core::Parameters,
+    patch::{array_index, parse_pointer},
+};
+
+/// Step from `node` to its child named by one reference token.
+fn child<'a>(node: &'a dyn ParameterValue, token: &str) -> Option<&'a dyn ParameterValue> {
+    let any = node.as_any();
+    if let Some(params) = any.downcast_ref::<Parameters>() {
+        return params.stored(token).map(|value| value.as_ref());
+    }
+    if let Some(json) = any.downcast_ref::<Value>() {
+        return match json {
+            Value::Object(object) => object.get(token).map(|value| value as &dyn ParameterValue),
+            Value::Array(items) => {
+                let index = array_index(token, items.len(), false).ok()?;
+                Some(&items[index] as &dyn ParameterValue)
+            }
+            _ => None,
+        };
+    }
+    macro_rules! int_vec {
+        ($($t:ty),+) => {
+            $(if let Some(ints) = any.downcast_ref::<IntVec<$t>>() {
+                let index = array_index(token, ints.0.len(), false).ok()?;
+                return Some(&ints.0[index] as &dyn ParameterValue);
+            })+
+        };
+    }
+    int_vec!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    macro_rules! list {
+        ($($t:ty),+) => {
+            $(if let Some(items) = any.downcast_ref::<$t>() {
+                let index = array_index(token, items.0.len(), false).ok()?;
+                return Some(&items.0[index] as &dyn ParameterValue);
+            })+
+        };
+    }
+    list!(StrVec, FloatVec, BoolVec);
+    None
+}
+
+impl Parameters {
+    /// Resolve an RFC 6901 JSON Pointer against the nested parameter tree.
+    ///
+    /// Object tokens step into nested `Parameters` or JSON objects; numeric
+    /// tokens index into JSON arrays and the list types such as `IntVec` and `StrVec`. `~1` and `~0` unescape to
+    /// `/` and `~`, and the empty pointer refers to the whole set.
+    ///
+    /// # Arguments
+    ///
+    /// * `pointer` - A JSON Pointer such as `/db/replicas/0/host`.
+    ///
+    /// # Returns
+    ///
+    /// The value the pointer refers to, or `None` if the pointer is malformed or does not resolve.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    /// use serde_json::json;
+    ///
+    /// let params = Parameters::from_json(json!({
+    ///     "db": { "replicas": [{ "host": "db-1" }, { "host": "db-2" }] }
+    /// })).unwrap();
+    ///
+    /// let host = params.get_pointer("/db/replicas/1/host").unwrap();
+    /// assert_eq!(host.as_any().downcast_ref(), Some(&json!("db-2")));
+    /// assert!(params.get_pointer("/db/replicas/2/host").is_none());
+    /// ```
+    pub fn get_pointer(&self, pointer: &str) -> Option<&dyn ParameterValue> {
+        parse_pointer(pointer).ok()?
+            .iter()
+            .try_fold(self as &dyn ParameterValue, |node, token| child(node, token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pointer_through_typed_values() {
+        let params = Parameters::new()
+            .with("a/b", 1)
+            .with("limits", Parameters::new().with("ports", IntVec(vec![80u16, 443])));
+
+        assert_eq!(params.get_pointer("/a~1b").unwrap().as_any().downcast_ref::<i32>(), Some(&1));
+        assert_eq!(params.get_pointer("/limits/ports/1").unwrap().as_any().downcast_ref::<u16>(), Some(&443));
+        assert!(params.get_pointer("/limits/ports/01").is_none());
+        assert!(params.get_pointer("/limits/ports/-").is_none());
+        assert!(params.get_pointer("limits").is_none());
+        assert!(params.get_pointer("").unwrap().as_any().is::<Parameters>());
+    }
+}
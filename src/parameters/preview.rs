@@ -0,0 +1,164 @@
+use std::fmt;
+use crate::schema::{ParameterSchema, SchemaViolation};
+use super::{core::Parameters, diff::ParametersDiff, display::short_type_name};
+
+/// Something worth confirming before a proposed merge is applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeWarning {
+    /// The proposed value has a different stored type than the current one.
+    TypeChanged {
+        key: String,
+        from: &'static str,
+        to: &'static str,
+    },
+    /// The value of a key marked with `mark_sensitive` would change.
+    SensitiveChanged { key: String },
+}
+
+impl fmt::Display for MergeWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MergeWarning::TypeChanged { key, from, to } => {
+                write!(f, "{}: type changes from {} to {}", key, short_type_name(from), short_type_name(to))
+            }
+            MergeWarning::SensitiveChanged { key } => write!(f, "{}: sensitive value changes", key),
+        }
+    }
+}
+
+/// The consequences of merging a proposed set of overrides, computed without applying it.
+#[derive(Debug, Clone)]
+pub struct MergePreview {
+    /// The parameters as they would be after the merge.
+    pub merged: Parameters,
+    /// The keys the merge would add or change.
+    pub diff: ParametersDiff,
+    /// Schema violations the merge would introduce. Violations already present are not repeated.
+    pub violations: Vec<SchemaViolation>,
+    pub warnings: Vec<MergeWarning>,
+}
+
+impl MergePreview {
+    /// Whether the merge would be accepted by the schema.
+    pub fn is_valid(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+impl Parameters {
+    /// Preview what `merge` would do with `proposed`, without changing anything.
+    ///
+    /// Change listeners are not notified. Use `preview_merge_validated` to
+    /// also check the result against a schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `proposed` - The overrides that would be merged on top of these parameters.
+    ///
+    /// # Returns
+    ///
+    /// A `MergePreview` with the merged result, the keys that would change and any warnings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{MergeWarning, Parameters};
+    ///
+    /// let current = Parameters::new().with("workers", 4).with("mode", "fast");
+    /// let proposed = Parameters::new().with("workers", "eight").with("mode", "fast");
+    ///
+    /// let preview = current.preview_merge(&proposed);
+    /// assert_eq!(preview.diff.changed, vec!["workers"]);
+    /// assert!(matches!(&preview.warnings[0], MergeWarning::TypeChanged { key, .. } if key == "workers"));
+    /// assert_eq!(current.get::<i32>("workers"), Some(&4));
+    /// ```
+    pub fn preview_merge(&self, proposed: &Parameters) -> MergePreview {
+        let mut merged = self.clone();
+        merged.merge(proposed.clone());
+        let diff = self.diff(&merged);
+
+        let warnings = diff.changed.iter()
+            .flat_map(|key| {
+                let (old, new) = (&self.map[key], &merged.map[key]);
+                let type_changed = (old.type_name() != new.type_name()).then(|| MergeWarning::TypeChanged {
+                    key: key.clone(),
+                    from: old.type_name(),
+                    to: new.type_name(),
+                });
+                let sensitive = merged.is_sensitive(key).then(|| MergeWarning::SensitiveChanged { key: key.clone() });
+                type_changed.into_iter().chain(sensitive)
+            })
+            .collect();
+
+        MergePreview {
+            merged,
+            diff,
+            violations: Vec::new(),
+            warnings,
+        }
+    }
+
+    /// Preview a merge and report the schema violations it would introduce.
+    ///
+    /// # Arguments
+    ///
+    /// * `proposed` - The overrides that would be merged on top of these parameters.
+    /// * `schema` - The schema the merged result must satisfy.
+    ///
+    /// # Returns
+    ///
+    /// A `MergePreview` whose `violations` lists the problems not already present before the merge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{FieldSchema, ParameterKind, ParameterSchema, Parameters};
+    ///
+    /// let schema = ParameterSchema::new()
+    ///     .field("port", FieldSchema::new(ParameterKind::Integer).range(1.0, 65535.0));
+    /// let current = Parameters::new().with("port", 8080);
+    ///
+    /// let preview = current.preview_merge_validated(&Parameters::new().with("port", 70000), &schema);
+    /// assert!(!preview.is_valid());
+    /// assert_eq!(preview.violations[0].key, "port");
+    /// ```
+    pub fn preview_merge_validated(&self, proposed: &Parameters, schema: &ParameterSchema) -> MergePreview {
+        let mut preview = self.preview_merge(proposed);
+        let existing = schema.violations(self);
+        preview.violations = schema.violations(&preview.merged)
+            .into_iter()
+            .filter(|violation| !existing.contains(violation))
+            .collect();
+        preview
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{atomic::{AtomicUsize, Ordering}, Arc};
+    use crate::{FieldSchema, ParameterKind};
+    use super::*;
+
+    #[test]
+    fn test_preview_does_not_mutate_or_notify() {
+        let mut current = Parameters::new().with("token", "a".to_string()).with("name", 1);
+        current.mark_sensitive("token");
+        let notified = Arc::new(AtomicUsize::new(0));
+        let counter = notified.clone();
+        current.on_change(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let schema = ParameterSchema::new()
+            .field("name", FieldSchema::new(ParameterKind::String))
+            .field("region", FieldSchema::new(ParameterKind::String).required());
+        let proposed = Parameters::new().with("token", "b".to_string()).with("extra", true);
+        let preview = current.preview_merge_validated(&proposed, &schema);
+
+        assert_eq!(preview.diff.added, vec!["extra"]);
+        assert_eq!(preview.warnings, vec![MergeWarning::SensitiveChanged { key: "token".into() }]);
+        assert!(preview.is_valid(), "pre-existing violations are not reported: {:?}", preview.violations);
+        assert_eq!(notified.load(Ordering::SeqCst), 0);
+        assert_eq!(current.get::<String>("token"), Some(&"a".to_string()));
+    }
+}
@@ -0,0 +1,166 @@
+use std::sync::Arc;
+use crate::value::ParameterValue;
+use super::{core::Parameters, key::Key, redact::glob_match};
+
+impl Parameters {
+    /// Iterate over the keys starting with `prefix` and their values, in key order.
+    ///
+    /// With the default sorted backend only the matching range of keys is
+    /// visited, however many other keys there are; other backends scan every
+    /// key and yield matches in their own order.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The literal key prefix, such as `feature.`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the matching key-value pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("feature.beta", true).with("feature.dark_mode", false).with("port", 80);
+    ///
+    /// let flags: Vec<&str> = params.iter_prefix("feature.").map(|(key, _)| key.as_str()).collect();
+    /// assert_eq!(flags, ["feature.beta", "feature.dark_mode"]);
+    /// ```
+    pub fn iter_prefix<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = (&'a Key, &'a Arc<dyn ParameterValue>)> + 'a {
+        self.map.iter_prefix(prefix).filter(|(key, _)| !self.expiry.is_expired(key))
+    }
+
+    /// Iterate over the keys matching a glob pattern and their values, in key order.
+    ///
+    /// `*` matches any run of characters, including dots, and `?` a single
+    /// character. Only keys starting with the pattern's literal prefix are visited.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The glob, such as `feature.*.enabled`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the matching key-value pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new()
+    ///     .with("feature.beta.enabled", true)
+    ///     .with("feature.beta.rollout", 10)
+    ///     .with("feature.search.enabled", false);
+    ///
+    /// let enabled: Vec<&str> = params.iter_matching("feature.*.enabled").map(|(key, _)| key.as_str()).collect();
+    /// assert_eq!(enabled, ["feature.beta.enabled", "feature.search.enabled"]);
+    /// ```
+    pub fn iter_matching<'a>(&'a self, pattern: &'a str) -> impl Iterator<Item = (&'a Key, &'a Arc<dyn ParameterValue>)> + 'a {
+        let literal = pattern.find(['*', '?']).map_or(pattern, |at| &pattern[..at]);
+        self.iter_prefix(literal)
+            .filter(move |(key, _)| glob_match(pattern, key))
+    }
+
+    /// Iterate over the keys matching a regular expression and their values, in key order.
+    ///
+    /// # Arguments
+    ///
+    /// * `regex` - The expression; it matches anywhere in the key unless anchored.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the matching key-value pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    /// use regex::Regex;
+    ///
+    /// let params = Parameters::new().with("shard.0.host", "a").with("shard.12.host", "b").with("shard.x.host", "c");
+    ///
+    /// let numbered = Regex::new(r"^shard\.\d+\.host$").unwrap();
+    /// assert_eq!(params.iter_matching_regex(&numbered).count(), 2);
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn iter_matching_regex<'a>(&'a self, regex: &'a regex::Regex) -> impl Iterator<Item = (&'a Key, &'a Arc<dyn ParameterValue>)> + 'a {
+        self.iter().filter(move |(key, _)| regex.is_match(key))
+    }
+
+    /// Iterate over the explicit values whose concrete type is `T`, with their keys.
+    ///
+    /// Values of other types are skipped, including numbers stored as a
+    /// different numeric type; `f64` does not match a value stored as `f32`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the keys and typed values, in the order of the storage backend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("learning_rate", 0.01).with("epochs", 20).with("momentum", 0.9);
+    ///
+    /// let tunable: Vec<(&str, f64)> = params.iter_of::<f64>().map(|(key, rate)| (key.as_str(), *rate)).collect();
+    /// assert_eq!(tunable, [("learning_rate", 0.01), ("momentum", 0.9)]);
+    /// ```
+    pub fn iter_of<T: 'static>(&self) -> impl Iterator<Item = (&Key, &T)> {
+        self.iter().filter_map(|(key, value)| Some((key, value.as_any().downcast_ref::<T>()?)))
+    }
+
+    /// Iterate over the explicit values whose concrete type is `T`, with their keys.
+    ///
+    /// The same as `iter_of`, named to pair with `values_of_type`.
+    pub fn entries_of_type<T: 'static>(&self) -> impl Iterator<Item = (&Key, &T)> {
+        self.iter_of::<T>()
+    }
+
+    /// Iterate over the explicit values whose concrete type is `T`, without their keys.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the typed values, in the order of the storage backend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let weights = Parameters::new()
+    ///     .with("tenant.acme", 0.5)
+    ///     .with("tenant.globex", 0.25)
+    ///     .with("tenant.count", 2);
+    ///
+    /// let total: f64 = weights.values_of_type::<f64>().sum();
+    /// assert_eq!(total, 0.75);
+    /// ```
+    pub fn values_of_type<T: 'static>(&self) -> impl Iterator<Item = &T> {
+        self.iter_of::<T>().map(|(_, value)| value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_range_bounds() {
+        let params = Parameters::new()
+            .with("fea", 0)
+            .with("feature", 1)
+            .with("feature.a", 2)
+            .with("featurez", 3)
+            .with("g", 4);
+
+        assert_eq!(params.iter_prefix("feature").count(), 3);
+        assert_eq!(params.iter_prefix("").count(), 5);
+        assert_eq!(params.iter_matching("feature?").map(|(key, _)| key.as_str()).collect::<Vec<_>>(), ["featurez"]);
+        assert_eq!(params.iter_matching("*a*").count(), 4);
+        assert_eq!(params.iter_of::<i32>().map(|(_, value)| *value).sum::<i32>(), 10);
+        assert_eq!(params.iter_of::<i64>().count(), 0);
+    }
+}
@@ -0,0 +1,170 @@
+use std::{fmt, sync::Arc};
+use crate::{
+    error::ParameterError,
+    value::{as_number, Number, ParameterValue},
+    Result,
+};
+use super::{core::Parameters, key::Key};
+
+/// A key whose usage exceeds its limit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuotaOverage {
+    pub key: String,
+    pub limit: f64,
+    pub usage: f64,
+}
+
+impl fmt::Display for QuotaOverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: usage {} exceeds limit {}", self.key, self.usage, self.limit)
+    }
+}
+
+/// Store `remaining` using the same numeric type as `limit`, so integer limits stay integers.
+fn remaining_like(limit: &dyn ParameterValue, remaining: Number) -> Arc<dyn ParameterValue> {
+    let any = limit.as_any();
+    if let Number::Int(remaining) = remaining {
+        macro_rules! same_int {
+            ($($t:ty),+) => {
+                $(if any.is::<$t>() {
+                    if let Ok(v) = <$t>::try_from(remaining) {
+                        return Arc::new(v);
+                    }
+                })+
+            };
+        }
+        same_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, u128, usize);
+        return Arc::new(remaining);
+    }
+    if any.is::<f32>() {
+        return Arc::new(remaining.as_f64() as f32);
+    }
+    Arc::new(remaining.as_f64())
+}
+
+fn subtract(limit: Number, usage: Number) -> Number {
+    match (limit, usage) {
+        (Number::Int(l), Number::Int(u)) => l.checked_sub(u)
+            .map_or(Number::Float(l as f64 - u as f64), Number::Int),
+        (l, u) => Number::Float(l.as_f64() - u.as_f64()),
+    }
+}
+
+fn is_negative(number: Number) -> bool {
+    match number {
+        Number::Int(i) => i < 0,
+        Number::Float(f) => f < 0.0,
+    }
+}
+
+impl Parameters {
+    /// List the numeric keys whose value in `usage` exceeds the limit stored here.
+    ///
+    /// Keys that are missing from either side, or that are not numeric on both
+    /// sides, are not governed by a limit and are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `usage` - The consumed amounts, keyed like the limits.
+    ///
+    /// # Returns
+    ///
+    /// Every overage, in key order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let limits = Parameters::new().with("cpus", 8).with("memory_gb", 32.0);
+    /// let usage = Parameters::new().with("cpus", 10u8).with("memory_gb", 16.5);
+    ///
+    /// let overages = limits.overages(&usage);
+    /// assert_eq!(overages.len(), 1);
+    /// assert_eq!(overages[0].key, "cpus");
+    /// ```
+    pub fn overages(&self, usage: &Parameters) -> Vec<QuotaOverage> {
+        self.numeric_pairs(usage)
+            .filter(|(_, limit, used)| is_negative(subtract(*limit, *used)))
+            .map(|(key, limit, used)| QuotaOverage {
+                key: String::from(key),
+                limit: limit.as_f64(),
+                usage: used.as_f64(),
+            })
+            .collect()
+    }
+
+    /// Subtract `usage` from these limits, failing if any key would go negative.
+    ///
+    /// Numeric keys present on both sides are reduced by the usage, keeping the
+    /// numeric type of the limit. All other keys are copied unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `usage` - The consumed amounts, keyed like the limits.
+    ///
+    /// # Returns
+    ///
+    /// The remaining quota, or `ParameterError::QuotaExceeded` listing every key that would go negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let limits = Parameters::new().with("requests", 1000u32).with("tier", "gold");
+    /// let usage = Parameters::new().with("requests", 250);
+    ///
+    /// let remaining = limits.checked_subtract(&usage).unwrap();
+    /// assert_eq!(remaining.get::<u32>("requests"), Some(&750));
+    /// assert_eq!(remaining.get::<&str>("tier"), Some(&"gold"));
+    /// ```
+    pub fn checked_subtract(&self, usage: &Parameters) -> Result<Parameters> {
+        let overages = self.overages(usage);
+        if !overages.is_empty() {
+            return Err(ParameterError::QuotaExceeded(overages));
+        }
+
+        let mut remaining = self.clone();
+        let reductions: Vec<(Key, Arc<dyn ParameterValue>)> = self.numeric_pairs(usage)
+            .map(|(key, limit, used)| (key.clone(), remaining_like(self.map[key].as_ref(), subtract(limit, used))))
+            .collect();
+        for (key, value) in reductions {
+            remaining.insert_arc(key, value);
+        }
+        Ok(remaining)
+    }
+
+    fn numeric_pairs<'a>(&'a self, usage: &'a Parameters) -> impl Iterator<Item = (&'a Key, Number, Number)> + 'a {
+        self.iter().filter_map(move |(key, limit)| {
+            let used = usage.stored(key)?;
+            Some((key, as_number(limit.as_ref())?, as_number(used.as_ref())?))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reports_every_overage() {
+        let limits = Parameters::new().with("a", 1).with("b", 2.5).with("c", 10u64);
+        let usage = Parameters::new().with("a", 2).with("b", 3.0).with("c", 1).with("d", 100);
+
+        match limits.checked_subtract(&usage) {
+            Err(ParameterError::QuotaExceeded(overages)) => {
+                let keys: Vec<&str> = overages.iter().map(|o| o.key.as_str()).collect();
+                assert_eq!(keys, vec!["a", "b"]);
+            }
+            other => panic!("expected QuotaExceeded, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_float_remaining() {
+        let limits = Parameters::new().with("gb", 10.0f32);
+        let remaining = limits.checked_subtract(&Parameters::new().with("gb", 2)).unwrap();
+        assert_eq!(remaining.get::<f32>("gb"), Some(&8.0));
+    }
+}
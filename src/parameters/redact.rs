@@ -0,0 +1,247 @@
+use serde_json::Value;
+use crate::{value::{ParameterValue, MASK}, Result};
+use super::core::Parameters;
+
+/// The key patterns whose values are masked in rendered output.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Redaction {
+    patterns: Vec<String>,
+    /// Match keys ignoring case, for case-insensitive parameter sets.
+    pub(crate) fold_case: bool,
+}
+
+impl Redaction {
+    pub(crate) fn add(&mut self, pattern: String) {
+        if !self.patterns.contains(&pattern) {
+            self.patterns.push(pattern);
+        }
+    }
+
+    pub(crate) fn extend(&mut self, other: &Redaction) {
+        for pattern in &other.patterns {
+            self.add(pattern.clone());
+        }
+    }
+
+    /// The patterns of both policies, used when rendering nested `Parameters`.
+    pub(crate) fn joined(&self, other: &Redaction) -> Redaction {
+        let mut joined = self.clone();
+        joined.extend(other);
+        joined
+    }
+
+    /// The patterns that apply to keys under `prefix`, with the prefix removed.
+    ///
+    /// Patterns starting with a wildcard may match anywhere and are kept as they are.
+    pub(crate) fn strip_prefix(&self, prefix: &str) -> Redaction {
+        let patterns = self.patterns.iter()
+            .filter_map(|pattern| match pattern.strip_prefix(prefix) {
+                Some(rest) => Some(rest.to_string()),
+                None if pattern.starts_with(['*', '?']) => Some(pattern.clone()),
+                None => None,
+            })
+            .collect();
+        Redaction { patterns, fold_case: self.fold_case }
+    }
+
+    /// The patterns rewritten for keys moved under `prefix`.
+    pub(crate) fn with_prefix(&self, prefix: &str) -> Redaction {
+        let patterns = self.patterns.iter().map(|pattern| format!("{}{}", prefix, pattern)).collect();
+        Redaction { patterns, fold_case: self.fold_case }
+    }
+
+    pub(crate) fn is_sensitive(&self, key: &str) -> bool {
+        if self.fold_case {
+            let key = key.to_lowercase();
+            return self.patterns.iter().any(|pattern| glob_match(&pattern.to_lowercase(), &key));
+        }
+        self.patterns.iter().any(|pattern| glob_match(pattern, key))
+    }
+
+    /// The string form of `value` stored under `key`, masked if the key is sensitive.
+    ///
+    /// Nested `Parameters` are rendered with this policy joined to their own, as `Display` does.
+    pub(crate) fn render(&self, key: &str, value: &dyn ParameterValue) -> String {
+        if self.is_sensitive(key) {
+            return MASK.to_string();
+        }
+        match value.as_any().downcast_ref::<Parameters>() {
+            Some(nested) => nested.display_with(&self.joined(&nested.redaction)),
+            None => value.to_string(),
+        }
+    }
+}
+
+/// Match `text` against a glob where `*` matches any run of characters and `?` any one character.
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+impl Parameters {
+    /// Mark the keys matching a pattern as sensitive.
+    ///
+    /// Values under sensitive keys are replaced by `***` in `to_json`,
+    /// `get_string`, `Display`, `Debug` and `fmt_pretty`, at any nesting
+    /// depth. Patterns are globs where `*` matches any run of characters and
+    /// `?` a single character. The values themselves are untouched and remain
+    /// available through `get` and the lossless sinks, such as `to_json_unredacted`.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - A key name or glob such as `*_token`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new()
+    ///     .with("password", "hunter2")
+    ///     .with("github_token", "ghp_123")
+    ///     .with("user", "admin");
+    /// params.mark_sensitive("password");
+    /// params.mark_sensitive("*_token");
+    ///
+    /// assert_eq!(params.get_string("github_token"), Some("***".to_string()));
+    /// assert_eq!(params.get_string("user"), Some("admin".to_string()));
+    /// assert_eq!(params.get::<&str>("password"), Some(&"hunter2"));
+    /// ```
+    pub fn mark_sensitive(&mut self, pattern: impl Into<String>) {
+        self.redaction.add(pattern.into());
+    }
+
+    /// Check whether a key matches one of the sensitive patterns.
+    pub fn is_sensitive(&self, key: &str) -> bool {
+        self.redaction.is_sensitive(key)
+    }
+
+    /// Convert the `Parameters` to JSON without masking anything.
+    ///
+    /// This is the lossless form the data sinks use: sensitive keys keep their
+    /// values and `Secret`s are exposed, so that loading the output gives the
+    /// real values back. Only send it to trusted destinations.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the JSON object, or an error if a value cannot be converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new().with("credentials", Parameters::new());
+    /// params.mark_sensitive("credentials");
+    /// params.insert_secret("token", "t0k");
+    ///
+    /// assert_eq!(params.to_json().unwrap()["credentials"], "***");
+    /// assert!(params.to_json_unredacted().unwrap()["credentials"].is_object());
+    /// assert_eq!(params.to_json_unredacted().unwrap()["token"], "t0k");
+    /// ```
+    pub fn to_json_unredacted(&self) -> Result<Value> {
+        self.json_with(None)
+    }
+
+    /// Convert to a JSON object without failing, as `json_object` does, but with
+    /// sensitive keys and `Secret`s masked, for output sent to clients.
+    #[cfg(feature = "async-graphql")]
+    pub(crate) fn redacted_json_object(&self, policy: &Redaction) -> serde_json::Map<String, Value> {
+        self.iter()
+            .map(|(key, value)| {
+                let json = if policy.is_sensitive(key) {
+                    Value::String(MASK.to_string())
+                } else if let Some(nested) = value.as_any().downcast_ref::<Parameters>() {
+                    Value::Object(nested.redacted_json_object(&policy.joined(&nested.redaction)))
+                } else {
+                    value.to_json().unwrap_or_else(|_| Value::String(value.to_string()))
+                };
+                (String::from(key), json)
+            })
+            .collect()
+    }
+
+    /// Convert to JSON, masking what `policy` marks sensitive, or losslessly without a policy.
+    pub(crate) fn json_with(&self, policy: Option<&Redaction>) -> Result<Value> {
+        let mut map = serde_json::Map::new();
+        for (key, value) in self.iter() {
+            let value = match value.exposed() {
+                Some(inner) if policy.is_none() => inner,
+                _ => value.as_ref(),
+            };
+            let json = if policy.is_some_and(|policy| policy.is_sensitive(key)) {
+                Value::String(MASK.to_string())
+            } else if let Some(nested) = value.as_any().downcast_ref::<Parameters>() {
+                nested.json_with(policy.map(|policy| policy.joined(&nested.redaction)).as_ref())?
+            } else {
+                value.to_json().map_err(|e| e.with_key(key))?
+            };
+            map.insert(String::from(key), json);
+        }
+        Ok(Value::Object(map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*_token", "api_token"));
+        assert!(glob_match("*_token", "_token"));
+        assert!(!glob_match("*_token", "api_tokens"));
+        assert!(glob_match("pass?ord", "password"));
+        assert!(glob_match("*key*", "secret_key_id"));
+        assert!(!glob_match("key", "keys"));
+    }
+
+    #[test]
+    fn test_nested_values_are_masked() {
+        let mut params = Parameters::new()
+            .with("db", Parameters::new().with("password", "x".to_string()).with("port", 5432));
+        params.mark_sensitive("password");
+
+        let mut credentials = Parameters::new().with("db", Parameters::new().with("password", "x".to_string()));
+        credentials.mark_sensitive("password");
+        assert_eq!(credentials.to_json().unwrap(), serde_json::json!({ "db": { "password": "***" } }));
+        assert_eq!(format!("{}", params), "db = {password = ***, port = 5432} (Parameters)");
+        assert_eq!(format!("{:?}", params), r#"Parameters {"db": Parameters {"password": ***, "port": 5432}}"#);
+        assert!(params.fmt_pretty().contains("password = *** (String)"));
+    }
+
+    #[test]
+    fn test_parent_patterns_mask_nested_keys_in_strings() {
+        let mut params = Parameters::new().with_tracking();
+        params.mark_sensitive("password");
+        params.insert("db", Parameters::new().with("password", "hunter2").with("port", 5432));
+
+        let expected = "password = *** (&str)\nport = 5432 (i32)";
+        assert_eq!(params.get_string("db").as_deref(), Some(expected));
+        assert_eq!(params.to_string_map()["db"], expected);
+        assert_eq!(params.changes()[0].new.as_deref(), Some(expected));
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{error::ParameterError, Result};
+use super::{core::Parameters, key::Key};
+
+/// What `rename_key` does when the new key already has a value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RenamePolicy {
+    /// Fail with `ParameterError::DuplicateKey`, leaving both keys as they are.
+    #[default]
+    Fail,
+    /// Replace the value of the new key.
+    Overwrite,
+}
+
+impl Parameters {
+    /// Choose whether `rename_key` fails or overwrites when the new key is taken.
+    pub fn set_rename_policy(&mut self, policy: RenamePolicy) {
+        self.rename_policy = policy;
+    }
+
+    pub fn rename_policy(&self) -> RenamePolicy {
+        self.rename_policy
+    }
+
+    /// Move the value of `old` to `new`, for migrating between key names.
+    ///
+    /// The value moves as it is, without being copied or converted, and its
+    /// metadata and time to live move with it. Subscribers see `old` removed
+    /// and `new` added or updated in one batch. Renaming a key to itself does
+    /// nothing, while in a case-insensitive set renaming it to another casing
+    /// only changes how it is displayed. Aliases are followed for both keys,
+    /// and an expired value counts as missing. To keep `old` readable, follow
+    /// up with `alias(old, new)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The key that holds the value.
+    /// * `new` - The key to move it to.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` once moved, `ParameterError::KeyNotFound` if `old` has no
+    /// value, or `DuplicateKey` if `new` has one and the `RenamePolicy` is `Fail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{ParameterError, Parameters, RenamePolicy};
+    ///
+    /// let mut params = Parameters::new().with("timeout", 30).with("retries", 3);
+    /// params.rename_key("timeout", "http.timeout_s").unwrap();
+    /// assert_eq!(params.get::<i32>("http.timeout_s"), Some(&30));
+    /// assert!(!params.contains_key("timeout"));
+    ///
+    /// params.insert("max_retries", 5);
+    /// assert!(matches!(params.rename_key("retries", "max_retries"), Err(ParameterError::DuplicateKey(_))));
+    /// params.set_rename_policy(RenamePolicy::Overwrite);
+    /// params.rename_key("retries", "max_retries").unwrap();
+    /// assert_eq!(params.get::<i32>("max_retries"), Some(&3));
+    /// ```
+    pub fn rename_key<K: Into<Key>>(&mut self, old: &str, new: K) -> Result<()> {
+        let new: Key = new.into();
+        let new = self.dealias_write(&new).unwrap_or(new);
+        let target = self.dealias_write(old);
+        let old = target.as_deref().unwrap_or(old);
+        if !self.is_stored(old) {
+            return Err(self.missing_key(old));
+        }
+        if old == new.as_str() {
+            return Ok(());
+        }
+        if self.rename_policy == RenamePolicy::Fail && !self.map.same_key(old, &new) && self.is_stored(&new) {
+            return Err(ParameterError::DuplicateKey(new.into()));
+        }
+
+        let deadline = self.expiry.deadline(old);
+        let meta = self.meta.take(old);
+        self.batch(|params| {
+            if let Some(value) = params.remove(old) {
+                params.insert_arc(new.clone(), value);
+            }
+        });
+        if let Some(deadline) = deadline {
+            self.expiry.set(&new, deadline);
+        }
+        if let Some(meta) = meta {
+            self.set_meta(new, meta);
+        }
+        Ok(())
+    }
+
+    /// Exchange the values of two keys.
+    ///
+    /// Only the values move; metadata and times to live stay with their keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - One key.
+    /// * `b` - The other key.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` once swapped, or `ParameterError::KeyNotFound` for the first key that has no value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new().with("primary", "db-1").with("replica", "db-2");
+    /// params.swap("primary", "replica").unwrap();
+    /// assert_eq!(params.get_str("primary"), Some("db-2"));
+    /// assert_eq!(params.get_str("replica"), Some("db-1"));
+    /// ```
+    pub fn swap(&mut self, a: &str, b: &str) -> Result<()> {
+        let value_a = self.stored(a).cloned().ok_or_else(|| self.missing_key(a))?;
+        let value_b = self.stored(b).cloned().ok_or_else(|| self.missing_key(b))?;
+        let (deadline_a, deadline_b) = (self.expiry.deadline(a), self.expiry.deadline(b));
+        self.batch(|params| {
+            params.insert_arc(a, value_b);
+            params.insert_arc(b, value_a);
+        });
+        if let Some(deadline) = deadline_a {
+            self.expiry.set(a, deadline);
+        }
+        if let Some(deadline) = deadline_b {
+            self.expiry.set(b, deadline);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::{ChangeKind, Meta};
+    use super::*;
+
+    #[test]
+    fn test_rename_moves_meta_and_ttl_in_one_batch() {
+        let mut params = Parameters::new();
+        params.insert_with_meta("old", 1, Meta { description: Some("legacy name".to_string()), ..Meta::default() });
+        params.insert_with_ttl("token", "t", Duration::from_secs(60));
+        let changes = params.subscribe("");
+
+        params.rename_key("old", "new").unwrap();
+        params.rename_key("token", "auth.token").unwrap();
+        params.rename_key("new", "new").unwrap();
+
+        assert_eq!(params.meta("new").and_then(|meta| meta.description.as_deref()), Some("legacy name"));
+        assert!(params.meta("old").is_none());
+        assert!(params.expires_at("auth.token").is_some());
+        let kinds: Vec<(String, ChangeKind)> = changes.try_iter().map(|change| (change.key, change.kind)).collect();
+        assert_eq!(kinds[..2], [("new".to_string(), ChangeKind::Added), ("old".to_string(), ChangeKind::Removed)]);
+        assert!(matches!(params.rename_key("missing", "x"), Err(ParameterError::KeyNotFound { .. })));
+        assert!(matches!(params.swap("new", "missing"), Err(ParameterError::KeyNotFound { .. })));
+    }
+
+    #[test]
+    fn test_rename_follows_aliases_and_skips_expired_values() {
+        let mut params = Parameters::new().with("timeout_s", 30);
+        params.alias("timeout", "timeout_s");
+        params.rename_key("timeout", "http.timeout_s").unwrap();
+        assert_eq!(params.get::<i32>("http.timeout_s"), Some(&30));
+        assert!(!params.contains_key("timeout_s"));
+
+        params.insert_with_ttl("t1", "token", Duration::ZERO);
+        assert!(matches!(params.rename_key("t1", "t2"), Err(ParameterError::KeyNotFound { .. })));
+        assert!(!params.contains_key("t2"));
+    }
+}
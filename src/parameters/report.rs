@@ -0,0 +1,171 @@
+use std::{fmt::Write, sync::Arc};
+use crate::value::ParameterValue;
+use super::{core::Parameters, diff::same_value, key::Key};
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+const RESET: &str = "\x1b[0m";
+
+/// Shown in a column whose set does not define the key.
+const UNSET: &str = "(unset)";
+
+/// Options for [`Parameters::render_diff_with`].
+#[derive(Debug, Clone)]
+pub struct DiffFormat {
+    color: bool,
+    unchanged: bool,
+    labels: (String, String),
+}
+
+impl Default for DiffFormat {
+    fn default() -> Self {
+        Self {
+            color: false,
+            unchanged: false,
+            labels: ("A".to_string(), "B".to_string()),
+        }
+    }
+}
+
+impl DiffFormat {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Color added rows green, removed rows red and changed rows yellow with ANSI escapes.
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Also list the keys whose values are the same in both sets.
+    pub fn unchanged(mut self, unchanged: bool) -> Self {
+        self.unchanged = unchanged;
+        self
+    }
+
+    /// The column headings for the two sets, `A` and `B` by default.
+    pub fn labels(mut self, a: impl Into<String>, b: impl Into<String>) -> Self {
+        self.labels = (a.into(), b.into());
+        self
+    }
+}
+
+impl Parameters {
+    /// Render the differences between two sets as a table for people to read.
+    ///
+    /// Same as `render_diff_with` with the default `DiffFormat`: no colors,
+    /// only the keys that differ, and columns headed `A` and `B`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let live = Parameters::new().with("port", 8080).with("legacy", true).with("host", "db");
+    /// let next = Parameters::new().with("port", 9090).with("workers", 4).with("host", "db");
+    ///
+    /// let report = Parameters::render_diff(&live, &next);
+    /// assert_eq!(report.lines().collect::<Vec<_>>(), [
+    ///     "  key     A       B",
+    ///     "- legacy  true    (unset)",
+    ///     "~ port    8080    9090",
+    ///     "+ workers (unset) 4",
+    /// ]);
+    /// ```
+    pub fn render_diff(a: &Parameters, b: &Parameters) -> String {
+        Parameters::render_diff_with(a, b, &DiffFormat::new())
+    }
+
+    /// Render the differences between two sets as a table, one row per key.
+    ///
+    /// Nested `Parameters` are compared key by key under dotted names. Each
+    /// row starts with `+` (only in B), `-` (only in A), `~` (changed) or a
+    /// space (unchanged), and values under sensitive keys are masked.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first set, usually the one currently deployed.
+    /// * `b` - The second set, usually the one about to go out.
+    /// * `format` - Colors, headings, and whether to list unchanged keys.
+    ///
+    /// # Returns
+    ///
+    /// The report, ending with a newline; just the heading row if nothing differs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{DiffFormat, Parameters};
+    ///
+    /// let live = Parameters::new().with("port", 8080);
+    /// let next = Parameters::new().with("port", 9090);
+    ///
+    /// let report = Parameters::render_diff_with(&live, &next, &DiffFormat::new().labels("live", "next").color(true));
+    /// assert!(report.contains("\x1b[33m~ port 8080 9090\x1b[0m"));
+    /// ```
+    pub fn render_diff_with(a: &Parameters, b: &Parameters, format: &DiffFormat) -> String {
+        let (a, b) = (a.flatten(), b.flatten());
+        let mut keys: Vec<&Key> = a.keys().chain(b.keys().filter(|key| !a.is_stored(key))).collect();
+        keys.sort();
+
+        let mut rows = vec![(' ', "key".to_string(), format.labels.0.clone(), format.labels.1.clone())];
+        for key in keys {
+            let (old, new) = (a.stored(key), b.stored(key));
+            let marker = match (old, new) {
+                (Some(old), Some(new)) if same_value(old, new) => ' ',
+                (Some(_), Some(_)) => '~',
+                (Some(_), None) => '-',
+                _ => '+',
+            };
+            if marker == ' ' && !format.unchanged {
+                continue;
+            }
+            let cell = |params: &Parameters, value: Option<&Arc<dyn ParameterValue>>| {
+                value.map_or_else(|| UNSET.to_string(), |value| params.redaction.render(key, value.as_ref()))
+            };
+            rows.push((marker, String::from(key), cell(&a, old), cell(&b, new)));
+        }
+
+        let width = |column: fn(&(char, String, String, String)) -> &String| {
+            rows.iter().map(|row| column(row).chars().count()).max().unwrap_or(0)
+        };
+        let (key_width, a_width) = (width(|row| &row.1), width(|row| &row.2));
+
+        let mut out = String::new();
+        for (marker, key, old, new) in &rows {
+            let line = format!("{} {:<key_width$} {:<a_width$} {}", marker, key, old, new);
+            let line = line.trim_end();
+            let color = match marker {
+                '+' => GREEN,
+                '-' => RED,
+                '~' => YELLOW,
+                _ => "",
+            };
+            if format.color && !color.is_empty() {
+                let _ = writeln!(out, "{}{}{}", color, line, RESET);
+            } else {
+                let _ = writeln!(out, "{}", line);
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_unchanged_and_masked_rows() {
+        let mut a = Parameters::new().with("db", Parameters::new().with("host", "a").with("password", "old"));
+        a.mark_sensitive("db.password");
+        let mut b = Parameters::new().with("db", Parameters::new().with("host", "a").with("password", "new"));
+        b.mark_sensitive("db.password");
+
+        let report = Parameters::render_diff_with(&a, &b, &DiffFormat::new().unchanged(true).labels("before", "after"));
+        assert_eq!(report, "  key         before after\n  db.host     a      a\n~ db.password ***    ***\n");
+        assert_eq!(Parameters::render_diff(&a, &a), "  key A B\n");
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::BTreeSet;
+use crate::{value::ParameterValue, Result};
+use super::core::Parameters;
+
+/// Separators tried, in order, between a scope's prefix and a key.
+const SEPARATORS: [char; 2] = ['.', '_'];
+
+/// The part of `key` after `prefix` and a separator, if it has both.
+fn suffix<'k>(key: &'k str, prefix: &str) -> Option<&'k str> {
+    let rest = key.strip_prefix(prefix)?;
+    let mut chars = rest.chars();
+    match chars.next() {
+        Some(separator) if SEPARATORS.contains(&separator) && !chars.as_str().is_empty() => Some(chars.as_str()),
+        _ => None,
+    }
+}
+
+/// A read-only view of the keys of a `Parameters` that share a prefix.
+///
+/// Created by [`Parameters::scope`]. A key such as `host` is looked up as
+/// `db.host` and then as `db_host` in the parent, so a subsystem can read its
+/// own settings without knowing where they are embedded.
+#[derive(Debug, Clone, Copy)]
+pub struct ScopedParameters<'a> {
+    parent: &'a Parameters,
+    prefix: &'a str,
+}
+
+impl<'a> ScopedParameters<'a> {
+    /// The parent key a scoped key resolves to, if the parent defines it.
+    ///
+    /// Explicit values are looked up unless `defaults` is set; expired values are skipped.
+    fn resolve(&self, key: &str, defaults: bool) -> Option<(String, &'a dyn ParameterValue)> {
+        let parent = self.parent;
+        SEPARATORS.iter()
+            .map(|separator| format!("{}{}{}", self.prefix, separator, key))
+            .find_map(|full| {
+                let value = if defaults { parent.defaults.get(&full) } else { parent.stored(&full) }?.as_ref();
+                Some((full, value))
+            })
+    }
+
+    /// The prefix this view was created with.
+    pub fn prefix(&self) -> &str {
+        self.prefix
+    }
+
+    /// Get a value of type `T` stored under `prefix.key` or `prefix_key`.
+    pub fn get<T: 'static>(&self, key: &str) -> Option<&'a T> {
+        self.resolve(key, false)?.1.as_any().downcast_ref::<T>()
+    }
+
+    /// Like `get`, but falling back to a default registered under the prefixed key.
+    pub fn get_or_default<T: 'static>(&self, key: &str) -> Option<&'a T> {
+        self.resolve(key, false)
+            .or_else(|| self.resolve(key, true))?
+            .1
+            .as_any()
+            .downcast_ref::<T>()
+    }
+
+    /// Like `get`, but returning `KeyNotFound` with the dotted key when the value is missing.
+    pub fn get_required<T: 'static>(&self, key: &str) -> Result<&'a T> {
+        self.get(key).ok_or_else(|| self.parent.missing_key(&format!("{}.{}", self.prefix, key)))
+    }
+
+    /// Get the string representation of a value, masked if the parent marks its full key as sensitive.
+    pub fn get_string(&self, key: &str) -> Option<String> {
+        let (full, value) = self.resolve(key, false)?;
+        Some(self.parent.redaction.render(&full, value))
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.resolve(key, false).is_some()
+    }
+
+    /// The keys visible through this view, without the prefix, in order.
+    pub fn keys(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.parent.keys()
+            .filter_map(|key| suffix(key, self.prefix))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+    }
+
+    /// Copy the keys visible through this view into an owned `Parameters`.
+    pub fn to_parameters(&self) -> Parameters {
+        self.parent.strip_prefix(self.prefix)
+    }
+}
+
+impl Parameters {
+    /// A view of the keys under `prefix`, addressed without it.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The namespace, such as `db`; keys are resolved as `db.key`, then `db_key`.
+    ///
+    /// # Returns
+    ///
+    /// A `ScopedParameters` borrowing these parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("db.host", "localhost").with("db_port", 5432).with("cache.ttl", 60);
+    /// let db = params.scope("db");
+    ///
+    /// assert_eq!(db.get::<&str>("host"), Some(&"localhost"));
+    /// assert_eq!(db.get::<i32>("port"), Some(&5432));
+    /// assert_eq!(db.keys().collect::<Vec<_>>(), ["host", "port"]);
+    /// assert!(!db.contains_key("ttl"));
+    /// ```
+    pub fn scope<'a>(&'a self, prefix: &'a str) -> ScopedParameters<'a> {
+        ScopedParameters { parent: self, prefix }
+    }
+
+    /// An owned copy of the keys under `prefix`, with the prefix removed.
+    ///
+    /// Both `prefix.key` and `prefix_key` are included; if both spellings
+    /// exist, the dotted one wins. Defaults and sensitive-key patterns under
+    /// the prefix are carried over the same way.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The namespace to extract.
+    ///
+    /// # Returns
+    ///
+    /// A new `Parameters` holding the stripped keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("db.host", "localhost").with("dbname", "app");
+    /// let db = params.strip_prefix("db");
+    ///
+    /// assert_eq!(db.get::<&str>("host"), Some(&"localhost"));
+    /// assert_eq!(db.len(), 1);
+    /// ```
+    pub fn strip_prefix(&self, prefix: &str) -> Parameters {
+        let mut stripped = Parameters::with_backend(self.backend());
+        let view = self.scope(prefix);
+        for defaults in [false, true] {
+            let layer = if defaults { &self.defaults } else { &self.map };
+            let keys: BTreeSet<&str> = layer.keys().filter_map(|key| suffix(key, prefix)).collect();
+            for key in keys {
+                if let Some((full, _)) = view.resolve(key, defaults) {
+                    let target = if defaults { stripped.defaults_mut() } else { stripped.map_mut() };
+                    target.insert(key.to_string(), layer[&full].clone());
+                }
+            }
+        }
+        for separator in SEPARATORS {
+            let prefix = format!("{}{}", prefix, separator);
+            stripped.redaction.extend(&self.redaction.strip_prefix(&prefix));
+        }
+        stripped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dotted_spelling_wins_and_defaults_resolve() {
+        let mut params = Parameters::new()
+            .with("db.user", "dotted")
+            .with("db_user", "underscored")
+            .with("db.password", "pw")
+            .with("db.", "empty suffix");
+        params.set_default("db.pool", 4);
+        params.mark_sensitive("db.password");
+
+        let db = params.scope("db");
+        assert_eq!(db.get::<&str>("user"), Some(&"dotted"));
+        assert_eq!(db.get_or_default::<i32>("pool"), Some(&4));
+        assert_eq!(db.get_string("password"), Some("***".to_string()));
+        assert_eq!(
+            format!("{}", db.get_required::<i32>("pol").unwrap_err()),
+            "Parameter not found: db.pol (did you mean `db.pool`?)"
+        );
+
+        let owned = db.to_parameters();
+        assert_eq!(owned.keys().collect::<Vec<_>>(), ["password", "user"]);
+        assert_eq!(owned.get::<&str>("user"), Some(&"dotted"));
+        assert_eq!(owned.get_or_default::<i32>("pool"), Some(&4));
+        assert!(owned.is_sensitive("password"));
+    }
+}
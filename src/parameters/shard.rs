@@ -0,0 +1,125 @@
+use sha2::{Digest, Sha256};
+use super::core::Parameters;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64-bit FNV-1a, chosen because its output is fixed by specification rather
+/// than by the Rust release or platform.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_field(&mut self, bytes: &[u8]) {
+        self.write(&(bytes.len() as u64).to_le_bytes());
+        self.write(bytes);
+    }
+}
+
+impl Parameters {
+    /// Hash the values of the selected keys into a stable routing key.
+    ///
+    /// The result depends only on the selected key names and the string
+    /// representation of their values: the order in which keys are listed does
+    /// not matter, and the same inputs produce the same hash on every platform,
+    /// process, and release. Missing keys are hashed as absent, so a missing
+    /// value never collides with an empty string. Values are hashed unredacted,
+    /// so sensitive keys still spread across shards; a `Secret` contributes the
+    /// SHA-256 digest of its exposed value rather than the value itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys whose values identify the work item.
+    ///
+    /// # Returns
+    ///
+    /// A 64-bit hash suitable for `hash % workers` style routing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new()
+    ///     .with("tenant", "acme")
+    ///     .with("region", "eu-west-1")
+    ///     .with("request_id", 42);
+    ///
+    /// let shard = params.shard_key(["tenant", "region"]);
+    /// assert_eq!(shard, params.shard_key(["region", "tenant"]));
+    /// let worker = shard % 16;
+    /// ```
+    pub fn shard_key<I, K>(&self, keys: I) -> u64
+    where
+        I: IntoIterator<Item = K>,
+        K: AsRef<str>,
+    {
+        let mut keys: Vec<K> = keys.into_iter().collect();
+        keys.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+        keys.dedup_by(|a, b| a.as_ref() == b.as_ref());
+
+        let mut hasher = Fnv1a(FNV_OFFSET_BASIS);
+        for key in &keys {
+            let key = key.as_ref();
+            hasher.write_field(key.as_bytes());
+            match self.lookup(key) {
+                Some(value) => match value.exposed() {
+                    Some(inner) => {
+                        hasher.write(&[2]);
+                        hasher.write_field(&Sha256::digest(inner.to_string().as_bytes()));
+                    }
+                    None => {
+                        hasher.write(&[1]);
+                        hasher.write_field(value.to_string().as_bytes());
+                    }
+                },
+                None => hasher.write(&[0]),
+            }
+        }
+        hasher.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shard_key_is_stable() {
+        let params = Parameters::new().with("tenant", "acme").with("region", "eu");
+
+        // Pinned so an accidental change to the encoding is caught.
+        assert_eq!(params.shard_key(["tenant", "region"]), 0x48be_9931_78b9_2c89);
+        assert_eq!(params.shard_key(["region", "tenant", "region"]), params.shard_key(["tenant", "region"]));
+    }
+
+    #[test]
+    fn test_shard_key_distinguishes_missing_from_empty() {
+        let missing = Parameters::new().with("tenant", "acme");
+        let empty = missing.clone().with("region", "");
+        assert_ne!(missing.shard_key(["tenant", "region"]), empty.shard_key(["tenant", "region"]));
+        assert_eq!(missing.shard_key(["tenant"]), Parameters::from_iter([("tenant".to_string(), "acme".to_string())]).shard_key(["tenant"]));
+    }
+
+    #[test]
+    fn test_shard_key_hashes_sensitive_values_unredacted() {
+        let mut acme = Parameters::new().with("tenant", "acme");
+        let mut globex = Parameters::new().with("tenant", "globex");
+        acme.mark_sensitive("tenant");
+        globex.mark_sensitive("tenant");
+        assert_ne!(acme.shard_key(["tenant"]), globex.shard_key(["tenant"]));
+
+        let mut acme = Parameters::new();
+        let mut globex = Parameters::new();
+        acme.insert_secret("tenant", "acme");
+        globex.insert_secret("tenant", "globex");
+        assert_ne!(acme.shard_key(["tenant"]), globex.shard_key(["tenant"]));
+        assert_ne!(acme.shard_key(["tenant"]), Parameters::new().with("tenant", "acme").shard_key(["tenant"]));
+    }
+}
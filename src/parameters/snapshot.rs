@@ -0,0 +1,217 @@
+use std::sync::Arc;
+use crate::value::ParameterValue;
+use super::{
+    alias::Aliases, core::Parameters, diff::same_value, key::Key, meta::MetaTable, redact::Redaction, store::Store,
+    ttl::Expiry,
+};
+
+type Value = Arc<dyn ParameterValue>;
+
+/// The values and defaults of a `Parameters` set at one point in time, with
+/// the times to live, sensitive-key patterns, aliases and metadata that
+/// decide how they are read.
+///
+/// Taking a snapshot is O(1): it shares storage with the parameters until
+/// they are next modified.
+#[derive(Debug, Clone)]
+pub struct ParametersSnapshot {
+    map: Arc<Store>,
+    defaults: Arc<Store>,
+    expiry: Expiry,
+    redaction: Redaction,
+    aliases: Aliases,
+    meta: MetaTable,
+}
+
+impl ParametersSnapshot {
+    fn get(&self, key: &str) -> Option<&Value> {
+        self.map.get(key).filter(|_| !self.expiry.is_expired(key))
+    }
+
+    /// The number of explicitly set keys in the snapshot that had not expired.
+    pub fn len(&self) -> usize {
+        self.map.iter().filter(|(key, _)| !self.expiry.is_expired(key)).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Parameters {
+    /// Capture the current values, defaults and the settings that decide how
+    /// they are read, to return to later with `restore`.
+    ///
+    /// # Returns
+    ///
+    /// A `ParametersSnapshot` sharing storage with these parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new().with("mode", "safe");
+    /// let before = params.snapshot();
+    ///
+    /// params.insert("mode", "speculative");
+    /// params.insert("extra", 1);
+    /// params.restore(before);
+    ///
+    /// assert_eq!(params.get::<&str>("mode"), Some(&"safe"));
+    /// assert!(!params.contains_key("extra"));
+    /// ```
+    pub fn snapshot(&self) -> ParametersSnapshot {
+        ParametersSnapshot {
+            map: self.map.clone(),
+            defaults: self.defaults.clone(),
+            expiry: self.expiry.clone(),
+            redaction: self.redaction.clone(),
+            aliases: self.aliases.clone(),
+            meta: self.meta.clone(),
+        }
+    }
+
+    /// Return the values and defaults to those captured by `snapshot`.
+    ///
+    /// Times to live, sensitive-key patterns, aliases and metadata return to
+    /// the snapshot's too. Subscribers are notified once, of the keys whose
+    /// values differ from the snapshot. Listeners, limits and other settings
+    /// are left as they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot` - A snapshot taken earlier, from these or any other parameters.
+    pub fn restore(&mut self, snapshot: ParametersSnapshot) {
+        let mut changes: Vec<(Key, Option<Value>, Option<Value>)> = Vec::new();
+        for (key, old) in self.iter() {
+            match snapshot.get(key) {
+                Some(new) if same_value(old, new) => {}
+                new => changes.push((key.clone(), Some(old.clone()), new.cloned())),
+            }
+        }
+        for (key, new) in snapshot.map.iter() {
+            if !self.is_stored(key) && snapshot.get(key).is_some() {
+                changes.push((key.clone(), None, Some(new.clone())));
+            }
+        }
+
+        self.reset_to(snapshot);
+        self.notifier.begin();
+        for (key, old, new) in changes {
+            self.track(&key, old.as_ref(), new.as_ref());
+            self.notifier.record(&key, old, new);
+        }
+        self.notifier.end();
+    }
+
+    /// Apply several mutations atomically: kept if `f` succeeds, undone if it fails.
+    ///
+    /// Subscribers are notified once, after a successful transaction, and never see
+    /// changes from a failed one; neither does change tracking.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A closure performing the mutations, returning `Err` to roll them back.
+    ///
+    /// # Returns
+    ///
+    /// The closure's result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{ParameterError, Parameters};
+    ///
+    /// let mut params = Parameters::new().with("replicas", 3);
+    /// let outcome: Result<(), ParameterError> = params.transaction(|tx| {
+    ///     tx.insert("replicas", 10);
+    ///     tx.require(["canary_percent"])
+    /// });
+    ///
+    /// assert!(outcome.is_err());
+    /// assert_eq!(params.get::<i32>("replicas"), Some(&3));
+    /// ```
+    pub fn transaction<R, E>(&mut self, f: impl FnOnce(&mut Parameters) -> Result<R, E>) -> Result<R, E> {
+        self.notifier.begin();
+        let mark = self.notifier.mark();
+        let tracked = self.journal.changes.len();
+        let before = self.snapshot();
+        let result = f(self);
+        if result.is_err() {
+            self.reset_to(before);
+            self.notifier.discard_since(mark);
+            self.journal.changes.truncate(tracked);
+        }
+        self.notifier.end();
+        result
+    }
+
+    fn reset_to(&mut self, snapshot: ParametersSnapshot) {
+        self.map = snapshot.map;
+        self.defaults = snapshot.defaults;
+        self.expiry = snapshot.expiry;
+        self.redaction = snapshot.redaction;
+        self.aliases = snapshot.aliases;
+        self.meta = snapshot.meta;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use crate::ChangeKind;
+    use super::*;
+
+    #[test]
+    fn test_failed_transaction_is_silent_and_restore_notifies() {
+        let mut params = Parameters::new().with("a", 1).with("b", 2);
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        params.on_change(move |batch| {
+            sink.lock().unwrap().extend(batch.changes().iter().map(|change| (change.key.clone(), change.kind)));
+        });
+
+        let failed: Result<(), &str> = params.transaction(|tx| {
+            tx.insert("a", 5);
+            tx.remove("b");
+            Err("stage failed")
+        });
+        assert!(failed.is_err());
+        assert_eq!(params.get::<i32>("a"), Some(&1));
+        assert!(seen.lock().unwrap().is_empty());
+
+        let before = params.snapshot();
+        params.transaction(|tx| {
+            tx.insert("a", 5);
+            tx.insert("c", 3);
+            Ok::<_, ()>(())
+        }).unwrap();
+        params.restore(before);
+        assert_eq!(*seen.lock().unwrap(), [
+            ("a".to_string(), ChangeKind::Updated),
+            ("c".to_string(), ChangeKind::Added),
+            ("a".to_string(), ChangeKind::Updated),
+            ("c".to_string(), ChangeKind::Removed),
+        ]);
+    }
+
+    #[test]
+    fn test_rollback_restores_ttl_redaction_and_aliases() {
+        let mut params = Parameters::new().with("host", "a");
+        params.insert_with_ttl("token", "t", std::time::Duration::from_secs(60));
+        let deadline = params.expires_at("token");
+
+        let failed: Result<(), &str> = params.transaction(|tx| {
+            tx.insert("token", "u");
+            tx.mark_sensitive("host");
+            tx.alias("server", "host");
+            Err("stage failed")
+        });
+        assert!(failed.is_err());
+        assert_eq!(params.get::<&str>("token"), Some(&"t"));
+        assert_eq!(params.expires_at("token"), deadline);
+        assert_eq!(params.get_string("host").as_deref(), Some("a"));
+        assert!(!params.contains_key("server"));
+    }
+}
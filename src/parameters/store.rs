@@ -0,0 +1,387 @@
+use std::{
+    borrow::{Borrow, Cow},
+    collections::{btree_map, hash_map, BTreeMap, HashMap},
+    ops::{Bound, Index},
+    sync::Arc,
+};
+use crate::value::ParameterValue;
+use super::{key::Key, ttl::Expiry};
+
+type Value = Arc<dyn ParameterValue>;
+
+/// The map implementation behind a `Parameters`.
+///
+/// The public API is the same whichever backend is used; only lookup cost
+/// and iteration order differ.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StorageBackend {
+    /// A `BTreeMap`: iteration in key order and cheap prefix queries. The default.
+    #[default]
+    Sorted,
+    /// A `HashMap`: faster lookups, iteration in no particular order.
+    Hash,
+    /// An `IndexMap`: hashed lookups, iteration in insertion order.
+    #[cfg(feature = "indexmap")]
+    Insertion,
+    /// A `BTreeMap` keyed by lowercased keys: lookups ignore case, and iteration
+    /// is in case-insensitive key order. Keys keep the casing they were first
+    /// inserted with.
+    CaseInsensitive,
+}
+
+/// Key-value storage dispatching to the selected backend.
+#[derive(Debug, Clone)]
+pub(crate) enum Store {
+    Sorted(BTreeMap<Key, Value>),
+    Hash(HashMap<Key, Value>),
+    #[cfg(feature = "indexmap")]
+    Insertion(indexmap::IndexMap<Key, Value>),
+    /// Entries under their lowercased key, each with its key as inserted.
+    Folded(BTreeMap<Key, (Key, Value)>),
+}
+
+/// The lowercase form of `key`, borrowed when it is already lowercase.
+fn fold(key: &str) -> Cow<'_, str> {
+    if key.chars().any(char::is_uppercase) {
+        Cow::Owned(key.to_lowercase())
+    } else {
+        Cow::Borrowed(key)
+    }
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        Store::Sorted(BTreeMap::new())
+    }
+}
+
+macro_rules! dispatch {
+    ($store:expr, $map:ident => $body:expr) => {
+        match $store {
+            Store::Sorted($map) => $body,
+            Store::Hash($map) => $body,
+            #[cfg(feature = "indexmap")]
+            Store::Insertion($map) => $body,
+            Store::Folded(_) => unreachable!("folded stores are handled separately"),
+        }
+    };
+}
+
+impl Store {
+    pub(crate) fn new(backend: StorageBackend) -> Self {
+        match backend {
+            StorageBackend::Sorted => Store::Sorted(BTreeMap::new()),
+            StorageBackend::Hash => Store::Hash(HashMap::new()),
+            #[cfg(feature = "indexmap")]
+            StorageBackend::Insertion => Store::Insertion(indexmap::IndexMap::new()),
+            StorageBackend::CaseInsensitive => Store::Folded(BTreeMap::new()),
+        }
+    }
+
+    pub(crate) fn backend(&self) -> StorageBackend {
+        match self {
+            Store::Sorted(_) => StorageBackend::Sorted,
+            Store::Hash(_) => StorageBackend::Hash,
+            #[cfg(feature = "indexmap")]
+            Store::Insertion(_) => StorageBackend::Insertion,
+            Store::Folded(_) => StorageBackend::CaseInsensitive,
+        }
+    }
+
+    /// Whether `a` and `b` name the same entry, which for folded stores ignores case.
+    pub(crate) fn same_key(&self, a: &str, b: &str) -> bool {
+        match self {
+            Store::Folded(_) => fold(a) == fold(b),
+            _ => a == b,
+        }
+    }
+
+    /// An empty store with the same backend.
+    pub(crate) fn new_like(&self) -> Self {
+        Store::new(self.backend())
+    }
+
+    pub(crate) fn get<K: Borrow<str> + ?Sized>(&self, key: &K) -> Option<&Value> {
+        match self {
+            Store::Folded(map) => map.get(fold(key.borrow()).as_ref()).map(|(_, value)| value),
+            _ => dispatch!(self, map => map.get(key.borrow())),
+        }
+    }
+
+    /// The entry under `key` with its key as stored, which for folded stores may differ in case.
+    pub(crate) fn get_key_value<K: Borrow<str> + ?Sized>(&self, key: &K) -> Option<(&Key, &Value)> {
+        match self {
+            Store::Folded(map) => map.get(fold(key.borrow()).as_ref()).map(|(key, value)| (key, value)),
+            _ => dispatch!(self, map => map.get_key_value(key.borrow())),
+        }
+    }
+
+    pub(crate) fn get_mut<K: Borrow<str> + ?Sized>(&mut self, key: &K) -> Option<&mut Value> {
+        match self {
+            Store::Folded(map) => map.get_mut(fold(key.borrow()).as_ref()).map(|(_, value)| value),
+            _ => dispatch!(self, map => map.get_mut(key.borrow())),
+        }
+    }
+
+    pub(crate) fn contains_key<K: Borrow<str> + ?Sized>(&self, key: &K) -> bool {
+        match self {
+            Store::Folded(map) => map.contains_key(fold(key.borrow()).as_ref()),
+            _ => dispatch!(self, map => map.contains_key(key.borrow())),
+        }
+    }
+
+    /// Insert a value; a case-insensitive store keeps the casing of an existing key.
+    pub(crate) fn insert(&mut self, key: impl Into<Key>, value: Value) -> Option<Value> {
+        let key = key.into();
+        match self {
+            Store::Folded(map) => {
+                let folded = fold(&key);
+                if let Some((_, existing)) = map.get_mut(folded.as_ref()) {
+                    return Some(std::mem::replace(existing, value));
+                }
+                let folded = Key::from(folded.into_owned());
+                map.insert(folded, (key, value));
+                None
+            }
+            _ => dispatch!(self, map => map.insert(key, value)),
+        }
+    }
+
+    pub(crate) fn remove<K: Borrow<str> + ?Sized>(&mut self, key: &K) -> Option<Value> {
+        let key = key.borrow();
+        match self {
+            Store::Sorted(map) => map.remove(key),
+            Store::Hash(map) => map.remove(key),
+            #[cfg(feature = "indexmap")]
+            Store::Insertion(map) => map.shift_remove(key),
+            Store::Folded(map) => map.remove(fold(key).as_ref()).map(|(_, value)| value),
+        }
+    }
+
+    /// Insert `value` unless the key is already present.
+    pub(crate) fn insert_if_absent(&mut self, key: Key, value: Value) {
+        if !self.contains_key(&key) {
+            self.insert(key, value);
+        }
+    }
+
+    pub(crate) fn retain(&mut self, mut keep: impl FnMut(&Key, &mut Value) -> bool) {
+        match self {
+            Store::Folded(map) => map.retain(|_, (key, value)| keep(key, value)),
+            _ => dispatch!(self, map => map.retain(|key, value| keep(key, value))),
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Store::Folded(map) => map.len(),
+            _ => dispatch!(self, map => map.len()),
+        }
+    }
+
+    pub(crate) fn iter(&self) -> Iter<'_> {
+        let inner = match self {
+            Store::Sorted(map) => IterInner::Sorted(map.iter()),
+            Store::Hash(map) => IterInner::Hash(map.iter()),
+            #[cfg(feature = "indexmap")]
+            Store::Insertion(map) => IterInner::Insertion(map.iter()),
+            Store::Folded(map) => IterInner::Folded(map.values()),
+        };
+        Iter { inner, expiry: None }
+    }
+
+    pub(crate) fn keys(&self) -> impl Iterator<Item = &Key> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// The entries whose keys start with `prefix`, using a range scan when the backend is sorted.
+    ///
+    /// A case-insensitive store matches the prefix ignoring case.
+    pub(crate) fn iter_prefix<'a>(&'a self, prefix: &'a str) -> Box<dyn Iterator<Item = (&'a Key, &'a Value)> + 'a> {
+        match self {
+            Store::Sorted(map) => Box::new(map.range::<str, _>((Bound::Included(prefix), Bound::Unbounded))
+                .take_while(move |(key, _)| key.starts_with(prefix))),
+            Store::Folded(map) => {
+                let prefix = fold(prefix).into_owned();
+                Box::new(map.range::<str, _>((Bound::Included(prefix.as_str()), Bound::Unbounded))
+                    .take_while(move |(folded, _)| folded.starts_with(prefix.as_str()))
+                    .map(|(_, (key, value))| (key, value)))
+            }
+            _ => Box::new(self.iter().filter(move |(key, _)| key.starts_with(prefix))),
+        }
+    }
+}
+
+impl<K: Borrow<str> + ?Sized> Index<&K> for Store {
+    type Output = Value;
+
+    fn index(&self, key: &K) -> &Value {
+        self.get(key.borrow()).expect("key is present in the store")
+    }
+}
+
+impl Extend<(Key, Value)> for Store {
+    fn extend<I: IntoIterator<Item = (Key, Value)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+/// Collecting builds a sorted store; use `new_like` and `extend` to keep another backend.
+impl FromIterator<(Key, Value)> for Store {
+    fn from_iter<I: IntoIterator<Item = (Key, Value)>>(iter: I) -> Self {
+        Store::Sorted(iter.into_iter().collect())
+    }
+}
+
+enum IterInner<'a> {
+    Sorted(btree_map::Iter<'a, Key, Value>),
+    Hash(hash_map::Iter<'a, Key, Value>),
+    #[cfg(feature = "indexmap")]
+    Insertion(indexmap::map::Iter<'a, Key, Value>),
+    Folded(btree_map::Values<'a, Key, (Key, Value)>),
+}
+
+/// An iterator over the key-value pairs of a `Parameters`, in the order of its storage backend.
+pub struct Iter<'a> {
+    inner: IterInner<'a>,
+    /// The deadlines to check, so that expired entries are skipped.
+    expiry: Option<&'a Expiry>,
+}
+
+impl<'a> Iter<'a> {
+    /// Skip the entries whose time to live in `expiry` has run out.
+    pub(crate) fn skip_expired(mut self, expiry: &'a Expiry) -> Self {
+        self.expiry = Some(expiry).filter(|expiry| !expiry.is_empty());
+        self
+    }
+
+    fn next_stored(&mut self) -> Option<(&'a Key, &'a Value)> {
+        match &mut self.inner {
+            IterInner::Sorted(iter) => iter.next(),
+            IterInner::Hash(iter) => iter.next(),
+            #[cfg(feature = "indexmap")]
+            IterInner::Insertion(iter) => iter.next(),
+            IterInner::Folded(iter) => iter.next().map(|(key, value)| (key, value)),
+        }
+    }
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (&'a Key, &'a Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (key, value) = self.next_stored()?;
+            if !self.expiry.is_some_and(|expiry| expiry.is_expired(key)) {
+                return Some((key, value));
+            }
+        }
+    }
+}
+
+enum IntoIterInner {
+    Sorted(btree_map::IntoIter<Key, Value>),
+    Hash(hash_map::IntoIter<Key, Value>),
+    #[cfg(feature = "indexmap")]
+    Insertion(indexmap::map::IntoIter<Key, Value>),
+    Folded(btree_map::IntoValues<Key, (Key, Value)>),
+}
+
+/// An owning iterator over the key-value pairs of a `Parameters`, in the order of its storage backend.
+pub struct IntoIter(IntoIterInner);
+
+impl Iterator for IntoIter {
+    type Item = (Key, Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            IntoIterInner::Sorted(iter) => iter.next(),
+            IntoIterInner::Hash(iter) => iter.next(),
+            #[cfg(feature = "indexmap")]
+            IntoIterInner::Insertion(iter) => iter.next(),
+            IntoIterInner::Folded(iter) => iter.next(),
+        }
+    }
+}
+
+impl IntoIterator for Store {
+    type Item = (Key, Value);
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> IntoIter {
+        IntoIter(match self {
+            Store::Sorted(map) => IntoIterInner::Sorted(map.into_iter()),
+            Store::Hash(map) => IntoIterInner::Hash(map.into_iter()),
+            #[cfg(feature = "indexmap")]
+            Store::Insertion(map) => IntoIterInner::Insertion(map.into_iter()),
+            Store::Folded(map) => IntoIterInner::Folded(map.into_values()),
+        })
+    }
+}
+
+impl<'a> IntoIterator for &'a Store {
+    type Item = (&'a Key, &'a Value);
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Parameters;
+    use super::*;
+
+    #[test]
+    fn test_hash_backend_matches_sorted() {
+        let build = |mut params: Parameters| {
+            for i in 0..50 {
+                params.insert(format!("key{i}"), i);
+            }
+            params.remove("key7");
+            params.set_default("fallback", true);
+            params
+        };
+        let sorted = build(Parameters::new());
+        let hashed = build(Parameters::with_hash_backend());
+
+        assert_eq!(hashed, sorted);
+        assert_eq!(hashed.get::<i32>("key8"), Some(&8));
+        assert_eq!(hashed.iter_prefix("key4").count(), 11);
+        let mut compacted = hashed.map_values(|_, value| value).flatten();
+        compacted.compact();
+        assert_eq!(compacted.backend(), StorageBackend::Hash);
+        assert_eq!(compacted.len(), 49);
+    }
+
+    #[test]
+    fn test_case_insensitive_backend() {
+        let mut params = Parameters::new_case_insensitive()
+            .with("X-Api-Key", "abc")
+            .with("X-Request-Id", "r1")
+            .with("Accept", "*/*");
+        params.mark_sensitive("x-api-*");
+
+        assert!(params.contains_key("x-api-key"));
+        assert_eq!(params.get_string("X-API-KEY").as_deref(), Some("***"));
+        assert_eq!(params.iter_prefix("x-").map(|(key, _)| key.as_str()).collect::<Vec<_>>(), ["X-Api-Key", "X-Request-Id"]);
+
+        assert!(params.remove("accept").is_some());
+        let copy = params.map_values(|_, value| value);
+        assert_eq!(copy.backend(), StorageBackend::CaseInsensitive);
+        assert_eq!(copy.get_str("x-request-id"), Some("r1"));
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn test_indexmap_backend_keeps_insertion_order() {
+        let mut params = Parameters::with_indexmap_backend().with("zeta", 1).with("alpha", 2).with("mid", 3);
+        params.remove("zeta");
+        params.insert("zeta", 4);
+        assert_eq!(params.keys().collect::<Vec<_>>(), ["alpha", "mid", "zeta"]);
+        assert_eq!(params, Parameters::new().with("mid", 3).with("zeta", 4).with("alpha", 2));
+    }
+}
@@ -0,0 +1,65 @@
+use crate::error::ParameterError;
+use super::{core::Parameters, key::Key};
+
+/// Edit distance between two strings, counted in characters, where an
+/// insertion, deletion, substitution or swap of two adjacent characters
+/// each cost one.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    d[0] = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1).min(d[i][j - 1] + 1).min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+/// The candidate closest to `key`, if it is within a third of the key's length.
+pub(crate) fn closest<'a, I>(key: &str, candidates: I) -> Option<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let limit = (key.chars().count() / 3).max(1);
+    candidates.into_iter()
+        .map(|candidate| (edit_distance(key, candidate), candidate))
+        .filter(|(distance, _)| *distance <= limit)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+impl Parameters {
+    /// A `KeyNotFound` error for `key`, suggesting the closest existing key or default.
+    pub(crate) fn missing_key(&self, key: &str) -> ParameterError {
+        let candidates = self.keys().chain(self.defaults.keys()).map(Key::as_str);
+        ParameterError::KeyNotFound {
+            key: key.to_string(),
+            suggestion: closest(key, candidates).map(str::to_string),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggestions() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(closest("hots", ["host", "port"]), Some("host"));
+        assert_eq!(closest("timeout", ["retries", "port"]), None);
+
+        let params = Parameters::new().with("database_url", "x");
+        let err = params.get_required::<&str>("databse_url").unwrap_err();
+        assert_eq!(err.to_string(), "Parameter not found: databse_url (did you mean `database_url`?)");
+    }
+}
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+use crate::value::ParameterValue;
+use super::core::Parameters;
+
+impl Parameters {
+    /// Keep only the keys for which `keep` returns `true`, removing the rest in place.
+    ///
+    /// The removals are delivered to change listeners as a single batch.
+    /// Registered defaults are not affected.
+    ///
+    /// # Arguments
+    ///
+    /// * `keep` - A predicate receiving each key and its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new().with("host", "db").with("_internal", true);
+    /// params.retain(|key, _| !key.starts_with('_'));
+    ///
+    /// assert!(params.contains_key("host"));
+    /// assert!(!params.contains_key("_internal"));
+    /// ```
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&str, &dyn ParameterValue) -> bool,
+    {
+        let dropped: Vec<String> = self.iter()
+            .filter(|(key, value)| !keep(key, value.as_ref()))
+            .map(|(key, _)| String::from(key))
+            .collect();
+        self.batch(|params| {
+            for key in &dropped {
+                params.remove(key);
+            }
+        });
+    }
+
+    /// Consume these parameters and return the ones for which `keep` returns `true`.
+    ///
+    /// Defaults and sensitive-key patterns are carried over. Unlike `retain`,
+    /// no change notifications are sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `keep` - A predicate receiving each key and its value.
+    ///
+    /// # Returns
+    ///
+    /// The filtered `Parameters`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::Parameters;
+    ///
+    /// let params = Parameters::new().with("port", 80).with("name", "api")
+    ///     .filter(|_, value| value.as_any().is::<i32>());
+    /// assert_eq!(params.keys().collect::<Vec<_>>(), ["port"]);
+    /// ```
+    pub fn filter<F>(mut self, mut keep: F) -> Parameters
+    where
+        F: FnMut(&str, &dyn ParameterValue) -> bool,
+    {
+        self.map_mut().retain(|key, value| keep(key, value.as_ref()));
+        self
+    }
+
+    /// Consume these parameters and replace every explicit value with the result of `f`.
+    ///
+    /// Defaults and sensitive-key patterns are carried over unchanged, and no
+    /// change notifications are sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A function receiving each key and its value, returning the value to store.
+    ///
+    /// # Returns
+    ///
+    /// The transformed `Parameters`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use parameterx::{ParameterValue, Parameters};
+    ///
+    /// let params = Parameters::new().with("env", " Prod ".to_string()).with("port", 80)
+    ///     .map_values(|_, value| match value.as_any().downcast_ref::<String>() {
+    ///         Some(s) => Arc::new(s.trim().to_lowercase()) as Arc<dyn ParameterValue>,
+    ///         None => value,
+    ///     });
+    ///
+    /// assert_eq!(params.get::<String>("env").map(String::as_str), Some("prod"));
+    /// assert_eq!(params.get::<i32>("port"), Some(&80));
+    /// ```
+    pub fn map_values<F>(mut self, mut f: F) -> Parameters
+    where
+        F: FnMut(&str, Arc<dyn ParameterValue>) -> Arc<dyn ParameterValue>,
+    {
+        self.drop_expired();
+        let mut mapped = self.map.new_like();
+        mapped.extend(Arc::unwrap_or_clone(std::mem::take(&mut self.map))
+            .into_iter()
+            .map(|(key, value)| {
+                let value = f(&key, value);
+                (key, value)
+            }));
+        self.map = Arc::new(mapped);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use super::*;
+
+    #[test]
+    fn test_retain_notifies_once_and_keeps_defaults() {
+        let mut params = Parameters::new().with("a", 1).with("b", 2).with("c", 3);
+        params.set_default("b", 0);
+        let batches = Arc::new(Mutex::new(Vec::new()));
+        let sink = batches.clone();
+        params.on_change(move |batch| sink.lock().unwrap().push(batch.len()));
+
+        params.retain(|key, _| key == "a");
+
+        assert_eq!(params.keys().collect::<Vec<_>>(), ["a"]);
+        assert_eq!(params.get_or_default::<i32>("b"), Some(&0));
+        assert_eq!(*batches.lock().unwrap(), [2]);
+    }
+}
@@ -0,0 +1,252 @@
+use std::{borrow::Cow, collections::BTreeMap, sync::Arc, time::{Duration, Instant}};
+use crate::value::{share, ParameterValue};
+use super::{core::Parameters, key::Key};
+
+/// The deadlines of keys inserted with a time to live, shared between clones.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Expiry {
+    deadlines: Arc<BTreeMap<Key, Instant>>,
+    /// Match keys ignoring case, for case-insensitive parameter sets.
+    pub(crate) fold_case: bool,
+}
+
+impl Expiry {
+    fn key<'a>(&self, key: &'a str) -> Cow<'a, str> {
+        if self.fold_case {
+            Cow::Owned(key.to_lowercase())
+        } else {
+            Cow::Borrowed(key)
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.deadlines.is_empty()
+    }
+
+    /// An `Expiry` with no deadlines that matches keys the same way.
+    pub(crate) fn cleared(&self) -> Self {
+        Self { deadlines: Arc::default(), fold_case: self.fold_case }
+    }
+
+    pub(crate) fn deadline(&self, key: &str) -> Option<Instant> {
+        if self.deadlines.is_empty() {
+            return None;
+        }
+        self.deadlines.get(self.key(key).as_ref()).copied()
+    }
+
+    pub(crate) fn is_expired(&self, key: &str) -> bool {
+        self.deadline(key).is_some_and(|deadline| deadline <= Instant::now())
+    }
+
+    pub(crate) fn set(&mut self, key: &str, deadline: Instant) {
+        let key = Key::from(self.key(key).into_owned());
+        Arc::make_mut(&mut self.deadlines).insert(key, deadline);
+    }
+
+    /// Forget the deadline of `key`, which is being replaced or removed.
+    pub(crate) fn clear(&mut self, key: &str) {
+        if self.deadline(key).is_some() {
+            let key = self.key(key).into_owned();
+            Arc::make_mut(&mut self.deadlines).remove(key.as_str());
+        }
+    }
+}
+
+impl Parameters {
+    /// The explicit value stored under exactly `key`, unless it has expired.
+    ///
+    /// Internal reads of the explicit values go through this, `is_stored` or
+    /// `iter`, so an expired key is absent everywhere until it is purged.
+    pub(crate) fn stored(&self, key: &str) -> Option<&Arc<dyn ParameterValue>> {
+        self.map.get(key).filter(|_| !self.expiry.is_expired(key))
+    }
+
+    pub(crate) fn is_stored(&self, key: &str) -> bool {
+        self.stored(key).is_some()
+    }
+
+    /// Drop expired values without notifying subscribers, before the storage is handed out whole.
+    pub(crate) fn drop_expired(&mut self) {
+        if self.expiry.is_empty() {
+            return;
+        }
+        let expiry = self.expiry.clone();
+        self.map_mut().retain(|key, _| !expiry.is_expired(key));
+    }
+
+    /// Insert a value that expires after `ttl`, such as a short-lived token.
+    ///
+    /// Once expired, the key is treated as absent everywhere: lookups fall
+    /// back to its default if one is set, and `len`, iteration, serialization
+    /// and `entry` skip it. It only stays in storage until `purge_expired`
+    /// removes it. Inserting the key again with `insert` clears its time to live.
+    /// An alias sets the time to live of its target, and a value rejected by
+    /// the capacity limits leaves the stored value and its time to live as they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A key that can be converted into a `Key`, such as a `&str` or `String`.
+    /// * `value` - A value that implements the `ParameterValue` trait.
+    /// * `ttl` - How long the value stays valid.
+    ///
+    /// # Returns
+    ///
+    /// The value previously stored under the key, or `None` if the key was not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new();
+    /// params.insert_with_ttl("session", "a1b2", Duration::from_secs(3600));
+    /// params.insert_with_ttl("nonce", "x9", Duration::ZERO);
+    ///
+    /// assert_eq!(params.get_str("session"), Some("a1b2"));
+    /// assert!(params.expires_at("session").is_some());
+    /// assert!(!params.contains_key("nonce"));
+    /// assert_eq!(params.purge_expired(), ["nonce"]);
+    /// ```
+    pub fn insert_with_ttl<K, V>(&mut self, key: K, value: V, ttl: Duration) -> Option<Arc<dyn ParameterValue>>
+    where
+        K: Into<Key>,
+        V: ParameterValue + 'static,
+    {
+        let key: Key = key.into();
+        let key = self.dealias_write(&key).unwrap_or(key);
+        match self.try_insert_arc(key.clone(), share(value)) {
+            Ok(old) => {
+                if let Some(deadline) = Instant::now().checked_add(ttl) {
+                    self.expiry.set(&key, deadline);
+                }
+                old
+            }
+            Err(err) => {
+                self.warnings.emit(&key, &format!("not stored; {}", err));
+                None
+            }
+        }
+    }
+
+    /// When the value of `key` expires, or `None` if it has no time to live.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string slice that holds the key.
+    pub fn expires_at(&self, key: &str) -> Option<Instant> {
+        let key = self.canonical_key(key);
+        self.expiry.deadline(key).filter(|_| self.map.contains_key(key))
+    }
+
+    /// Remove every expired value, notifying subscribers once with a removal for each.
+    ///
+    /// # Returns
+    ///
+    /// The removed keys, in key order.
+    pub fn purge_expired(&mut self) -> Vec<String> {
+        if self.expiry.deadlines.is_empty() {
+            return Vec::new();
+        }
+        let expired: Vec<Key> = self.map.keys()
+            .filter(|key| self.expiry.is_expired(key))
+            .cloned()
+            .collect();
+        self.batch(|params| {
+            for key in &expired {
+                if let Some(old) = params.map_mut().remove(key) {
+                    params.track(key, Some(&old), None);
+                    params.notifier.record(key, Some(old), None);
+                    params.record_mutation();
+                }
+            }
+        });
+        // Deadlines of keys removed by other means, such as `retain`, are dropped here too.
+        let map = self.map.clone();
+        Arc::make_mut(&mut self.expiry.deadlines).retain(|key, _| map.contains_key(key));
+        expired.into_iter().map(String::from).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expired_values_fall_back_and_reinsert_clears_ttl() {
+        let mut params = Parameters::new();
+        params.set_default("token", "anonymous");
+        params.insert_with_ttl("token", "t1", Duration::ZERO);
+        assert_eq!(params.get_or_default::<&str>("token"), Some(&"anonymous"));
+        assert!(params.is_empty());
+
+        params.insert("token", "t2");
+        assert_eq!(params.expires_at("token"), None);
+        assert!(params.purge_expired().is_empty());
+        assert_eq!(params.get_str("token"), Some("t2"));
+
+        let mut headers = Parameters::new_case_insensitive();
+        headers.insert_with_ttl("X-Token", "t3", Duration::ZERO);
+        assert!(headers.get_str("x-token").is_none());
+        assert_eq!(headers.purge_expired(), ["X-Token"]);
+        assert!(headers.is_empty());
+    }
+
+    #[test]
+    fn test_expired_values_are_absent_from_every_view() {
+        let mut params = Parameters::new().with("db.host", "h").with("port", 1);
+        params.insert_with_ttl("db.token", "t", Duration::ZERO);
+        params.insert_with_ttl("nonce", "n", Duration::ZERO);
+
+        assert_eq!(params.len(), 2);
+        assert_eq!(params.keys().map(Key::as_str).collect::<Vec<_>>(), ["db.host", "port"]);
+        assert!(params.scope("db").get::<&str>("token").is_none());
+        assert!(params.get_pointer("/nonce").is_none());
+        assert!(params.iter_of::<&str>().all(|(key, _)| key == "db.host"));
+        assert_eq!(params.to_json().unwrap(), serde_json::json!({"db.host": "h", "port": 1}));
+        assert!(!params.to_string_map().contains_key("nonce"));
+        assert!(params.explain("nonce").to_string().contains("not set"));
+        assert!(matches!(params.entry("nonce"), crate::Entry::Vacant(_)));
+        assert!(params.try_insert("nonce", "fresh").is_ok());
+        assert!(params.rename_key("db.token", "token").is_err());
+        assert_eq!(params.clone().into_iter().count(), 3);
+    }
+
+    #[test]
+    fn test_ttl_follows_aliases_and_purge_notifies() {
+        let mut params = Parameters::new().with("old", 1);
+        params.alias("token", "target");
+        params.insert_with_ttl("token", "t", Duration::from_secs(60));
+        assert!(params.expires_at("target").is_some());
+
+        params.set_capacity_limits(crate::CapacityLimits::new().max_value_string_len(4));
+        assert!(params.insert_with_ttl("old", "too long", Duration::ZERO).is_none());
+        assert_eq!(params.get::<i32>("old"), Some(&1));
+        assert!(params.expires_at("old").is_none());
+
+        let changes = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = changes.clone();
+        params.on_change(move |batch| sink.lock().unwrap().extend(batch.keys().map(String::from)));
+        params.insert_with_ttl("t", 2, Duration::ZERO);
+        changes.lock().unwrap().clear();
+        assert_eq!(params.purge_expired(), ["t"]);
+        assert_eq!(*changes.lock().unwrap(), ["t"]);
+    }
+
+    #[test]
+    fn test_entries_survive_expiry_while_held() {
+        let mut params = Parameters::new();
+        params.insert_with_ttl("a", 1, Duration::from_millis(20));
+        params.insert_with_ttl("b", 2, Duration::from_millis(300));
+        let crate::Entry::Occupied(mut a) = params.entry("a") else { panic!("`a` is stored") };
+        std::thread::sleep(Duration::from_millis(40));
+        assert_eq!(a.insert(3).unwrap().as_any().downcast_ref::<i32>(), Some(&1));
+        assert_eq!(params.get::<i32>("a"), Some(&3));
+
+        let crate::Entry::Occupied(b) = params.entry("b") else { panic!("`b` is stored") };
+        std::thread::sleep(Duration::from_millis(300));
+        assert_eq!(b.remove().as_any().downcast_ref::<i32>(), Some(&2));
+        assert!(!params.contains_key("b"));
+    }
+}
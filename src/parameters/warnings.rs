@@ -0,0 +1,183 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use super::core::Parameters;
+
+/// How long an identical warning is held back after it was last delivered.
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A warning about how a parameter was read, such as a value coerced from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    pub key: String,
+    pub message: String,
+    /// How many identical warnings were held back since this one was last delivered.
+    pub suppressed: u64,
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.key, self.message)?;
+        if self.suppressed > 0 {
+            write!(f, " ({} repeated warning(s) suppressed)", self.suppressed)?;
+        }
+        Ok(())
+    }
+}
+
+type Sink = Arc<dyn Fn(&Warning) + Send + Sync>;
+
+#[derive(Debug)]
+struct Seen {
+    delivered: Instant,
+    suppressed: u64,
+}
+
+/// Delivers warnings to subscribers, passing on each distinct key and
+/// message at most once per interval and counting the repeats it drops.
+pub(crate) struct WarningEmitter {
+    sinks: Vec<Sink>,
+    interval: Duration,
+    seen: Mutex<HashMap<(String, String), Seen>>,
+}
+
+impl Default for WarningEmitter {
+    fn default() -> Self {
+        Self {
+            sinks: Vec::new(),
+            interval: DEFAULT_INTERVAL,
+            seen: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl fmt::Debug for WarningEmitter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WarningEmitter")
+            .field("sinks", &self.sinks.len())
+            .field("interval", &self.interval)
+            .finish()
+    }
+}
+
+impl WarningEmitter {
+    pub(crate) fn emit(&self, key: &str, message: &str) {
+        if self.sinks.is_empty() {
+            return;
+        }
+        let now = Instant::now();
+        let suppressed = {
+            let mut seen = self.seen.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            match seen.get_mut(&(key.to_string(), message.to_string())) {
+                Some(entry) if now.duration_since(entry.delivered) < self.interval => {
+                    entry.suppressed += 1;
+                    return;
+                }
+                Some(entry) => {
+                    entry.delivered = now;
+                    std::mem::take(&mut entry.suppressed)
+                }
+                None => {
+                    seen.insert((key.to_string(), message.to_string()), Seen { delivered: now, suppressed: 0 });
+                    0
+                }
+            }
+        };
+        let warning = Warning {
+            key: key.to_string(),
+            message: message.to_string(),
+            suppressed,
+        };
+        for sink in &self.sinks {
+            sink(&warning);
+        }
+    }
+}
+
+impl Parameters {
+    /// Register a callback receiving the warnings raised while reading these parameters.
+    ///
+    /// Warnings are deduplicated: a given key and message is delivered at most
+    /// once per interval (a minute unless changed with `set_warning_interval`),
+    /// so a hot loop reading a coerced value does not flood the logs. The next
+    /// delivery reports how many repeats were dropped in between.
+    ///
+    /// # Arguments
+    ///
+    /// * `sink` - A callback receiving each delivered `Warning`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use parameterx::Parameters;
+    ///
+    /// let mut params = Parameters::new().with("workers", "4");
+    /// let logged = Arc::new(Mutex::new(Vec::new()));
+    /// let sink = logged.clone();
+    /// params.on_warning(move |warning| sink.lock().unwrap().push(warning.to_string()));
+    ///
+    /// for _ in 0..1000 {
+    ///     assert_eq!(params.get_i64("workers"), Some(4));
+    /// }
+    /// assert_eq!(*logged.lock().unwrap(), ["workers: parsed i64 from a string value"]);
+    /// ```
+    pub fn on_warning<F>(&mut self, sink: F)
+    where
+        F: Fn(&Warning) + Send + Sync + 'static,
+    {
+        self.warnings.sinks.push(Arc::new(sink));
+    }
+
+    /// Set how long an identical warning is held back after it was delivered.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The quiet period per key and message; `Duration::ZERO` delivers every warning.
+    pub fn set_warning_interval(&mut self, interval: Duration) {
+        self.warnings.interval = interval;
+    }
+
+    /// Raise a warning about `key` through the rate limiter.
+    ///
+    /// The crate uses this for lenient coercions; applications can use it for
+    /// their own notices, such as reads of deprecated keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key the warning is about.
+    /// * `message` - The warning text. Repeats are recognized by key and message.
+    pub fn warn(&self, key: &str, message: &str) {
+        self.warnings.emit(key, message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use super::*;
+
+    #[test]
+    fn test_repeats_are_counted_and_reported() {
+        let mut params = Parameters::new();
+        let delivered = Arc::new(Mutex::new(Vec::new()));
+        let sink = delivered.clone();
+        params.on_warning(move |warning| sink.lock().unwrap().push(warning.clone()));
+        params.set_warning_interval(Duration::from_millis(50));
+
+        for _ in 0..5 {
+            params.warn("old_name", "deprecated");
+        }
+        params.warn("other", "deprecated");
+        thread::sleep(Duration::from_millis(60));
+        params.warn("old_name", "deprecated");
+
+        let delivered = delivered.lock().unwrap();
+        let summary: Vec<(&str, u64)> = delivered.iter().map(|w| (w.key.as_str(), w.suppressed)).collect();
+        assert_eq!(summary, [("old_name", 0), ("other", 0), ("old_name", 4)]);
+        assert_eq!(format!("{}", delivered[2]), "old_name: deprecated (4 repeated warning(s) suppressed)");
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{ParameterError, Parameters, Result};
+
+/// A backend that persists whole parameter sets under a namespace, such as a
+/// pipeline stage or job id.
+///
+/// Sets are stored as their lossless `to_json_unredacted` form: values under
+/// sensitive keys are kept, and `Secret`s are written exposed so that a load
+/// gives back the real value rather than `***`. The backend therefore holds
+/// the secrets in plain form and must be trusted with them. Loaded values
+/// have the types `Parameters::from_json` gives them, so a secret comes back
+/// as a plain `String`; wrap it again with `insert_secret` if it should stay
+/// masked. Backends report their own failures as `ParameterError::Store`.
+///
+/// # Examples
+///
+/// ```
+/// use std::{collections::HashMap, sync::Mutex};
+/// use parameterx::{ParameterStore, Parameters, Result};
+///
+/// #[derive(Default)]
+/// struct InMemory(Mutex<HashMap<String, Vec<u8>>>);
+///
+/// impl ParameterStore for InMemory {
+///     fn save_bytes(&self, namespace: &str, bytes: Vec<u8>) -> Result<()> {
+///         self.0.lock().unwrap().insert(namespace.to_string(), bytes);
+///         Ok(())
+///     }
+///
+///     fn load_bytes(&self, namespace: &str) -> Result<Option<Vec<u8>>> {
+///         Ok(self.0.lock().unwrap().get(namespace).cloned())
+///     }
+///
+///     fn delete(&self, namespace: &str) -> Result<bool> {
+///         Ok(self.0.lock().unwrap().remove(namespace).is_some())
+///     }
+/// }
+///
+/// let store = InMemory::default();
+/// let mut params = Parameters::new().with("batch", 3);
+/// params.insert_secret("api_key", "k3y");
+/// store.save(&params, "job-42").unwrap();
+///
+/// let params = store.load("job-42").unwrap().unwrap();
+/// assert_eq!(params.get_i64("batch"), Some(3));
+/// assert_eq!(params.get_str("api_key"), Some("k3y"));
+/// assert!(store.load("job-43").unwrap().is_none());
+/// ```
+pub trait ParameterStore: Send + Sync {
+    /// Store the encoded set for `namespace`, replacing any previous one.
+    fn save_bytes(&self, namespace: &str, bytes: Vec<u8>) -> Result<()>;
+
+    /// The encoded set stored for `namespace`, if any.
+    fn load_bytes(&self, namespace: &str) -> Result<Option<Vec<u8>>>;
+
+    /// Remove the set stored for `namespace`, returning whether there was one.
+    fn delete(&self, namespace: &str) -> Result<bool>;
+
+    /// Save `params` under `namespace`, replacing any previous set, with `Secret`s exposed.
+    fn save(&self, params: &Parameters, namespace: &str) -> Result<()> {
+        let json = params.to_json_unredacted()?;
+        let bytes = serde_json::to_vec(&json).map_err(|error| ParameterError::Store(error.into()))?;
+        self.save_bytes(namespace, bytes)
+    }
+
+    /// Load the set saved under `namespace`, or `None` if nothing was saved.
+    fn load(&self, namespace: &str) -> Result<Option<Parameters>> {
+        let Some(bytes) = self.load_bytes(namespace)? else {
+            return Ok(None);
+        };
+        let json = serde_json::from_slice(&bytes).map_err(|error| ParameterError::Parse {
+            format: "JSON",
+            message: error.to_string(),
+        })?;
+        Parameters::from_json(json).map(Some)
+    }
+}
+
+impl<S: ParameterStore + ?Sized> ParameterStore for std::sync::Arc<S> {
+    fn save_bytes(&self, namespace: &str, bytes: Vec<u8>) -> Result<()> {
+        (**self).save_bytes(namespace, bytes)
+    }
+
+    fn load_bytes(&self, namespace: &str) -> Result<Option<Vec<u8>>> {
+        (**self).load_bytes(namespace)
+    }
+
+    fn delete(&self, namespace: &str) -> Result<bool> {
+        (**self).delete(namespace)
+    }
+}
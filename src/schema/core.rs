@@ -0,0 +1,265 @@
+use std::{collections::BTreeMap, fmt};
+use crate::{
+    error::ParameterError,
+    value::{as_f64, ParameterKind},
+    Parameters, Result,
+};
+
+/// The expectations for a single parameter key.
+#[derive(Debug, Clone)]
+pub struct FieldSchema {
+    pub kind: ParameterKind,
+    pub required: bool,
+    pub description: Option<String>,
+    pub default: Option<serde_json::Value>,
+    pub minimum: Option<f64>,
+    pub maximum: Option<f64>,
+}
+
+impl FieldSchema {
+    /// Create an optional field of the given kind.
+    pub fn new(kind: ParameterKind) -> Self {
+        Self {
+            kind,
+            required: false,
+            description: None,
+            default: None,
+            minimum: None,
+            maximum: None,
+        }
+    }
+
+    /// Mark the field as required.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Document the value used when the key is absent.
+    pub fn default_value(mut self, default: serde_json::Value) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Restrict numeric values to the inclusive range `min..=max`.
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.minimum = Some(min);
+        self.maximum = Some(max);
+        self
+    }
+
+    pub fn minimum(mut self, min: f64) -> Self {
+        self.minimum = Some(min);
+        self
+    }
+
+    pub fn maximum(mut self, max: f64) -> Self {
+        self.maximum = Some(max);
+        self
+    }
+}
+
+/// Why a key failed validation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ViolationKind {
+    Missing,
+    TypeMismatch {
+        expected: ParameterKind,
+        actual: &'static str,
+    },
+    OutOfRange {
+        value: f64,
+        minimum: Option<f64>,
+        maximum: Option<f64>,
+    },
+    UnknownKey,
+}
+
+/// A single validation failure for one key.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaViolation {
+    pub key: String,
+    pub kind: ViolationKind,
+}
+
+impl fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ViolationKind::Missing => write!(f, "{}: required parameter is missing", self.key),
+            ViolationKind::TypeMismatch { expected, actual } => {
+                write!(f, "{}: expected {}, found {}", self.key, expected, actual)
+            }
+            ViolationKind::OutOfRange { value, minimum, maximum } => {
+                write!(f, "{}: {} is outside the allowed range", self.key, value)?;
+                match (minimum, maximum) {
+                    (Some(min), Some(max)) => write!(f, " [{}, {}]", min, max),
+                    (Some(min), None) => write!(f, " [{}, ..)", min),
+                    (None, Some(max)) => write!(f, " (.., {}]", max),
+                    (None, None) => Ok(()),
+                }
+            }
+            ViolationKind::UnknownKey => write!(f, "{}: parameter is not declared in the schema", self.key),
+        }
+    }
+}
+
+/// A declaration of the keys a `Parameters` set is expected to contain.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{FieldSchema, ParameterKind, ParameterSchema, Parameters};
+///
+/// let schema = ParameterSchema::new()
+///     .field("host", FieldSchema::new(ParameterKind::String).required())
+///     .field("port", FieldSchema::new(ParameterKind::Integer).range(1.0, 65535.0));
+///
+/// let params = Parameters::new().with("host", "localhost").with("port", 8080);
+/// assert!(schema.validate(&params).is_ok());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ParameterSchema {
+    pub(crate) title: Option<String>,
+    pub(crate) fields: BTreeMap<String, FieldSchema>,
+    pub(crate) deny_unknown: bool,
+}
+
+impl ParameterSchema {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Declare a field, replacing any previous declaration for the same key.
+    pub fn field(mut self, key: impl Into<String>, field: FieldSchema) -> Self {
+        self.fields.insert(key.into(), field);
+        self
+    }
+
+    /// Report keys that are not declared in the schema as violations.
+    pub fn deny_unknown_keys(mut self) -> Self {
+        self.deny_unknown = true;
+        self
+    }
+
+    /// Get the declaration for a key.
+    pub fn get(&self, key: &str) -> Option<&FieldSchema> {
+        self.fields.get(key)
+    }
+
+    /// Iterate over the declared fields in key order.
+    pub fn fields(&self) -> impl Iterator<Item = (&String, &FieldSchema)> {
+        self.fields.iter()
+    }
+
+    /// Check `params` against the schema, collecting every violation.
+    ///
+    /// # Returns
+    ///
+    /// A list of violations, empty when `params` conforms to the schema.
+    pub fn violations(&self, params: &Parameters) -> Vec<SchemaViolation> {
+        let mut violations = Vec::new();
+        let mut violation = |key: &str, kind| violations.push(SchemaViolation { key: key.to_string(), kind });
+
+        for (key, field) in &self.fields {
+            let Some(value) = params.stored(key) else {
+                if field.required {
+                    violation(key, ViolationKind::Missing);
+                }
+                continue;
+            };
+
+            let actual = ParameterKind::of(value.as_ref());
+            if !field.kind.accepts(actual) {
+                violation(key, ViolationKind::TypeMismatch {
+                    expected: field.kind,
+                    actual: value.type_name(),
+                });
+                continue;
+            }
+
+            if let Some(number) = as_f64(value.as_ref()) {
+                let below = field.minimum.is_some_and(|min| number < min);
+                let above = field.maximum.is_some_and(|max| number > max);
+                if below || above {
+                    violation(key, ViolationKind::OutOfRange {
+                        value: number,
+                        minimum: field.minimum,
+                        maximum: field.maximum,
+                    });
+                }
+            }
+        }
+
+        if self.deny_unknown {
+            for key in params.keys().filter(|key| !self.fields.contains_key(key.as_str())) {
+                violation(key, ViolationKind::UnknownKey);
+            }
+        }
+
+        violations
+    }
+
+    /// Validate `params` against the schema.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if `params` conforms, or `ParameterError::ValidationFailed` listing every violation.
+    pub fn validate(&self, params: &Parameters) -> Result<()> {
+        let violations = self.violations(params);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ParameterError::ValidationFailed(violations))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema() -> ParameterSchema {
+        ParameterSchema::new()
+            .field("host", FieldSchema::new(ParameterKind::String).required())
+            .field("port", FieldSchema::new(ParameterKind::Integer).required().range(1.0, 65535.0))
+            .field("ratio", FieldSchema::new(ParameterKind::Float))
+    }
+
+    #[test]
+    fn test_validate_collects_all_violations() {
+        let params = Parameters::new()
+            .with("port", 70000)
+            .with("ratio", "high");
+
+        let violations = schema().violations(&params);
+        assert_eq!(violations.len(), 3);
+        assert_eq!(violations[0].kind, ViolationKind::Missing);
+        assert!(matches!(violations[1].kind, ViolationKind::OutOfRange { .. }));
+        assert!(matches!(violations[2].kind, ViolationKind::TypeMismatch { .. }));
+    }
+
+    #[test]
+    fn test_deny_unknown_keys() {
+        let params = Parameters::new()
+            .with("host", "localhost")
+            .with("port", 80)
+            .with("extra", true);
+
+        assert!(schema().validate(&params).is_ok());
+        let violations = schema().deny_unknown_keys().violations(&params);
+        assert_eq!(violations, vec![SchemaViolation {
+            key: "extra".to_string(),
+            kind: ViolationKind::UnknownKey,
+        }]);
+    }
+}
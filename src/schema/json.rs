@@ -0,0 +1,111 @@
+use serde_json::{json, Map, Value};
+use crate::value::ParameterKind;
+use super::core::{FieldSchema, ParameterSchema};
+
+const DRAFT_07: &str = "http://json-schema.org/draft-07/schema#";
+
+impl ParameterSchema {
+    /// Export the schema as a draft-07 JSON Schema document.
+    ///
+    /// # Returns
+    ///
+    /// A JSON object describing the expected parameters as an object with one property per field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{FieldSchema, ParameterKind, ParameterSchema};
+    ///
+    /// let schema = ParameterSchema::new()
+    ///     .title("server")
+    ///     .field("port", FieldSchema::new(ParameterKind::Integer).required());
+    ///
+    /// let document = schema.to_json_schema();
+    /// assert_eq!(document["properties"]["port"]["type"], "integer");
+    /// assert_eq!(document["required"][0], "port");
+    /// ```
+    pub fn to_json_schema(&self) -> Value {
+        let mut document = Map::new();
+        document.insert("$schema".into(), json!(DRAFT_07));
+        if let Some(title) = &self.title {
+            document.insert("title".into(), json!(title));
+        }
+        document.insert("type".into(), json!("object"));
+
+        let properties: Map<String, Value> = self.fields
+            .iter()
+            .map(|(key, field)| (key.clone(), field_schema(field)))
+            .collect();
+        document.insert("properties".into(), Value::Object(properties));
+
+        let required: Vec<&String> = self.fields
+            .iter()
+            .filter(|(_, field)| field.required)
+            .map(|(key, _)| key)
+            .collect();
+        if !required.is_empty() {
+            document.insert("required".into(), json!(required));
+        }
+
+        document.insert("additionalProperties".into(), json!(!self.deny_unknown));
+        Value::Object(document)
+    }
+}
+
+fn field_schema(field: &FieldSchema) -> Value {
+    let mut schema = Map::new();
+    if let Some(kind) = json_type(field.kind) {
+        schema.insert("type".into(), json!(kind));
+    }
+    if let Some(description) = &field.description {
+        schema.insert("description".into(), json!(description));
+    }
+    if let Some(default) = &field.default {
+        schema.insert("default".into(), default.clone());
+    }
+    if let Some(minimum) = field.minimum {
+        schema.insert("minimum".into(), json!(minimum));
+    }
+    if let Some(maximum) = field.maximum {
+        schema.insert("maximum".into(), json!(maximum));
+    }
+    Value::Object(schema)
+}
+
+fn json_type(kind: ParameterKind) -> Option<&'static str> {
+    match kind {
+        ParameterKind::String => Some("string"),
+        ParameterKind::Integer => Some("integer"),
+        ParameterKind::Float => Some("number"),
+        ParameterKind::Bool => Some("boolean"),
+        ParameterKind::Array => Some("array"),
+        ParameterKind::Object => Some("object"),
+        ParameterKind::Any => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_json_schema() {
+        let schema = ParameterSchema::new()
+            .field("host", FieldSchema::new(ParameterKind::String).required().description("Server host"))
+            .field("port", FieldSchema::new(ParameterKind::Integer).range(1.0, 65535.0).default_value(json!(8080)))
+            .field("extra", FieldSchema::new(ParameterKind::Any))
+            .deny_unknown_keys();
+
+        assert_eq!(schema.to_json_schema(), json!({
+            "$schema": DRAFT_07,
+            "type": "object",
+            "properties": {
+                "extra": {},
+                "host": { "type": "string", "description": "Server host" },
+                "port": { "type": "integer", "default": 8080, "minimum": 1.0, "maximum": 65535.0 },
+            },
+            "required": ["host"],
+            "additionalProperties": false,
+        }));
+    }
+}
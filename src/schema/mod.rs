@@ -0,0 +1,4 @@
+mod core;
+mod json;
+
+pub use core::*;
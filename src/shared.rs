@@ -0,0 +1,185 @@
+use std::sync::{mpsc::Receiver, Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use crate::{Key, ParameterChange, ParameterValue, Parameters, ParametersDiff};
+
+/// A handle to a `Parameters` set that several threads or tasks can read and update.
+///
+/// Cloning the handle is cheap and every clone refers to the same set.
+/// Mutation takes `&self`: each call holds an internal lock only for its own
+/// duration, so readers never see a half-applied `update`. Because no
+/// reference can outlive the lock, `get` returns a clone of the value; use
+/// `snapshot` for an O(1) copy of the whole set to read at leisure.
+///
+/// # Examples
+///
+/// ```
+/// use std::thread;
+/// use parameterx::{Parameters, SharedParameters};
+///
+/// let shared = SharedParameters::new(Parameters::new().with("requests", 0u64));
+/// let workers: Vec<_> = (0..4)
+///     .map(|_| {
+///         let shared = shared.clone();
+///         thread::spawn(move || shared.update(|params| {
+///             *params.get_mut::<u64>("requests").unwrap() += 1;
+///         }))
+///     })
+///     .collect();
+/// for worker in workers {
+///     worker.join().unwrap();
+/// }
+///
+/// assert_eq!(shared.get::<u64>("requests"), Some(4));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SharedParameters {
+    inner: Arc<RwLock<Parameters>>,
+}
+
+impl SharedParameters {
+    pub fn new(params: Parameters) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(params)),
+        }
+    }
+
+    /// A panic while the lock was held cannot leave a `Parameters` half-modified in a way
+    /// that breaks its invariants, so a poisoned lock is used as is.
+    fn read_lock(&self) -> RwLockReadGuard<'_, Parameters> {
+        self.inner.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write_lock(&self) -> RwLockWriteGuard<'_, Parameters> {
+        self.inner.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Insert a value, returning the one previously stored under the key.
+    pub fn insert<K, V>(&self, key: K, value: V) -> Option<Arc<dyn ParameterValue>>
+    where
+        K: Into<Key>,
+        V: ParameterValue + 'static,
+    {
+        self.write_lock().insert(key, value)
+    }
+
+    pub fn remove(&self, key: &str) -> Option<Arc<dyn ParameterValue>> {
+        self.write_lock().remove(key)
+    }
+
+    /// Get a copy of the value of type `T` stored under the key.
+    pub fn get<T: Clone + 'static>(&self, key: &str) -> Option<T> {
+        self.read_lock().get::<T>(key).cloned()
+    }
+
+    /// Get the stored value under the key, shared rather than copied.
+    pub fn get_value(&self, key: &str) -> Option<Arc<dyn ParameterValue>> {
+        self.read_lock().stored(key).cloned()
+    }
+
+    pub fn get_string(&self, key: &str) -> Option<String> {
+        self.read_lock().get_string(key)
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.read_lock().contains_key(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.read_lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.read_lock().is_empty()
+    }
+
+    /// Run `f` with shared access to the parameters, for reads that need references.
+    pub fn read<R>(&self, f: impl FnOnce(&Parameters) -> R) -> R {
+        f(&self.read_lock())
+    }
+
+    /// Run `f` with exclusive access to the parameters, so several changes apply atomically.
+    ///
+    /// `f` must not call back into this handle, or it will deadlock.
+    pub fn update<R>(&self, f: impl FnOnce(&mut Parameters) -> R) -> R {
+        f(&mut self.write_lock())
+    }
+
+    /// Replace the whole set, including its defaults and settings, returning the previous one.
+    ///
+    /// Subscriptions, change listeners and warning listeners stay with this
+    /// handle and are notified once of the keys whose values differ, as with
+    /// `reload_from`. The returned set has the listeners of `params`, if any.
+    pub fn replace(&self, params: Parameters) -> Parameters {
+        self.write_lock().replace_keeping_listeners(params)
+    }
+
+    /// Replace the explicit values with `new`, notifying subscribers of the keys that differ.
+    pub fn reload_from(&self, new: Parameters) -> ParametersDiff {
+        self.write_lock().reload_from(new)
+    }
+
+    /// Receive the changes made through any handle to keys starting with `prefix`.
+    ///
+    /// See [`Parameters::subscribe`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{thread, time::Duration};
+    /// use parameterx::{Parameters, SharedParameters};
+    ///
+    /// let shared = SharedParameters::new(Parameters::new().with("rate_limit", 100));
+    /// let changes = shared.subscribe("rate_limit");
+    ///
+    /// let writer = shared.clone();
+    /// thread::spawn(move || writer.insert("rate_limit", 50));
+    ///
+    /// let change = changes.recv_timeout(Duration::from_secs(5)).unwrap();
+    /// assert_eq!(change.new.unwrap().as_any().downcast_ref::<i32>(), Some(&50));
+    /// ```
+    pub fn subscribe(&self, prefix: impl Into<String>) -> Receiver<ParameterChange> {
+        self.write_lock().subscribe(prefix)
+    }
+
+    /// An independent copy of the current parameters. The copy is O(1) and shares storage until either side changes.
+    pub fn snapshot(&self) -> Parameters {
+        self.read_lock().clone()
+    }
+}
+
+impl From<Parameters> for SharedParameters {
+    fn from(params: Parameters) -> Self {
+        Self::new(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clones_share_state_and_snapshots_do_not() {
+        let shared = SharedParameters::from(Parameters::new().with("mode", "safe"));
+        let handle = shared.clone();
+        let before = shared.snapshot();
+
+        handle.insert("mode", "fast");
+        assert_eq!(shared.get::<&str>("mode"), Some("fast"));
+        assert_eq!(before.get::<&str>("mode"), Some(&"safe"));
+
+        let previous = shared.replace(Parameters::new());
+        assert_eq!(previous.len(), 1);
+        assert!(handle.is_empty());
+    }
+
+    #[test]
+    fn test_replace_keeps_subscribers() {
+        let shared = SharedParameters::new(Parameters::new().with("mode", "safe").with("old", 1));
+        let changes = shared.subscribe("");
+
+        shared.replace(Parameters::new().with("mode", "fast").with("new", 2));
+        shared.insert("later", 3);
+
+        let keys: Vec<String> = changes.try_iter().map(|change| change.key).collect();
+        assert_eq!(keys, ["mode", "new", "old", "later"]);
+    }
+}
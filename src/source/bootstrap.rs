@@ -0,0 +1,187 @@
+use std::fmt;
+use crate::{
+    error::ParameterError,
+    schema::{ParameterSchema, SchemaViolation},
+    Parameters,
+};
+use super::traits::ParameterSource;
+
+/// A source that failed to load during [`Parameters::bootstrap`].
+#[derive(Debug)]
+pub struct SourceFailure {
+    pub source: String,
+    pub error: ParameterError,
+}
+
+/// Everything that went wrong while assembling the startup parameters.
+#[derive(Debug, Default)]
+pub struct StartupReport {
+    /// Sources that could not be loaded; the remaining sources are still merged.
+    pub load_failures: Vec<SourceFailure>,
+    /// Schema violations of the merged parameters.
+    pub violations: Vec<SchemaViolation>,
+    /// Keys supplied by the sources that the schema does not declare.
+    ///
+    /// These are listed for context; they only fail the bootstrap when the
+    /// schema uses `deny_unknown_keys`, in which case they also appear in
+    /// `violations`.
+    pub unused_keys: Vec<String>,
+}
+
+impl StartupReport {
+    /// Whether the report contains any failure.
+    pub fn is_failure(&self) -> bool {
+        !self.load_failures.is_empty() || !self.violations.is_empty()
+    }
+}
+
+impl fmt::Display for StartupReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Startup configuration failed: {} source failure(s), {} violation(s)",
+            self.load_failures.len(),
+            self.violations.len(),
+        )?;
+        for failure in &self.load_failures {
+            writeln!(f, "  source {}: {}", failure.source, failure.error)?;
+        }
+        for violation in &self.violations {
+            writeln!(f, "  {}", violation)?;
+        }
+        if !self.unused_keys.is_empty() {
+            writeln!(f, "  unused keys: {}", self.unused_keys.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for StartupReport {}
+
+impl Parameters {
+    /// Load every source, merge them in order, and validate the result against `schema`.
+    ///
+    /// Later sources override earlier ones. A failing source does not stop the
+    /// remaining sources from loading, so the report lists every problem at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `sources` - The sources to load, lowest precedence first.
+    /// * `schema` - The schema the merged parameters must satisfy.
+    ///
+    /// # Returns
+    ///
+    /// The merged `Parameters`, or a `StartupReport` describing every load failure and violation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{async_trait, FieldSchema, ParameterKind, ParameterSchema, ParameterSource, Parameters, Result};
+    ///
+    /// struct Defaults;
+    ///
+    /// #[async_trait]
+    /// impl ParameterSource for Defaults {
+    ///     async fn load(&self) -> Result<Parameters> {
+    ///         Ok(Parameters::new().with("port", 8080))
+    ///     }
+    /// }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let schema = ParameterSchema::new()
+    ///     .field("port", FieldSchema::new(ParameterKind::Integer).required());
+    ///
+    /// let params = Parameters::bootstrap([Defaults], &schema).await.unwrap();
+    /// assert_eq!(params.get::<i32>("port"), Some(&8080));
+    /// # });
+    /// ```
+    pub async fn bootstrap<I>(sources: I, schema: &ParameterSchema) -> std::result::Result<Parameters, StartupReport>
+    where
+        I: IntoIterator,
+        I::Item: ParameterSource,
+    {
+        let mut report = StartupReport::default();
+        let mut params = Parameters::new();
+
+        for source in sources {
+            match source.load().await {
+                Ok(loaded) => params.merge(loaded),
+                Err(error) => report.load_failures.push(SourceFailure {
+                    source: source.name().to_string(),
+                    error,
+                }),
+            }
+        }
+
+        report.violations = schema.violations(&params);
+        report.unused_keys = params.keys()
+            .filter(|key| schema.get(key).is_none())
+            .map(String::from)
+            .collect();
+
+        if report.is_failure() {
+            Err(report)
+        } else {
+            Ok(params)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use crate::{schema::FieldSchema, value::ParameterKind, Result};
+    use super::*;
+
+    struct Static(Parameters);
+
+    #[async_trait]
+    impl ParameterSource for Static {
+        async fn load(&self) -> Result<Parameters> {
+            Ok(self.0.clone())
+        }
+    }
+
+    struct Down;
+
+    #[async_trait]
+    impl ParameterSource for Down {
+        async fn load(&self) -> Result<Parameters> {
+            Err(ParameterError::key_not_found("consul"))
+        }
+
+        fn name(&self) -> &str {
+            "consul"
+        }
+    }
+
+    fn schema() -> ParameterSchema {
+        ParameterSchema::new()
+            .field("host", FieldSchema::new(ParameterKind::String).required())
+            .field("port", FieldSchema::new(ParameterKind::Integer).required())
+    }
+
+    #[tokio::test]
+    async fn test_later_sources_override_earlier() {
+        let sources: Vec<Box<dyn ParameterSource>> = vec![
+            Box::new(Static(Parameters::new().with("host", "a").with("port", 1))),
+            Box::new(Static(Parameters::new().with("port", 2))),
+        ];
+        let params = Parameters::bootstrap(sources, &schema()).await.unwrap();
+        assert_eq!(params.get::<&str>("host"), Some(&"a"));
+        assert_eq!(params.get::<i32>("port"), Some(&2));
+    }
+
+    #[tokio::test]
+    async fn test_report_aggregates_everything() {
+        let sources: Vec<Box<dyn ParameterSource>> = vec![
+            Box::new(Static(Parameters::new().with("port", "80").with("hots", "a"))),
+            Box::new(Down),
+        ];
+        let report = Parameters::bootstrap(sources, &schema()).await.unwrap_err();
+        assert_eq!(report.load_failures.len(), 1);
+        assert_eq!(report.load_failures[0].source, "consul");
+        assert_eq!(report.violations.len(), 2);
+        assert_eq!(report.unused_keys, vec!["hots".to_string()]);
+    }
+}
@@ -0,0 +1,212 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+use tokio::sync::{Mutex, RwLock};
+use crate::{Parameters, Result};
+use super::traits::ParameterSource;
+
+struct Snapshot {
+    params: Arc<Parameters>,
+    loaded_at: Instant,
+}
+
+struct Inner<S> {
+    source: S,
+    ttl: Duration,
+    stale_window: Duration,
+    snapshot: RwLock<Option<Snapshot>>,
+    load_lock: Mutex<()>,
+    revalidating: AtomicBool,
+}
+
+/// A read-through cache over a slow `ParameterSource`.
+///
+/// Lookups are served from the last loaded snapshot while it is younger than
+/// the TTL. Once it expires, the snapshot is still served for the configured
+/// stale-while-revalidate window while a background task reloads it; past that
+/// window the caller waits for a fresh load.
+///
+/// Background revalidation uses `tokio::spawn`, so reads of a stale snapshot
+/// must happen inside a Tokio runtime.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use parameterx::{async_trait, CachedParameters, ParameterSource, Parameters, Result};
+///
+/// struct Remote;
+///
+/// #[async_trait]
+/// impl ParameterSource for Remote {
+///     async fn load(&self) -> Result<Parameters> {
+///         Ok(Parameters::new().with("replicas", 3))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let cache = CachedParameters::new(Remote, Duration::from_secs(30))
+///     .stale_while_revalidate(Duration::from_secs(300));
+/// let params = cache.parameters().await.unwrap();
+/// assert_eq!(params.get::<i32>("replicas"), Some(&3));
+/// # });
+/// ```
+pub struct CachedParameters<S> {
+    inner: Arc<Inner<S>>,
+}
+
+impl<S> Clone for CachedParameters<S> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone() }
+    }
+}
+
+impl<S> CachedParameters<S>
+where
+    S: ParameterSource + 'static,
+{
+    /// Wrap `source`, treating loaded snapshots as fresh for `ttl`.
+    pub fn new(source: S, ttl: Duration) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                source,
+                ttl,
+                stale_window: Duration::ZERO,
+                snapshot: RwLock::new(None),
+                load_lock: Mutex::new(()),
+                revalidating: AtomicBool::new(false),
+            }),
+        }
+    }
+
+    /// Keep serving an expired snapshot for `window` while it is reloaded in the background.
+    ///
+    /// Must be called before the cache is cloned or first used.
+    pub fn stale_while_revalidate(mut self, window: Duration) -> Self {
+        if let Some(inner) = Arc::get_mut(&mut self.inner) {
+            inner.stale_window = window;
+        }
+        self
+    }
+
+    /// Get the current parameters, loading or revalidating them as needed.
+    ///
+    /// # Returns
+    ///
+    /// The cached snapshot, or a `ParameterError` if a blocking load was required and failed.
+    pub async fn parameters(&self) -> Result<Arc<Parameters>> {
+        if let Some((params, age)) = self.current().await {
+            if age < self.inner.ttl {
+                return Ok(params);
+            }
+            if age < self.inner.ttl + self.inner.stale_window {
+                self.revalidate_in_background();
+                return Ok(params);
+            }
+        }
+        self.load_if_expired().await
+    }
+
+    /// Reload the parameters from the source immediately, regardless of age.
+    ///
+    /// On failure the previous snapshot is kept.
+    pub async fn refresh(&self) -> Result<Arc<Parameters>> {
+        let _guard = self.inner.load_lock.lock().await;
+        Self::load_into(&self.inner).await
+    }
+
+    /// Drop the cached snapshot so the next lookup loads from the source.
+    pub async fn invalidate(&self) {
+        *self.inner.snapshot.write().await = None;
+    }
+
+    async fn current(&self) -> Option<(Arc<Parameters>, Duration)> {
+        self.inner.snapshot.read().await
+            .as_ref()
+            .map(|snapshot| (snapshot.params.clone(), snapshot.loaded_at.elapsed()))
+    }
+
+    async fn load_if_expired(&self) -> Result<Arc<Parameters>> {
+        let _guard = self.inner.load_lock.lock().await;
+        // Another caller may have finished loading while we waited for the lock.
+        if let Some((params, age)) = self.current().await {
+            if age < self.inner.ttl {
+                return Ok(params);
+            }
+        }
+        Self::load_into(&self.inner).await
+    }
+
+    fn revalidate_in_background(&self) {
+        if self.inner.revalidating.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        let inner = self.inner.clone();
+        tokio::spawn(async move {
+            {
+                let _guard = inner.load_lock.lock().await;
+                // A failed revalidation keeps serving the stale snapshot until the window closes.
+                let _ = Self::load_into(&inner).await;
+            }
+            inner.revalidating.store(false, Ordering::Release);
+        });
+    }
+
+    async fn load_into(inner: &Inner<S>) -> Result<Arc<Parameters>> {
+        let params = Arc::new(inner.source.load().await?);
+        *inner.snapshot.write().await = Some(Snapshot {
+            params: params.clone(),
+            loaded_at: Instant::now(),
+        });
+        Ok(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use async_trait::async_trait;
+    use super::*;
+
+    #[derive(Default)]
+    struct Counting {
+        loads: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl ParameterSource for Counting {
+        async fn load(&self) -> Result<Parameters> {
+            let n = self.loads.fetch_add(1, Ordering::SeqCst) + 1;
+            Ok(Parameters::new().with("generation", n))
+        }
+    }
+
+    fn generation(params: &Parameters) -> usize {
+        *params.get::<usize>("generation").unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_serves_fresh_snapshot_without_reloading() {
+        let cache = CachedParameters::new(Counting::default(), Duration::from_secs(60));
+        assert_eq!(generation(&cache.parameters().await.unwrap()), 1);
+        assert_eq!(generation(&cache.parameters().await.unwrap()), 1);
+        assert_eq!(generation(&cache.refresh().await.unwrap()), 2);
+        assert_eq!(generation(&cache.parameters().await.unwrap()), 2);
+    }
+
+    #[tokio::test]
+    async fn test_stale_while_revalidate() {
+        let cache = CachedParameters::new(Counting::default(), Duration::ZERO)
+            .stale_while_revalidate(Duration::from_secs(60));
+        assert_eq!(generation(&cache.parameters().await.unwrap()), 1);
+
+        // Expired but within the window: the stale snapshot is returned immediately.
+        assert_eq!(generation(&cache.parameters().await.unwrap()), 1);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(cache.inner.source.loads.load(Ordering::SeqCst), 2);
+    }
+}
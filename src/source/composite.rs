@@ -0,0 +1,132 @@
+use async_trait::async_trait;
+use crate::{Parameters, Result};
+use super::traits::ParameterSource;
+
+struct Layer {
+    source: Box<dyn ParameterSource>,
+    optional: bool,
+}
+
+/// A source that loads several providers in order and merges their parameters.
+///
+/// Later sources override the keys of earlier ones. A failing source fails
+/// the whole load unless it was added with `with_optional`, in which case it
+/// is skipped.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{async_trait, CompositeSource, ParameterSource, Parameters, Result};
+///
+/// struct Consul;
+/// struct Ssm;
+///
+/// #[async_trait]
+/// impl ParameterSource for Consul {
+///     async fn load(&self) -> Result<Parameters> {
+///         Ok(Parameters::new().with("replicas", 3).with("region", "eu"))
+///     }
+/// }
+///
+/// #[async_trait]
+/// impl ParameterSource for Ssm {
+///     async fn load(&self) -> Result<Parameters> {
+///         Ok(Parameters::new().with("replicas", 5))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let source = CompositeSource::new().with(Consul).with(Ssm);
+/// let params = Parameters::load_from(&source).await.unwrap();
+/// assert_eq!(params.get::<i32>("replicas"), Some(&5));
+/// assert_eq!(params.get::<&str>("region"), Some(&"eu"));
+/// # });
+/// ```
+#[derive(Default)]
+pub struct CompositeSource {
+    layers: Vec<Layer>,
+}
+
+impl CompositeSource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a source whose keys override those of the sources added before it.
+    pub fn with(mut self, source: impl ParameterSource + 'static) -> Self {
+        self.layers.push(Layer { source: Box::new(source), optional: false });
+        self
+    }
+
+    /// Add a source that is skipped, rather than failing the load, when it cannot be loaded.
+    pub fn with_optional(mut self, source: impl ParameterSource + 'static) -> Self {
+        self.layers.push(Layer { source: Box::new(source), optional: true });
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+}
+
+impl std::fmt::Debug for CompositeSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.layers.iter().map(|layer| layer.source.name()))
+            .finish()
+    }
+}
+
+#[async_trait]
+impl ParameterSource for CompositeSource {
+    async fn load(&self) -> Result<Parameters> {
+        let mut params = Parameters::new();
+        for layer in &self.layers {
+            match layer.source.load().await {
+                Ok(loaded) => params.merge(loaded),
+                Err(_) if layer.optional => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParameterError;
+    use super::*;
+
+    struct Failing;
+
+    #[async_trait]
+    impl ParameterSource for Failing {
+        async fn load(&self) -> Result<Parameters> {
+            Err(ParameterError::key_not_found("backend"))
+        }
+    }
+
+    struct Static(Parameters);
+
+    #[async_trait]
+    impl ParameterSource for Static {
+        async fn load(&self) -> Result<Parameters> {
+            Ok(self.0.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_optional_failures_are_skipped() {
+        let source = CompositeSource::new()
+            .with(Static(Parameters::new().with("a", 1)))
+            .with_optional(Failing);
+        assert_eq!(source.load().await.unwrap().get::<i32>("a"), Some(&1));
+
+        let source = source.with(Failing);
+        assert!(matches!(source.load().await, Err(ParameterError::KeyNotFound { .. })));
+    }
+}
@@ -0,0 +1,282 @@
+use std::{
+    ffi::OsString,
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use async_trait::async_trait;
+use crate::{error::ParameterError, Parameters, Result};
+use super::traits::ParameterSource;
+
+type ErrorCallback = Arc<dyn Fn(&ParameterError) + Send + Sync>;
+
+#[derive(Default)]
+struct Circuit {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+}
+
+/// Wraps a `ParameterSource` so that load failures fall back to a known-good set.
+///
+/// When the wrapped source fails, the last successfully loaded parameters are
+/// returned instead, first from memory, then from the on-disk snapshot if one is
+/// configured, and finally from the static defaults. Every failure is reported
+/// through the `on_error` callback. After `failure_threshold` consecutive
+/// failures the circuit opens and the source is not called again until the
+/// cooldown has elapsed.
+///
+/// On-disk snapshots hold the unredacted JSON form of the parameters, so
+/// `Secret`s and sensitive keys keep their real values and values come back
+/// typed as described in [`Parameters::from_json`]. A snapshot is written to a
+/// temporary file next to `path` and renamed into place, so a crash mid-write
+/// never leaves a truncated snapshot behind.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use parameterx::{async_trait, FallbackSource, ParameterSource, Parameters, Result};
+///
+/// struct Remote;
+///
+/// #[async_trait]
+/// impl ParameterSource for Remote {
+///     async fn load(&self) -> Result<Parameters> {
+///         Err(std::io::Error::other("backend unavailable").into())
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let source = FallbackSource::new(Remote)
+///     .static_defaults(Parameters::new().with("replicas", 1))
+///     .failure_threshold(3)
+///     .cooldown(Duration::from_secs(30))
+///     .on_error(|err| eprintln!("parameter source failed: {}", err));
+///
+/// let params = source.load().await.unwrap();
+/// assert_eq!(params.get::<i32>("replicas"), Some(&1));
+/// # });
+/// ```
+pub struct FallbackSource<S> {
+    source: S,
+    snapshot_path: Option<PathBuf>,
+    defaults: Option<Parameters>,
+    on_error: Option<ErrorCallback>,
+    failure_threshold: u32,
+    cooldown: Duration,
+    last_good: Mutex<Option<Parameters>>,
+    circuit: Mutex<Circuit>,
+}
+
+impl<S: ParameterSource> FallbackSource<S> {
+    pub fn new(source: S) -> Self {
+        Self {
+            source,
+            snapshot_path: None,
+            defaults: None,
+            on_error: None,
+            failure_threshold: u32::MAX,
+            cooldown: Duration::ZERO,
+            last_good: Mutex::new(None),
+            circuit: Mutex::new(Circuit::default()),
+        }
+    }
+
+    /// Persist every successful load to `path` and read it back when the source fails.
+    pub fn snapshot_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.snapshot_path = Some(path.into());
+        self
+    }
+
+    /// Use `defaults` when neither a previous load nor a snapshot is available.
+    pub fn static_defaults(mut self, defaults: Parameters) -> Self {
+        self.defaults = Some(defaults);
+        self
+    }
+
+    /// Report source and snapshot failures through `callback`.
+    pub fn on_error<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&ParameterError) + Send + Sync + 'static,
+    {
+        self.on_error = Some(Arc::new(callback));
+        self
+    }
+
+    /// Open the circuit after `failures` consecutive load failures.
+    pub fn failure_threshold(mut self, failures: u32) -> Self {
+        self.failure_threshold = failures.max(1);
+        self
+    }
+
+    /// How long an open circuit skips the source before trying it again.
+    pub fn cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Whether the circuit is currently open and loads go straight to the fallback.
+    pub fn is_open(&self) -> bool {
+        self.circuit.lock().unwrap()
+            .open_until
+            .is_some_and(|until| Instant::now() < until)
+    }
+
+    fn report(&self, err: &ParameterError) {
+        if let Some(callback) = &self.on_error {
+            callback(err);
+        }
+    }
+
+    fn record_success(&self, params: &Parameters) {
+        *self.circuit.lock().unwrap() = Circuit::default();
+        *self.last_good.lock().unwrap() = Some(params.clone());
+        if let Some(path) = &self.snapshot_path {
+            if let Err(err) = write_snapshot(path, params) {
+                self.report(&err);
+            }
+        }
+    }
+
+    fn record_failure(&self) {
+        let mut circuit = self.circuit.lock().unwrap();
+        circuit.consecutive_failures = circuit.consecutive_failures.saturating_add(1);
+        if circuit.consecutive_failures >= self.failure_threshold {
+            circuit.open_until = Some(Instant::now() + self.cooldown);
+        }
+    }
+
+    fn fallback(&self) -> Option<Parameters> {
+        if let Some(params) = self.last_good.lock().unwrap().clone() {
+            return Some(params);
+        }
+        if let Some(path) = &self.snapshot_path {
+            match read_snapshot(path) {
+                Ok(params) => return Some(params),
+                Err(err) => self.report(&err),
+            }
+        }
+        self.defaults.clone()
+    }
+}
+
+#[async_trait]
+impl<S: ParameterSource> ParameterSource for FallbackSource<S> {
+    async fn load(&self) -> Result<Parameters> {
+        if self.is_open() {
+            if let Some(params) = self.fallback() {
+                return Ok(params);
+            }
+        }
+
+        match self.source.load().await {
+            Ok(params) => {
+                self.record_success(&params);
+                Ok(params)
+            }
+            Err(err) => {
+                self.report(&err);
+                self.record_failure();
+                self.fallback().ok_or(err)
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        self.source.name()
+    }
+}
+
+fn write_snapshot(path: &Path, params: &Parameters) -> Result<()> {
+    let text = serde_json::to_string_pretty(&params.to_json_unredacted()?).map_err(std::io::Error::other)?;
+    let mut temp = OsString::from(path.as_os_str());
+    temp.push(".tmp");
+    let temp = PathBuf::from(temp);
+    fs::write(&temp, text)?;
+    if let Err(err) = fs::rename(&temp, path) {
+        let _ = fs::remove_file(&temp);
+        return Err(err.into());
+    }
+    Ok(())
+}
+
+fn read_snapshot(path: &Path) -> Result<Parameters> {
+    let text = fs::read_to_string(path)?;
+    let json = serde_json::from_str(&text)
+        .map_err(|e| ParameterError::Parse { format: "JSON", message: e.to_string() })?;
+    Parameters::from_json(json)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use super::*;
+
+    #[derive(Default)]
+    struct Flaky {
+        failing: AtomicBool,
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl ParameterSource for Flaky {
+        async fn load(&self) -> Result<Parameters> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.failing.load(Ordering::SeqCst) {
+                Err(ParameterError::key_not_found("remote"))
+            } else {
+                let mut params = Parameters::new().with("replicas", 3).with("debug", true);
+                params.insert_secret("token", "s3cret");
+                Ok(params)
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_snapshot_on_disk() {
+        let path = std::env::temp_dir().join(format!("parameterx-snapshot-{}.json", std::process::id()));
+        let remote = Arc::new(Flaky::default());
+
+        FallbackSource::new(remote.clone()).snapshot_path(&path).load().await.unwrap();
+
+        remote.failing.store(true, Ordering::SeqCst);
+        let errors = Arc::new(AtomicUsize::new(0));
+        let counter = errors.clone();
+        let restarted = FallbackSource::new(remote.clone())
+            .snapshot_path(&path)
+            .on_error(move |_| { counter.fetch_add(1, Ordering::SeqCst); });
+
+        let params = restarted.load().await.unwrap();
+        assert_eq!(params.get::<i64>("replicas"), Some(&3));
+        assert_eq!(params.get::<bool>("debug"), Some(&true));
+        assert_eq!(params.get::<String>("token").map(String::as_str), Some("s3cret"));
+        assert_eq!(errors.load(Ordering::SeqCst), 1);
+        assert!(!path.with_extension("json.tmp").exists());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_open_circuit_skips_source() {
+        let remote = Arc::new(Flaky::default());
+        remote.failing.store(true, Ordering::SeqCst);
+        let source = FallbackSource::new(remote.clone())
+            .static_defaults(Parameters::new().with("replicas", 1))
+            .failure_threshold(2)
+            .cooldown(Duration::from_secs(60));
+
+        for _ in 0..5 {
+            let params = source.load().await.unwrap();
+            assert_eq!(params.get::<i32>("replicas"), Some(&1));
+        }
+        assert!(source.is_open());
+        assert_eq!(remote.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_error_without_fallback() {
+        let remote = Arc::new(Flaky::default());
+        remote.failing.store(true, Ordering::SeqCst);
+        assert!(FallbackSource::new(remote).load().await.is_err());
+    }
+}
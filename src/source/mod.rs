@@ -0,0 +1,15 @@
+mod traits;
+mod cached;
+mod fallback;
+mod bootstrap;
+mod composite;
+mod parallel;
+mod sync;
+
+pub use traits::ParameterSource;
+pub use cached::CachedParameters;
+pub use fallback::FallbackSource;
+pub use composite::CompositeSource;
+pub use parallel::ParallelLoader;
+pub use sync::{MemoryStore, PushOutcome, RemoteStore, Resolution, SyncConflict, SyncReport, SyncedParameters, Versioned};
+pub use bootstrap::{SourceFailure, StartupReport};
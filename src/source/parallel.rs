@@ -0,0 +1,169 @@
+use std::{future::Future, pin::Pin, task::Poll};
+use crate::{Parameters, Result};
+use super::{bootstrap::{SourceFailure, StartupReport}, traits::ParameterSource};
+
+type LoadFuture<'a> = Pin<Box<dyn Future<Output = Result<Parameters>> + Send + 'a>>;
+
+/// Poll every future until all of them have completed, keeping results in their original order.
+async fn join_all<'a>(futures: Vec<LoadFuture<'a>>) -> Vec<Result<Parameters>> {
+    let mut pending: Vec<Option<LoadFuture<'a>>> = futures.into_iter().map(Some).collect();
+    let mut results: Vec<Option<Result<Parameters>>> = pending.iter().map(|_| None).collect();
+    std::future::poll_fn(|cx| {
+        let mut done = true;
+        for (slot, result) in pending.iter_mut().zip(results.iter_mut()) {
+            if let Some(future) = slot {
+                match future.as_mut().poll(cx) {
+                    Poll::Ready(output) => {
+                        *result = Some(output);
+                        *slot = None;
+                    }
+                    Poll::Pending => done = false,
+                }
+            }
+        }
+        if done { Poll::Ready(()) } else { Poll::Pending }
+    }).await;
+    results.into_iter().map(|result| result.expect("every future completed")).collect()
+}
+
+/// Loads several sources concurrently and merges them in a fixed precedence order.
+///
+/// All sources are started at once and awaited together on the calling
+/// task, so startup takes as long as the slowest source rather than the sum
+/// of all of them. However the loads finish, the results are merged in the
+/// order the sources were added, later sources overriding earlier ones.
+/// Failures are collected per source instead of stopping the others.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{async_trait, ParallelLoader, ParameterSource, Parameters, Result};
+///
+/// struct Env;
+/// struct Ssm;
+///
+/// #[async_trait]
+/// impl ParameterSource for Env {
+///     async fn load(&self) -> Result<Parameters> {
+///         Ok(Parameters::new().with("region", "eu").with("replicas", 1))
+///     }
+/// }
+///
+/// #[async_trait]
+/// impl ParameterSource for Ssm {
+///     async fn load(&self) -> Result<Parameters> {
+///         Ok(Parameters::new().with("replicas", 3))
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let params = ParallelLoader::new().with(Env).with(Ssm).load().await.unwrap();
+/// assert_eq!(params.get::<i32>("replicas"), Some(&3));
+/// assert_eq!(params.get::<&str>("region"), Some(&"eu"));
+/// # });
+/// ```
+#[derive(Default)]
+pub struct ParallelLoader {
+    sources: Vec<Box<dyn ParameterSource>>,
+}
+
+impl ParallelLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a source that takes precedence over every source added before it.
+    pub fn with(mut self, source: impl ParameterSource + 'static) -> Self {
+        self.sources.push(Box::new(source));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    /// Load every source and merge the ones that succeeded.
+    ///
+    /// # Returns
+    ///
+    /// The merged `Parameters`, together with the failure of each source that could not be loaded.
+    pub async fn load_partial(&self) -> (Parameters, Vec<SourceFailure>) {
+        let loads = self.sources.iter().map(|source| source.load()).collect();
+        let mut params = Parameters::new();
+        let mut failures = Vec::new();
+        for (source, result) in self.sources.iter().zip(join_all(loads).await) {
+            match result {
+                Ok(loaded) => params.merge(loaded),
+                Err(error) => failures.push(SourceFailure { source: source.name().to_string(), error }),
+            }
+        }
+        (params, failures)
+    }
+
+    /// Load every source and merge them, failing if any source failed.
+    ///
+    /// # Returns
+    ///
+    /// The merged `Parameters`, or a `StartupReport` listing the failure of every source that could not be loaded.
+    pub async fn load(&self) -> std::result::Result<Parameters, StartupReport> {
+        let (params, load_failures) = self.load_partial().await;
+        if load_failures.is_empty() {
+            Ok(params)
+        } else {
+            Err(StartupReport { load_failures, ..StartupReport::default() })
+        }
+    }
+}
+
+impl std::fmt::Debug for ParallelLoader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.sources.iter().map(|source| source.name()))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use async_trait::async_trait;
+    use crate::ParameterError;
+    use super::*;
+
+    struct Delayed(u64, Option<i32>);
+
+    #[async_trait]
+    impl ParameterSource for Delayed {
+        async fn load(&self) -> Result<Parameters> {
+            tokio::time::sleep(Duration::from_millis(self.0)).await;
+            self.1
+                .map(|value| Parameters::new().with("value", value))
+                .ok_or_else(|| ParameterError::key_not_found("value"))
+        }
+
+        fn name(&self) -> &str {
+            "delayed"
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_precedence_ignores_completion_order() {
+        let loader = ParallelLoader::new()
+            .with(Delayed(10, Some(1)))
+            .with(Delayed(300, Some(2)))
+            .with(Delayed(200, None))
+            .with(Delayed(100, Some(3)));
+
+        let started = tokio::time::Instant::now();
+        let (params, failures) = loader.load_partial().await;
+        assert_eq!(started.elapsed(), Duration::from_millis(300));
+        assert_eq!(params.get::<i32>("value"), Some(&3));
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].source, "delayed");
+        assert!(loader.load().await.is_err());
+    }
+}
@@ -0,0 +1,350 @@
+use std::{collections::{BTreeMap, BTreeSet}, sync::{Arc, Mutex}};
+use async_trait::async_trait;
+use crate::{value::parse_as, Parameters, Result};
+
+/// A value held by a `RemoteStore`, with the version the store assigned to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Versioned {
+    pub value: String,
+    /// Increases every time the store writes the key.
+    pub version: u64,
+}
+
+/// The outcome of a conditional write to a `RemoteStore`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PushOutcome {
+    /// The write was applied and the key now has this version.
+    Written(u64),
+    /// The key's version no longer matched; this is what the store holds instead.
+    Conflict(Option<Versioned>),
+}
+
+/// A writable, versioned key-value backend such as Redis or etcd.
+#[async_trait]
+pub trait RemoteStore: Send + Sync {
+    /// Read every key with its current version.
+    async fn pull(&self) -> Result<BTreeMap<String, Versioned>>;
+
+    /// Set `key` to `value`, or delete it for `None`, if its version is still `expected`.
+    ///
+    /// `expected` is `None` when the key is expected not to exist. Stores
+    /// implement this as a compare-and-swap, such as an etcd transaction or a
+    /// Redis `WATCH`.
+    async fn push(&self, key: &str, value: Option<&str>, expected: Option<u64>) -> Result<PushOutcome>;
+}
+
+/// A key edited both locally and remotely since the last sync.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncConflict {
+    pub key: String,
+    /// The local value, or `None` if it was removed locally.
+    pub local: Option<String>,
+    /// The remote value, or `None` if it was removed remotely.
+    pub remote: Option<Versioned>,
+}
+
+/// How a `SyncConflict` is settled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    /// Overwrite the remote value with the local one.
+    KeepLocal,
+    /// Replace the local value with the remote one.
+    KeepRemote,
+    /// Leave both sides as they are and report the conflict again on the next sync.
+    Defer,
+}
+
+/// What one `SyncedParameters::sync` call did.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncReport {
+    /// Keys whose local changes were written to the store.
+    pub pushed: Vec<String>,
+    /// Keys whose remote changes were applied locally.
+    pub pulled: Vec<String>,
+    /// Conflicts that were deferred and remain unresolved.
+    pub conflicts: Vec<SyncConflict>,
+}
+
+type Resolver = Box<dyn Fn(&SyncConflict) -> Resolution + Send + Sync>;
+
+/// `Parameters` kept in two-way sync with a `RemoteStore`.
+///
+/// Each `sync` pulls the store, compares both sides against the state agreed
+/// at the previous sync, and then pushes local edits and applies remote ones.
+/// Pushes are conditional on the version last seen, so an edit made remotely
+/// in the meantime is detected rather than overwritten. A key edited on both
+/// sides is passed to the resolver set with `on_conflict`; without one, it is
+/// deferred and listed in the report.
+///
+/// Values are exchanged in their string form. A pulled value is parsed into
+/// the type of the local value it replaces, such as `i32` or `bool`, and is
+/// stored as a `String` when the key is new or the text does not parse as
+/// that type. `Secret` values stay local: they are never pushed, and remote
+/// changes or deletions of their keys are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{MemoryStore, Parameters, RemoteStore, Resolution, SyncedParameters};
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let store = MemoryStore::new();
+/// let mut synced = SyncedParameters::new(Parameters::new().with("replicas", 3), store.clone())
+///     .on_conflict(|_| Resolution::KeepRemote);
+///
+/// let report = synced.sync().await.unwrap();
+/// assert_eq!(report.pushed, ["replicas"]);
+///
+/// store.push("region", Some("eu"), None).await.unwrap();
+/// synced.parameters_mut().insert("replicas", 5);
+/// let report = synced.sync().await.unwrap();
+/// assert_eq!((report.pushed, report.pulled), (vec!["replicas".to_string()], vec!["region".to_string()]));
+/// assert_eq!(synced.parameters().get_string("region").as_deref(), Some("eu"));
+/// # });
+/// ```
+pub struct SyncedParameters<S> {
+    params: Parameters,
+    store: S,
+    /// The value and version of every key as of the last sync.
+    base: BTreeMap<String, Versioned>,
+    resolver: Option<Resolver>,
+}
+
+impl<S: RemoteStore> SyncedParameters<S> {
+    /// Sync `params` with `store`; the first `sync` treats every key on either side as new.
+    pub fn new(params: Parameters, store: S) -> Self {
+        Self {
+            params,
+            store,
+            base: BTreeMap::new(),
+            resolver: None,
+        }
+    }
+
+    /// Decide how keys edited on both sides since the last sync are settled.
+    pub fn on_conflict<F>(mut self, resolver: F) -> Self
+    where
+        F: Fn(&SyncConflict) -> Resolution + Send + Sync + 'static,
+    {
+        self.resolver = Some(Box::new(resolver));
+        self
+    }
+
+    pub fn parameters(&self) -> &Parameters {
+        &self.params
+    }
+
+    /// Mutable access to the local parameters; changes are pushed on the next `sync`.
+    pub fn parameters_mut(&mut self) -> &mut Parameters {
+        &mut self.params
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    fn local_value(&self, key: &str) -> Option<String> {
+        self.params.stored(key).map(|value| value.to_string())
+    }
+
+    fn is_secret(&self, key: &str) -> bool {
+        self.params.stored(key).is_some_and(|value| value.exposed().is_some())
+    }
+
+    fn apply_remote(&mut self, key: &str, remote: Option<Versioned>) {
+        match remote {
+            Some(remote) => {
+                match self.params.stored(key).and_then(|local| parse_as(local.as_ref(), &remote.value)) {
+                    Some(value) => self.params.insert_arc(key, value),
+                    None => self.params.insert(key, remote.value.clone()),
+                };
+                self.base.insert(key.to_string(), remote);
+            }
+            None => {
+                self.params.remove(key);
+                self.base.remove(key);
+            }
+        }
+    }
+
+    /// Push local changes, pull remote changes and settle conflicts.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `SyncReport`, or the store's error. Changes
+    /// applied before a store error are kept and are not repeated by the next sync.
+    pub async fn sync(&mut self) -> Result<SyncReport> {
+        let remote = self.store.pull().await?;
+        let keys: BTreeSet<String> = self.params.keys().map(String::from)
+            .chain(remote.keys().cloned())
+            .chain(self.base.keys().cloned())
+            .collect();
+
+        let mut report = SyncReport::default();
+        for key in keys {
+            if self.is_secret(&key) {
+                continue;
+            }
+            let local = self.local_value(&key);
+            let base = self.base.get(&key);
+            let remote = remote.get(&key).cloned();
+            let local_changed = local.as_ref() != base.map(|base| &base.value);
+            let remote_changed = remote.as_ref().map(|remote| remote.version) != base.map(|base| base.version);
+            let expected = base.map(|base| base.version);
+
+            match (local_changed, remote_changed) {
+                (false, false) => {}
+                (false, true) => {
+                    self.apply_remote(&key, remote);
+                    report.pulled.push(key);
+                }
+                (true, true) if local == remote.as_ref().map(|remote| remote.value.clone()) => {
+                    self.apply_remote(&key, remote);
+                }
+                (true, false) => {
+                    self.push_or_resolve(key, local, expected, &mut report).await?;
+                }
+                (true, true) => {
+                    let conflict = SyncConflict { key, local, remote };
+                    self.resolve(conflict, &mut report).await?;
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    async fn push_or_resolve(&mut self, key: String, local: Option<String>, expected: Option<u64>, report: &mut SyncReport) -> Result<()> {
+        match self.store.push(&key, local.as_deref(), expected).await? {
+            PushOutcome::Written(version) => {
+                match &local {
+                    Some(value) => self.base.insert(key.clone(), Versioned { value: value.clone(), version }),
+                    None => self.base.remove(&key),
+                };
+                report.pushed.push(key);
+                Ok(())
+            }
+            PushOutcome::Conflict(remote) => {
+                let conflict = SyncConflict { key, local, remote };
+                self.resolve(conflict, report).await
+            }
+        }
+    }
+
+    async fn resolve(&mut self, conflict: SyncConflict, report: &mut SyncReport) -> Result<()> {
+        let resolution = self.resolver.as_ref().map_or(Resolution::Defer, |resolver| resolver(&conflict));
+        match resolution {
+            Resolution::KeepRemote => {
+                self.apply_remote(&conflict.key, conflict.remote);
+                report.pulled.push(conflict.key);
+            }
+            Resolution::KeepLocal => {
+                let expected = conflict.remote.as_ref().map(|remote| remote.version);
+                Box::pin(self.push_or_resolve(conflict.key, conflict.local, expected, report)).await?;
+            }
+            Resolution::Defer => report.conflicts.push(conflict),
+        }
+        Ok(())
+    }
+}
+
+impl<S> std::fmt::Debug for SyncedParameters<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyncedParameters")
+            .field("params", &self.params)
+            .field("synced_keys", &self.base.len())
+            .finish()
+    }
+}
+
+/// An in-process `RemoteStore`, for tests and for sharing parameters between components.
+///
+/// Clones share the same contents.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore {
+    entries: Arc<Mutex<(BTreeMap<String, Versioned>, u64)>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl RemoteStore for MemoryStore {
+    async fn pull(&self) -> Result<BTreeMap<String, Versioned>> {
+        Ok(self.entries.lock().unwrap().0.clone())
+    }
+
+    async fn push(&self, key: &str, value: Option<&str>, expected: Option<u64>) -> Result<PushOutcome> {
+        let mut guard = self.entries.lock().unwrap();
+        let (entries, next_version) = &mut *guard;
+        let current = entries.get(key);
+        if current.map(|current| current.version) != expected {
+            return Ok(PushOutcome::Conflict(current.cloned()));
+        }
+        *next_version += 1;
+        match value {
+            Some(value) => {
+                entries.insert(key.to_string(), Versioned { value: value.to_string(), version: *next_version });
+            }
+            None => {
+                entries.remove(key);
+            }
+        }
+        Ok(PushOutcome::Written(*next_version))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_concurrent_edits_go_to_the_resolver() {
+        let store = MemoryStore::new();
+        let mut a = SyncedParameters::new(Parameters::new().with("timeout", 30).with("old", true), store.clone());
+        a.sync().await.unwrap();
+        let mut b = SyncedParameters::new(Parameters::new(), store.clone())
+            .on_conflict(|conflict| if conflict.key == "timeout" { Resolution::KeepLocal } else { Resolution::KeepRemote });
+        assert_eq!(b.sync().await.unwrap().pulled, ["old", "timeout"]);
+
+        a.parameters_mut().insert("timeout", 10);
+        a.parameters_mut().remove("old");
+        b.parameters_mut().insert("timeout", 20);
+        a.sync().await.unwrap();
+
+        let report = b.sync().await.unwrap();
+        assert_eq!(report.pushed, ["timeout"]);
+        assert_eq!(report.pulled, ["old"]);
+        assert!(!b.parameters().contains_key("old"));
+
+        a.parameters_mut().insert("timeout", 5);
+        let report = a.sync().await.unwrap();
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(report.conflicts[0].remote.as_ref().map(|remote| remote.value.as_str()), Some("20"));
+        assert_eq!(store.pull().await.unwrap()["timeout"].value, "20");
+    }
+
+    #[tokio::test]
+    async fn test_secrets_stay_local_and_pulled_values_keep_their_type() {
+        let store = MemoryStore::new();
+        store.push("token", Some("remote"), None).await.unwrap();
+        let mut params = Parameters::new().with("workers", 4i32).with("greeting", "***");
+        params.insert_secret("token", "s3cret");
+        let mut synced = SyncedParameters::new(params, store.clone());
+
+        let report = synced.sync().await.unwrap();
+        assert_eq!(report.pushed, ["greeting", "workers"]);
+        assert!(report.pulled.is_empty());
+        assert_eq!(store.pull().await.unwrap()["greeting"].value, "***");
+        assert_eq!(store.pull().await.unwrap()["token"].value, "remote");
+
+        let version = store.pull().await.unwrap()["workers"].version;
+        store.push("workers", Some("8"), Some(version)).await.unwrap();
+        store.push("token", None, Some(1)).await.unwrap();
+        assert_eq!(synced.sync().await.unwrap().pulled, ["workers"]);
+        assert_eq!(synced.parameters().get::<i32>("workers"), Some(&8));
+        assert_eq!(synced.parameters().get::<crate::Secret<&str>>("token").unwrap().expose(), &"s3cret");
+    }
+}
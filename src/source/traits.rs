@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use crate::{Parameters, Result};
+
+/// A provider that loads a `Parameters` set, typically from a remote backend.
+#[async_trait]
+pub trait ParameterSource: Send + Sync {
+    async fn load(&self) -> Result<Parameters>;
+
+    /// A human-readable name used in reports; defaults to the type name.
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+}
+
+#[async_trait]
+impl<S: ParameterSource + ?Sized> ParameterSource for &S {
+    async fn load(&self) -> Result<Parameters> {
+        (**self).load().await
+    }
+
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+}
+
+#[async_trait]
+impl<S: ParameterSource + ?Sized> ParameterSource for std::sync::Arc<S> {
+    async fn load(&self) -> Result<Parameters> {
+        (**self).load().await
+    }
+
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+}
+
+#[async_trait]
+impl<S: ParameterSource + ?Sized> ParameterSource for Box<S> {
+    async fn load(&self) -> Result<Parameters> {
+        (**self).load().await
+    }
+
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+}
+
+impl Parameters {
+    /// Load parameters from a source.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The provider to load from, such as a `CompositeSource` of several backends.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the loaded `Parameters`, or the source's error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{async_trait, ParameterSource, Parameters, Result};
+    ///
+    /// struct Consul;
+    ///
+    /// #[async_trait]
+    /// impl ParameterSource for Consul {
+    ///     async fn load(&self) -> Result<Parameters> {
+    ///         Ok(Parameters::new().with("replicas", 3))
+    ///     }
+    /// }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let params = Parameters::load_from(Consul).await.unwrap();
+    /// assert_eq!(params.get::<i32>("replicas"), Some(&3));
+    /// # });
+    /// ```
+    pub async fn load_from<S: ParameterSource>(source: S) -> Result<Parameters> {
+        source.load().await
+    }
+}
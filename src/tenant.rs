@@ -0,0 +1,148 @@
+use std::collections::BTreeMap;
+use crate::{Explanation, Parameters};
+
+/// A base `Parameters` set with per-tenant overlays.
+///
+/// Lookups for a tenant resolve through that tenant's overlay first and fall
+/// back to the base set. Tenants without an overlay see the base set unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{Parameters, TenantParameters};
+///
+/// let mut tenants = TenantParameters::new(
+///     Parameters::new().with("rate_limit", 100).with("region", "eu"),
+/// );
+/// tenants.set_overlay("acme", Parameters::new().with("rate_limit", 1000));
+///
+/// assert_eq!(tenants.get::<i32>("acme", "rate_limit"), Some(&1000));
+/// assert_eq!(tenants.get::<&str>("acme", "region"), Some(&"eu"));
+/// assert_eq!(tenants.get::<i32>("globex", "rate_limit"), Some(&100));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TenantParameters {
+    base: Parameters,
+    overlays: BTreeMap<String, Parameters>,
+}
+
+impl TenantParameters {
+    pub fn new(base: Parameters) -> Self {
+        Self {
+            base,
+            overlays: BTreeMap::new(),
+        }
+    }
+
+    /// The parameters shared by every tenant.
+    pub fn base(&self) -> &Parameters {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut Parameters {
+        &mut self.base
+    }
+
+    /// Install or replace the overlay for a tenant, returning the previous one.
+    pub fn set_overlay(&mut self, tenant: impl Into<String>, overlay: Parameters) -> Option<Parameters> {
+        self.overlays.insert(tenant.into(), overlay)
+    }
+
+    /// Remove a tenant's overlay so it falls back to the base parameters.
+    pub fn remove_overlay(&mut self, tenant: &str) -> Option<Parameters> {
+        self.overlays.remove(tenant)
+    }
+
+    pub fn overlay(&self, tenant: &str) -> Option<&Parameters> {
+        self.overlays.get(tenant)
+    }
+
+    /// Get a tenant's overlay, creating an empty one if it has none.
+    pub fn overlay_mut(&mut self, tenant: impl Into<String>) -> &mut Parameters {
+        self.overlays.entry(tenant.into()).or_default()
+    }
+
+    /// Iterate over the tenants that have an overlay.
+    pub fn tenants(&self) -> impl Iterator<Item = &String> {
+        self.overlays.keys()
+    }
+
+    /// Get a value of type `T` for a tenant, resolving the overlay before the base.
+    ///
+    /// A key present in the overlay always wins, even if its value is not a `T`.
+    pub fn get<T: 'static>(&self, tenant: &str, key: &str) -> Option<&T> {
+        self.layer_for(tenant, key).get(key)
+    }
+
+    pub fn get_string(&self, tenant: &str, key: &str) -> Option<String> {
+        self.layer_for(tenant, key).get_string(key)
+    }
+
+    pub fn contains_key(&self, tenant: &str, key: &str) -> bool {
+        self.layer_for(tenant, key).contains_key(key)
+    }
+
+    /// Build the effective parameters for a tenant: the base with its overlay merged on top.
+    pub fn resolve(&self, tenant: &str) -> Parameters {
+        let mut resolved = self.base.clone();
+        if let Some(overlay) = self.overlays.get(tenant) {
+            resolved.merge(overlay.clone());
+        }
+        resolved
+    }
+
+    /// Explain how a key resolves for a tenant.
+    ///
+    /// Layers are listed in the order `resolve` applies them: the overlay's
+    /// explicit value, the base's explicit value, then the overlay's and the
+    /// base's defaults.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parameterx::{Parameters, TenantParameters};
+    ///
+    /// let mut tenants = TenantParameters::new(Parameters::new().with("rate_limit", 100));
+    /// tenants.set_overlay("acme", Parameters::new().with("rate_limit", 1000));
+    ///
+    /// let explanation = tenants.explain("acme", "rate_limit");
+    /// assert_eq!(explanation.winner().unwrap().layer, "overlay:acme");
+    /// assert_eq!(explanation.steps[1].layer, "base");
+    /// ```
+    pub fn explain(&self, tenant: &str, key: &str) -> Explanation {
+        let overlay_name = format!("overlay:{tenant}");
+        let overlay = self.overlays.get(tenant);
+        let overlay_layers = overlay.map(|overlay| (overlay.stored(key), overlay.defaults.get(key), &overlay.redaction));
+        let (overlay_value, overlay_default, overlay_redaction) = overlay_layers.unwrap_or((None, None, &self.base.redaction));
+
+        Explanation::from_layers(key, [
+            (overlay_name.clone(), overlay_value, overlay_redaction),
+            ("base".to_string(), self.base.stored(key), &self.base.redaction),
+            (format!("{overlay_name} default"), overlay_default, overlay_redaction),
+            ("base default".to_string(), self.base.defaults.get(key), &self.base.redaction),
+        ])
+    }
+
+    fn layer_for(&self, tenant: &str, key: &str) -> &Parameters {
+        self.overlays.get(tenant)
+            .filter(|overlay| overlay.contains_key(key))
+            .unwrap_or(&self.base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlay_lifecycle() {
+        let mut tenants = TenantParameters::new(Parameters::new().with("timeout", 30));
+        tenants.overlay_mut("acme").insert("timeout", 5);
+        assert_eq!(tenants.get::<i32>("acme", "timeout"), Some(&5));
+        assert_eq!(tenants.resolve("acme").get::<i32>("timeout"), Some(&5));
+        assert_eq!(tenants.tenants().collect::<Vec<_>>(), vec!["acme"]);
+
+        assert!(tenants.remove_overlay("acme").is_some());
+        assert_eq!(tenants.get::<i32>("acme", "timeout"), Some(&30));
+    }
+}
@@ -0,0 +1,131 @@
+use std::{fmt, str::FromStr};
+use crate::error::ParameterError;
+use super::traits::DisplayValue;
+
+const BINARY: [(&str, u64); 5] = [
+    ("PiB", 1 << 50),
+    ("TiB", 1 << 40),
+    ("GiB", 1 << 30),
+    ("MiB", 1 << 20),
+    ("KiB", 1 << 10),
+];
+
+const DECIMAL: [(&str, u64); 5] = [
+    ("PB", 1_000_000_000_000_000),
+    ("TB", 1_000_000_000_000),
+    ("GB", 1_000_000_000),
+    ("MB", 1_000_000),
+    ("KB", 1_000),
+];
+
+fn parse_error(message: impl ToString) -> ParameterError {
+    ParameterError::Parse {
+        format: "byte size",
+        message: message.to_string(),
+    }
+}
+
+/// A size in bytes, such as a buffer or cache limit.
+///
+/// Parses a number with an optional unit: decimal units (`KB`, `MB`, `GB`,
+/// `TB`, `PB`) are powers of 1000 and binary units (`KiB`, `MiB`, `GiB`,
+/// `TiB`, `PiB`) powers of 1024. Units are case-insensitive, a trailing `B`
+/// or no unit means bytes, and fractions such as `1.5GiB` are allowed.
+/// Sizes display with the largest unit that divides them exactly.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{ByteSize, Parameters};
+///
+/// let params = Parameters::new().with("cache_size", "10MiB");
+///
+/// let size: ByteSize = params.try_get_parse("cache_size").unwrap();
+/// assert_eq!(size.as_u64(), 10 * 1024 * 1024);
+/// assert_eq!(size.to_string(), "10MiB");
+/// assert_eq!("64KB".parse::<ByteSize>().unwrap().as_u64(), 64_000);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteSize(pub u64);
+
+impl ByteSize {
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u64> for ByteSize {
+    fn from(bytes: u64) -> Self {
+        ByteSize(bytes)
+    }
+}
+
+impl FromStr for ByteSize {
+    type Err = ParameterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim();
+        let split = text.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(text.len());
+        let (number, unit) = text.split_at(split);
+        let unit = unit.trim();
+
+        let scale = match unit.to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            lower => BINARY.iter().chain(DECIMAL.iter())
+                .find(|(name, _)| name.to_ascii_lowercase() == lower)
+                .map(|(_, scale)| *scale)
+                .ok_or_else(|| parse_error(format!("unknown unit `{}` in `{}`", unit, text)))?,
+        };
+
+        if let Ok(count) = number.parse::<u64>() {
+            return count.checked_mul(scale)
+                .map(ByteSize)
+                .ok_or_else(|| parse_error(format!("`{}` is too large", text)));
+        }
+        let count: f64 = number.parse().map_err(|_| parse_error(format!("invalid number in `{}`", text)))?;
+        let bytes = (count * scale as f64).round();
+        if bytes >= u64::MAX as f64 {
+            return Err(parse_error(format!("`{}` is too large", text)));
+        }
+        Ok(ByteSize(bytes as u64))
+    }
+}
+
+impl TryFrom<String> for ByteSize {
+    type Error = ParameterError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = BINARY.iter().chain(DECIMAL.iter()).find(|(_, scale)| self.0 != 0 && self.0.is_multiple_of(*scale));
+        match unit {
+            Some((name, scale)) => write!(f, "{}{}", self.0 / scale, name),
+            None => write!(f, "{}B", self.0),
+        }
+    }
+}
+
+impl DisplayValue for ByteSize {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_units_and_display() {
+        let parse = |text: &str| text.parse::<ByteSize>().map(|size| size.as_u64());
+        assert_eq!(parse("512").unwrap(), 512);
+        assert_eq!(parse("1.5 gib").unwrap(), 3 << 29);
+        assert_eq!(parse("2MB").unwrap(), 2_000_000);
+        assert!(parse("10 parsecs").is_err());
+        assert!(parse("20000PiB").is_err());
+
+        assert_eq!(ByteSize(2048).to_string(), "2KiB");
+        assert_eq!(ByteSize(3_000_000).to_string(), "3MB");
+        assert_eq!(ByteSize(1023).to_string(), "1023B");
+    }
+}
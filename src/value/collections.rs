@@ -0,0 +1,158 @@
+use std::{
+    any::Any,
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+use serde_json::Value;
+use crate::{parameters::value_to_json, Result};
+use super::traits::ParameterValue;
+
+/// Render `key: value` entries as `{a: 1, b: 2}`, in the given order.
+fn render_entries<'a, T: ParameterValue + 'a>(entries: impl Iterator<Item = (&'a String, &'a T)>) -> String {
+    let entries: Vec<String> = entries.map(|(key, value)| format!("{}: {}", key, value.to_string())).collect();
+    format!("{{{}}}", entries.join(", "))
+}
+
+/// A list of values, rendered as `[a, b]` and converted to a JSON array.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::Parameters;
+///
+/// let params = Parameters::new().with("ports", vec![80, 443]);
+/// assert_eq!(params.get_string("ports").as_deref(), Some("[80, 443]"));
+/// assert_eq!(params.to_json().unwrap()["ports"], serde_json::json!([80, 443]));
+/// ```
+impl<T: ParameterValue + Clone + 'static> ParameterValue for Vec<T> {
+    fn to_string(&self) -> String {
+        let items: Vec<String> = self.iter().map(|item| item.to_string()).collect();
+        format!("[{}]", items.join(", "))
+    }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    fn clone_arc(&self) -> Arc<dyn ParameterValue> {
+        Arc::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn eq_dyn(&self, other: &dyn ParameterValue) -> bool {
+        other.as_any().downcast_ref::<Self>().is_some_and(|other| {
+            self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.eq_dyn(b))
+        })
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        Ok(Value::Array(self.iter().map(|item| value_to_json(item)).collect()))
+    }
+}
+
+/// A map of values, rendered as `{a: 1, b: 2}` in key order and converted to a JSON object.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use parameterx::Parameters;
+///
+/// let weights = HashMap::from([("b".to_string(), 2), ("a".to_string(), 1)]);
+/// let params = Parameters::new().with("weights", weights);
+/// assert_eq!(params.get_string("weights").as_deref(), Some("{a: 1, b: 2}"));
+/// assert_eq!(params.to_json().unwrap()["weights"], serde_json::json!({ "a": 1, "b": 2 }));
+/// ```
+impl<T: ParameterValue + Clone + 'static> ParameterValue for HashMap<String, T> {
+    fn to_string(&self) -> String {
+        let sorted: BTreeMap<&String, &T> = self.iter().collect();
+        render_entries(sorted.into_iter())
+    }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    fn clone_arc(&self) -> Arc<dyn ParameterValue> {
+        Arc::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn eq_dyn(&self, other: &dyn ParameterValue) -> bool {
+        other.as_any().downcast_ref::<Self>().is_some_and(|other| {
+            self.len() == other.len()
+                && self.iter().all(|(key, value)| other.get(key).is_some_and(|other| value.eq_dyn(other)))
+        })
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        Ok(Value::Object(self.iter().map(|(key, value)| (key.clone(), value_to_json(value))).collect()))
+    }
+}
+
+/// A map of values, rendered as `{a: 1, b: 2}` and converted to a JSON object.
+impl<T: ParameterValue + Clone + 'static> ParameterValue for BTreeMap<String, T> {
+    fn to_string(&self) -> String {
+        render_entries(self.iter())
+    }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    fn clone_arc(&self) -> Arc<dyn ParameterValue> {
+        Arc::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn eq_dyn(&self, other: &dyn ParameterValue) -> bool {
+        other.as_any().downcast_ref::<Self>().is_some_and(|other| {
+            self.len() == other.len()
+                && self.iter().all(|(key, value)| other.get(key).is_some_and(|other| value.eq_dyn(other)))
+        })
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        Ok(Value::Object(self.iter().map(|(key, value)| (key.clone(), value_to_json(value))).collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Parameters;
+    use super::*;
+
+    #[test]
+    fn test_nested_collections_compare_and_serialize() {
+        let groups = BTreeMap::from([("admins".to_string(), vec!["ann".to_string()])]);
+        let params = Parameters::new().with("groups", groups.clone()).with("empty", Vec::<bool>::new());
+
+        assert_eq!(params.get::<BTreeMap<String, Vec<String>>>("groups"), Some(&groups));
+        assert_eq!(params.get_string("groups").as_deref(), Some("{admins: [ann]}"));
+        assert_eq!(params.to_json().unwrap(), serde_json::json!({ "empty": [], "groups": { "admins": ["ann"] } }));
+        assert_eq!(crate::ParameterKind::of(&groups), crate::ParameterKind::Object);
+        assert_eq!(params, params.clone().with("groups", groups));
+        assert_ne!(params, params.clone().with("empty", vec![false]));
+    }
+}
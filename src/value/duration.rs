@@ -0,0 +1,210 @@
+use std::{any::Any, fmt, str::FromStr, sync::Arc, time::Duration};
+use serde_json::Value;
+use crate::{error::ParameterError, Result};
+use super::traits::{DisplayValue, ParameterValue};
+
+const UNITS: [(&str, u128); 7] = [
+    ("d", 86_400_000_000_000),
+    ("h", 3_600_000_000_000),
+    ("m", 60_000_000_000),
+    ("s", 1_000_000_000),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("ns", 1),
+];
+
+fn parse_error(message: impl ToString) -> ParameterError {
+    ParameterError::Parse {
+        format: "duration",
+        message: message.to_string(),
+    }
+}
+
+fn unit_nanos(unit: &str) -> Option<u128> {
+    let canonical = match unit {
+        "d" | "day" | "days" => "d",
+        "h" | "hr" | "hrs" | "hour" | "hours" => "h",
+        "m" | "min" | "mins" | "minute" | "minutes" => "m",
+        "s" | "sec" | "secs" | "second" | "seconds" => "s",
+        "ms" | "millis" => "ms",
+        "us" | "µs" | "micros" => "us",
+        "ns" | "nanos" => "ns",
+        _ => return None,
+    };
+    UNITS.iter().find(|(name, _)| *name == canonical).map(|(_, nanos)| *nanos)
+}
+
+/// Parse a human-readable duration such as `250ms`, `5s`, `1.5h` or `2h30m`.
+///
+/// Segments are a number followed by a unit (`d`, `h`, `m`, `s`, `ms`,
+/// `us`, `ns` or their long forms) and may be separated by spaces. A bare
+/// number is read as seconds.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use parameterx::parse_duration;
+///
+/// assert_eq!(parse_duration("2h30m").unwrap(), Duration::from_secs(9000));
+/// assert_eq!(parse_duration("250ms").unwrap(), Duration::from_millis(250));
+/// assert_eq!(parse_duration("30").unwrap(), Duration::from_secs(30));
+/// ```
+pub fn parse_duration(text: &str) -> Result<Duration> {
+    let trimmed = text.trim();
+    if let Ok(secs) = trimmed.parse::<f64>() {
+        return Duration::try_from_secs_f64(secs).map_err(|e| parse_error(format!("`{}`: {}", trimmed, e)));
+    }
+
+    let mut nanos: u128 = 0;
+    let mut rest = trimmed;
+    if rest.is_empty() {
+        return Err(parse_error("empty duration"));
+    }
+    while !rest.is_empty() {
+        let number_len = rest.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(rest.len());
+        let (number, tail) = rest.split_at(number_len);
+        let tail = tail.trim_start();
+        let unit_len = tail.find(|c: char| c.is_ascii_digit() || c.is_whitespace()).unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(unit_len);
+
+        let amount: f64 = number.parse().map_err(|_| parse_error(format!("invalid number in `{}`", trimmed)))?;
+        let scale = unit_nanos(unit).ok_or_else(|| parse_error(format!("unknown unit `{}` in `{}`", unit, trimmed)))?;
+        nanos = nanos.saturating_add((amount * scale as f64).round() as u128);
+        rest = tail.trim_start();
+    }
+
+    let secs = u64::try_from(nanos / 1_000_000_000).map_err(|_| parse_error(format!("`{}` is too long", trimmed)))?;
+    Ok(Duration::new(secs, (nanos % 1_000_000_000) as u32))
+}
+
+/// Render a duration in the compact form `parse_duration` reads, such as `2h30m` or `1s500ms`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use parameterx::format_duration;
+///
+/// assert_eq!(format_duration(Duration::from_secs(9000)), "2h30m");
+/// assert_eq!(format_duration(Duration::from_millis(1500)), "1s500ms");
+/// assert_eq!(format_duration(Duration::ZERO), "0s");
+/// ```
+pub fn format_duration(duration: Duration) -> String {
+    if duration.is_zero() {
+        return "0s".to_string();
+    }
+    let mut remaining = duration.as_nanos();
+    let mut out = String::new();
+    for (unit, nanos) in UNITS {
+        let count = remaining / nanos;
+        if count > 0 {
+            out.push_str(&format!("{}{}", count, unit));
+            remaining %= nanos;
+        }
+    }
+    out
+}
+
+impl ParameterValue for Duration {
+    fn to_string(&self) -> String {
+        format_duration(*self)
+    }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    fn clone_arc(&self) -> Arc<dyn ParameterValue> {
+        Arc::new(*self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn eq_dyn(&self, other: &dyn ParameterValue) -> bool {
+        other.as_any().downcast_ref::<Self>() == Some(self)
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        Ok(Value::String(format_duration(*self)))
+    }
+}
+
+/// A `Duration` that parses from and displays as a human-readable string.
+///
+/// Use it with `try_get` or `try_get_parse` to read durations written as
+/// `"250ms"` or `"2h30m"`; `Duration` itself can be stored directly.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use parameterx::{HumanDuration, Parameters};
+///
+/// let params = Parameters::new().with("timeout", "1m30s");
+///
+/// let timeout: HumanDuration = params.try_get("timeout").unwrap();
+/// assert_eq!(timeout.0, Duration::from_secs(90));
+/// assert_eq!(timeout.to_string(), "1m30s");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HumanDuration(pub Duration);
+
+impl FromStr for HumanDuration {
+    type Err = ParameterError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_duration(s).map(HumanDuration)
+    }
+}
+
+impl TryFrom<String> for HumanDuration {
+    type Error = ParameterError;
+
+    fn try_from(value: String) -> Result<Self> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for HumanDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_duration(self.0))
+    }
+}
+
+impl From<Duration> for HumanDuration {
+    fn from(duration: Duration) -> Self {
+        HumanDuration(duration)
+    }
+}
+
+impl From<HumanDuration> for Duration {
+    fn from(duration: HumanDuration) -> Self {
+        duration.0
+    }
+}
+
+impl DisplayValue for HumanDuration {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format_round_trip() {
+        for text in ["1d2h", "1.5h", "90s", "2 h 30 min", "1ms500us", "7ns"] {
+            let duration = parse_duration(text).unwrap();
+            assert_eq!(parse_duration(&format_duration(duration)).unwrap(), duration, "{}", text);
+        }
+        assert_eq!(format_duration(parse_duration("1.5h").unwrap()), "1h30m");
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("5 parsecs").is_err());
+        assert!(parse_duration("-3s").is_err());
+    }
+}
@@ -0,0 +1,174 @@
+use std::{
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    path::PathBuf,
+    sync::Arc,
+};
+use crate::Parameters;
+use super::{
+    bytes::ByteSize,
+    duration::HumanDuration,
+    traits::ParameterValue,
+    values::{BoolVec, FloatVec, IntVec, StrVec},
+};
+
+/// The broad JSON-like category of a stored value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParameterKind {
+    String,
+    Integer,
+    Float,
+    Bool,
+    Array,
+    Object,
+    Any,
+}
+
+impl ParameterKind {
+    /// Classify a stored value by its concrete type.
+    ///
+    /// Collections such as `Vec<T>` are classified by their JSON form; other
+    /// custom types the crate does not know about are reported as `Any`.
+    pub fn of(value: &dyn ParameterValue) -> Self {
+        let any = value.as_any();
+        macro_rules! is_any {
+            ($($t:ty),+) => { $(any.is::<$t>())||+ };
+        }
+
+        if is_any!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize) {
+            ParameterKind::Integer
+        } else if is_any!(f32, f64) {
+            ParameterKind::Float
+        } else if is_any!(bool) {
+            ParameterKind::Bool
+        } else if is_any!(String, &'static str, char) {
+            ParameterKind::String
+        } else if is_any!(
+            IntVec<i8>, IntVec<i16>, IntVec<i32>, IntVec<i64>, IntVec<i128>, IntVec<isize>,
+            IntVec<u8>, IntVec<u16>, IntVec<u32>, IntVec<u64>, IntVec<u128>, IntVec<usize>,
+            StrVec, FloatVec, BoolVec
+        ) {
+            ParameterKind::Array
+        } else if is_any!(Parameters) {
+            ParameterKind::Object
+        } else {
+            match value.to_json() {
+                Ok(serde_json::Value::Array(_)) => ParameterKind::Array,
+                Ok(serde_json::Value::Object(_)) => ParameterKind::Object,
+                _ => ParameterKind::Any,
+            }
+        }
+    }
+
+    /// Whether a value of kind `actual` satisfies this expected kind.
+    pub fn accepts(&self, actual: ParameterKind) -> bool {
+        match self {
+            ParameterKind::Any => true,
+            ParameterKind::Float => matches!(actual, ParameterKind::Float | ParameterKind::Integer),
+            expected => *expected == actual,
+        }
+    }
+}
+
+impl fmt::Display for ParameterKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ParameterKind::String => "string",
+            ParameterKind::Integer => "integer",
+            ParameterKind::Float => "float",
+            ParameterKind::Bool => "bool",
+            ParameterKind::Array => "array",
+            ParameterKind::Object => "object",
+            ParameterKind::Any => "any",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A stored numeric value, widened so integers keep their exact value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Number {
+    Int(i128),
+    Float(f64),
+}
+
+impl Number {
+    pub(crate) fn as_f64(self) -> f64 {
+        match self {
+            Number::Int(i) => i as f64,
+            Number::Float(f) => f,
+        }
+    }
+}
+
+/// Read a stored integer or float without committing to its concrete width.
+///
+/// `u128` values above `i128::MAX` are not representable and yield `None`.
+pub(crate) fn as_number(value: &dyn ParameterValue) -> Option<Number> {
+    let any = value.as_any();
+    macro_rules! int {
+        ($($t:ty),+) => {
+            $(if let Some(v) = any.downcast_ref::<$t>() {
+                return i128::try_from(*v).ok().map(Number::Int);
+            })+
+        };
+    }
+
+    int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    if let Some(v) = any.downcast_ref::<f64>() {
+        return Some(Number::Float(*v));
+    }
+    any.downcast_ref::<f32>().map(|v| Number::Float(f64::from(*v)))
+}
+
+/// Read a stored numeric value as `f64`, whatever its concrete width.
+pub(crate) fn as_f64(value: &dyn ParameterValue) -> Option<f64> {
+    let any = value.as_any();
+    macro_rules! try_cast {
+        ($($t:ty),+) => {
+            $(if let Some(v) = any.downcast_ref::<$t>() {
+                return Some(*v as f64);
+            })+
+        };
+    }
+
+    try_cast!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+    None
+}
+
+/// Borrow a stored string, whether it was inserted as `String`, `&str` or a JSON string.
+pub(crate) fn as_str(value: &dyn ParameterValue) -> Option<&str> {
+    let any = value.as_any();
+    if let Some(s) = any.downcast_ref::<String>() {
+        return Some(s);
+    }
+    if let Some(s) = any.downcast_ref::<&'static str>() {
+        return Some(s);
+    }
+    any.downcast_ref::<serde_json::Value>().and_then(serde_json::Value::as_str)
+}
+
+/// Parse `text` into the concrete type of `existing`, so a value written back
+/// as text keeps the type it was stored with.
+///
+/// Covers the scalar types the crate stores, with `&str` values parsed into a
+/// `String`. Returns `None` for other types or when `text` does not parse.
+pub(crate) fn parse_as(existing: &dyn ParameterValue, text: &str) -> Option<Arc<dyn ParameterValue>> {
+    let any = existing.as_any();
+    if any.is::<String>() || any.is::<&'static str>() {
+        return Some(Arc::new(String::from(text)));
+    }
+    macro_rules! parse {
+        ($($t:ty),+) => {
+            $(if any.is::<$t>() {
+                return text.parse::<$t>().ok().map(|v| Arc::new(v) as Arc<dyn ParameterValue>);
+            })+
+        };
+    }
+
+    parse!(
+        i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char,
+        HumanDuration, ByteSize, IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, PathBuf
+    );
+    None
+}
@@ -0,0 +1,77 @@
+use std::{any::Any, fmt::Display};
+use num_traits::ToPrimitive;
+use serde_json::{Number, Value};
+use crate::Result;
+use super::values::IntVec;
+
+/// An integer as a JSON number, or as a string if it does not fit in 64 bits.
+fn int_json<T: ToPrimitive + Display>(n: &T) -> Value {
+    n.to_i64().map(Number::from)
+        .or_else(|| n.to_u64().map(Number::from))
+        .map_or_else(|| Value::String(n.to_string()), Value::Number)
+}
+
+fn float_json(n: f64) -> Value {
+    Number::from_f64(n).map_or(Value::Null, Value::Number)
+}
+
+/// The JSON form of a `DisplayValue`: numbers, bools, strings and integer lists
+/// keep their type, and anything else becomes its string form.
+pub(crate) fn typed_json(any: &dyn Any, text: impl FnOnce() -> String) -> Result<Value> {
+    if let Some(json) = any.downcast_ref::<Value>() {
+        return Ok(json.clone());
+    }
+    if let Some(b) = any.downcast_ref::<bool>() {
+        return Ok(Value::Bool(*b));
+    }
+    macro_rules! ints {
+        ($($t:ty),+) => {
+            $(if let Some(n) = any.downcast_ref::<$t>() {
+                return Ok(int_json(n));
+            })+
+            $(if let Some(ints) = any.downcast_ref::<IntVec<$t>>() {
+                return Ok(Value::Array(ints.0.iter().map(int_json).collect()));
+            })+
+        };
+    }
+    ints!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    if let Some(n) = any.downcast_ref::<f64>() {
+        return Ok(float_json(*n));
+    }
+    if let Some(n) = any.downcast_ref::<f32>() {
+        return Ok(float_json(f64::from(*n)));
+    }
+    Ok(Value::String(text()))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use crate::{ParameterValue, Parameters};
+    use super::*;
+
+    #[test]
+    fn test_primitives_serialize_typed() {
+        let params = Parameters::new()
+            .with("port", 8080u16)
+            .with("big", u128::MAX)
+            .with("ratio", 0.5f32)
+            .with("nan", f64::NAN)
+            .with("tls", true)
+            .with("initial", 'x')
+            .with("ids", IntVec(vec![1i8, -2]))
+            .with("nested", Parameters::new().with("depth", 2));
+
+        assert_eq!(params.to_json().unwrap(), json!({
+            "port": 8080,
+            "big": format!("{}", u128::MAX),
+            "ratio": 0.5,
+            "nan": null,
+            "tls": true,
+            "initial": "x",
+            "ids": [1, -2],
+            "nested": { "depth": 2 },
+        }));
+        assert_eq!(ParameterValue::to_json(&"name").unwrap(), json!("name"));
+    }
+}
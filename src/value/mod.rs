@@ -1,6 +1,26 @@
 mod traits;
 mod values;
+mod inspect;
+mod secret;
+mod range;
+mod pool;
+mod collections;
+mod null;
+mod duration;
+mod bytes;
+mod net;
+mod json;
 
-pub use traits::ParameterValue;
+pub use traits::{DisplayValue, ParameterValue};
 #[allow(unused_imports)]
 pub use values::*;
+pub use inspect::ParameterKind;
+pub use secret::Secret;
+pub use range::RangeValue;
+pub use null::Null;
+pub use bytes::ByteSize;
+pub use duration::{format_duration, parse_duration, HumanDuration};
+pub(crate) use null::is_null;
+pub(crate) use pool::share;
+pub(crate) use secret::MASK;
+pub(crate) use inspect::{as_f64, as_number, as_str, parse_as, Number};
@@ -0,0 +1,47 @@
+use std::{
+    any::Any,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    path::PathBuf,
+    sync::Arc,
+};
+use serde_json::Value;
+use crate::Result;
+use super::traits::{DisplayValue, ParameterValue};
+
+impl DisplayValue for IpAddr {}
+impl DisplayValue for Ipv4Addr {}
+impl DisplayValue for Ipv6Addr {}
+impl DisplayValue for SocketAddr {}
+impl DisplayValue for SocketAddrV4 {}
+impl DisplayValue for SocketAddrV6 {}
+
+/// Paths have no `Display`, so they render through `Path::display`, replacing invalid UTF-8.
+impl ParameterValue for PathBuf {
+    fn to_string(&self) -> String {
+        self.display().to_string()
+    }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    fn clone_arc(&self) -> Arc<dyn ParameterValue> {
+        Arc::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn eq_dyn(&self, other: &dyn ParameterValue) -> bool {
+        other.as_any().downcast_ref::<Self>() == Some(self)
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        Ok(Value::String(self.display().to_string()))
+    }
+}
@@ -0,0 +1,57 @@
+use std::{any::Any, sync::Arc};
+use serde_json::Value;
+use crate::Result;
+use super::traits::ParameterValue;
+
+/// An explicitly empty value, for optional parameters that are present but unset.
+///
+/// Renders as `null` and serializes to JSON `null`.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{Null, Parameters};
+///
+/// let params = Parameters::new().with("proxy", Null);
+/// assert_eq!(params.get_string("proxy").as_deref(), Some("null"));
+/// assert!(params.to_json().unwrap()["proxy"].is_null());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Null;
+
+impl ParameterValue for Null {
+    fn to_string(&self) -> String {
+        "null".to_string()
+    }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    fn clone_arc(&self) -> Arc<dyn ParameterValue> {
+        Arc::new(Null)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    /// Only equal to another `Null`, not to a JSON `null`, so that equality stays symmetric.
+    fn eq_dyn(&self, other: &dyn ParameterValue) -> bool {
+        other.as_any().is::<Null>()
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        Ok(Value::Null)
+    }
+}
+
+/// Whether a stored value is `Null` or a JSON `null`, as loaded by `from_json`.
+pub(crate) fn is_null(value: &dyn ParameterValue) -> bool {
+    let any = value.as_any();
+    any.is::<Null>() || any.downcast_ref::<Value>().is_some_and(Value::is_null)
+}
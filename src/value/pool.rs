@@ -0,0 +1,159 @@
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::{Arc, LazyLock},
+};
+use super::ParameterValue;
+
+/// The smallest and largest integers kept in the pools, clamped to each type's range.
+const POOL_MIN: i128 = -128;
+const POOL_MAX: i128 = 1023;
+
+/// The longest strings, in bytes, kept in the per-thread cache.
+const SMALL_STRING: usize = 23;
+
+/// The number of values the per-thread cache holds, each slot keeping the latest value hashed to it.
+const CACHE_SLOTS: usize = 256;
+
+type Pool = LazyLock<Vec<Arc<dyn ParameterValue>>>;
+
+/// A float's bit pattern or a short string's bytes, which identify a cached value exactly.
+#[derive(PartialEq, Eq, Hash)]
+struct SmallKey {
+    type_id: TypeId,
+    len: usize,
+    bytes: [u8; SMALL_STRING],
+}
+
+type Slot = Option<(SmallKey, Arc<dyn ParameterValue>)>;
+
+thread_local! {
+    static RECENT: RefCell<Vec<Slot>> = RefCell::new((0..CACHE_SLOTS).map(|_| None).collect());
+}
+
+/// Wrap `value` for storage, reusing an existing `Arc` where possible so that
+/// inserting it does not allocate.
+///
+/// Booleans and integers from -128 to 1023 come from preallocated pools.
+/// Floats and strings of up to 23 bytes go through a small per-thread cache,
+/// so a value inserted again on the same thread, as in a loop building many
+/// similar sets, reuses the earlier allocation; other values get their own.
+/// The cache keeps up to 256 such values alive after their sets are dropped,
+/// which is why longer strings, strings with spare capacity and `Secret`s
+/// are never cached.
+///
+/// Shared values are safe because they are only ever modified through
+/// `get_mut`, and that copies a shared value before handing out a mutable
+/// reference.
+pub(crate) fn share<V: ParameterValue + 'static>(value: V) -> Arc<dyn ParameterValue> {
+    if let Some(shared) = pooled(&value) {
+        return shared;
+    }
+    match small_key(&value) {
+        Some(key) => recent(key, value),
+        None => Arc::new(value),
+    }
+}
+
+/// The cached `Arc` for `key`, or a new one for `value` that replaces the slot's previous value.
+fn recent<V: ParameterValue + 'static>(key: SmallKey, value: V) -> Arc<dyn ParameterValue> {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    let index = hasher.finish() as usize % CACHE_SLOTS;
+    RECENT.with(|slots| {
+        let mut slots = slots.borrow_mut();
+        match &slots[index] {
+            Some((cached, shared)) if *cached == key => shared.clone(),
+            _ => {
+                let shared: Arc<dyn ParameterValue> = Arc::new(value);
+                slots[index] = Some((key, shared.clone()));
+                shared
+            }
+        }
+    })
+}
+
+fn small_key(value: &dyn Any) -> Option<SmallKey> {
+    let bytes: &[u8] = if let Some(n) = value.downcast_ref::<f64>() {
+        &n.to_bits().to_le_bytes()
+    } else if let Some(n) = value.downcast_ref::<f32>() {
+        &n.to_bits().to_le_bytes()
+    } else if let Some(text) = value.downcast_ref::<&'static str>() {
+        text.as_bytes()
+    } else if let Some(text) = value.downcast_ref::<String>().filter(|text| text.capacity() <= SMALL_STRING) {
+        text.as_bytes()
+    } else {
+        return None;
+    };
+    if bytes.len() > SMALL_STRING {
+        return None;
+    }
+    let mut key = SmallKey { type_id: value.type_id(), len: bytes.len(), bytes: [0; SMALL_STRING] };
+    key.bytes[..bytes.len()].copy_from_slice(bytes);
+    Some(key)
+}
+
+fn pooled(value: &dyn Any) -> Option<Arc<dyn ParameterValue>> {
+    if let Some(flag) = value.downcast_ref::<bool>() {
+        static BOOLS: Pool = LazyLock::new(|| vec![Arc::new(false), Arc::new(true)]);
+        return Some(BOOLS[usize::from(*flag)].clone());
+    }
+
+    macro_rules! int {
+        ($($t:ty),+) => {
+            $(if let Some(n) = value.downcast_ref::<$t>() {
+                let first = POOL_MIN.max(<$t>::MIN as i128);
+                let last = POOL_MAX.min(i128::try_from(<$t>::MAX).unwrap_or(i128::MAX));
+                let n = *n as i128;
+                if !(first..=last).contains(&n) {
+                    return None;
+                }
+                static POOL: Pool = LazyLock::new(|| {
+                    let first = POOL_MIN.max(<$t>::MIN as i128);
+                    let last = POOL_MAX.min(i128::try_from(<$t>::MAX).unwrap_or(i128::MAX));
+                    (first..=last).map(|n| Arc::new(n as $t) as Arc<dyn ParameterValue>).collect()
+                });
+                return Some(POOL[(n - first) as usize].clone());
+            })+
+        };
+    }
+
+    int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_values_share_one_allocation() {
+        assert!(Arc::ptr_eq(&share(7i32), &share(7i32)));
+        assert!(Arc::ptr_eq(&share(true), &share(true)));
+        assert!(Arc::ptr_eq(&share(-128i8), &share(-128i8)));
+        assert!(Arc::ptr_eq(&share(255u8), &share(255u8)));
+        assert!(Arc::ptr_eq(&share(1023u128), &share(1023u128)));
+        assert!(!Arc::ptr_eq(&share(1024u64), &share(1024u64)));
+        assert!(!Arc::ptr_eq(&share(-129i64), &share(-129i64)));
+
+        assert_eq!(share(u128::MAX).as_any().downcast_ref::<u128>(), Some(&u128::MAX));
+        assert_eq!(share(-5isize).as_any().downcast_ref::<isize>(), Some(&-5));
+    }
+
+    #[test]
+    fn test_floats_and_short_strings_are_cached_per_thread() {
+        assert!(Arc::ptr_eq(&share(1.5f64), &share(1.5f64)));
+        assert!(Arc::ptr_eq(&share("a"), &share("a")));
+        assert!(Arc::ptr_eq(&share("ab".to_string()), &share("ab".to_string())));
+        assert!(!Arc::ptr_eq(&share(0.0f64), &share(-0.0f64)));
+        assert!(!Arc::ptr_eq(&share(1.5f32), &share(1.5f64)));
+        assert!(!Arc::ptr_eq(&share("x".repeat(24)), &share("x".repeat(24))));
+        assert!(!Arc::ptr_eq(&share(String::with_capacity(64)), &share(String::with_capacity(64))));
+
+        assert_eq!(share(-0.0f64).as_any().downcast_ref::<f64>().map(|n| n.is_sign_negative()), Some(true));
+        assert_eq!(share("ab").as_any().downcast_ref::<&str>(), Some(&"ab"));
+        let other_thread = std::thread::spawn(|| share(2.5f64)).join().unwrap();
+        assert!(!Arc::ptr_eq(&other_thread, &share(2.5f64)));
+    }
+}
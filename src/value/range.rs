@@ -0,0 +1,222 @@
+use std::{
+    fmt::{self, Debug, Display},
+    ops::{Bound, Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive},
+    str::FromStr,
+};
+use crate::error::ParameterError;
+use super::traits::DisplayValue;
+
+fn parse_error(message: impl ToString) -> ParameterError {
+    ParameterError::Parse {
+        format: "range",
+        message: message.to_string(),
+    }
+}
+
+fn parse_bound<T: FromStr>(text: &str, original: &str) -> Result<T, ParameterError> {
+    text.trim().parse().map_err(|_| parse_error(format!("invalid bound `{}` in `{}`", text.trim(), original)))
+}
+
+/// A range of values stored as a single parameter, such as a threshold or a window.
+///
+/// Parses Rust range syntax (`10..20`, `10..=20`, `..=100`, `5..`) and
+/// comparisons (`>= 5`, `< 1.5`, `= 3`), which can be combined with a
+/// comma, as in `> 0, <= 10`. Keeping both ends in one value means they
+/// cannot drift apart the way two separate keys can.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{Parameters, RangeValue};
+///
+/// let params = Parameters::new().with("latency_ms", "..=250").with("score", ">= 0.5");
+///
+/// let latency: RangeValue<u32> = params.try_get_parse("latency_ms").unwrap();
+/// assert!(latency.contains(&250));
+/// assert!(!latency.contains(&251));
+///
+/// let score: RangeValue<f64> = params.try_get_parse("score").unwrap();
+/// assert!(score.contains(&0.75));
+/// assert_eq!(score.to_string(), "0.5..");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeValue<T> {
+    start: Bound<T>,
+    end: Bound<T>,
+}
+
+impl<T: PartialOrd> RangeValue<T> {
+    /// Create a range from explicit bounds.
+    pub fn new(start: Bound<T>, end: Bound<T>) -> Self {
+        Self { start, end }
+    }
+
+    pub fn start(&self) -> Bound<&T> {
+        self.start.as_ref()
+    }
+
+    pub fn end(&self) -> Bound<&T> {
+        self.end.as_ref()
+    }
+
+    /// Whether `value` lies within the range.
+    pub fn contains(&self, value: &T) -> bool {
+        let above = match &self.start {
+            Bound::Included(start) => value >= start,
+            Bound::Excluded(start) => value > start,
+            Bound::Unbounded => true,
+        };
+        let below = match &self.end {
+            Bound::Included(end) => value <= end,
+            Bound::Excluded(end) => value < end,
+            Bound::Unbounded => true,
+        };
+        above && below
+    }
+}
+
+impl<T: FromStr + PartialOrd> FromStr for RangeValue<T> {
+    type Err = ParameterError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let trimmed = text.trim();
+        let range = match trimmed.find("..") {
+            Some(at) => {
+                let (start, rest) = (&trimmed[..at], &trimmed[at + 2..]);
+                let start = match start.trim() {
+                    "" => Bound::Unbounded,
+                    start => Bound::Included(parse_bound(start, text)?),
+                };
+                let end = match rest.strip_prefix('=') {
+                    Some(end) if end.trim().is_empty() => return Err(parse_error(format!("inclusive range `{}` has no end", text))),
+                    Some(end) => Bound::Included(parse_bound(end, text)?),
+                    None if rest.trim().is_empty() => Bound::Unbounded,
+                    None => Bound::Excluded(parse_bound(rest, text)?),
+                };
+                Self { start, end }
+            }
+            None => {
+                let mut range = Self { start: Bound::Unbounded, end: Bound::Unbounded };
+                for part in trimmed.split(',') {
+                    let part = part.trim();
+                    let (lower, upper) = if let Some(bound) = part.strip_prefix(">=") {
+                        (Some(Bound::Included(parse_bound(bound, text)?)), None)
+                    } else if let Some(bound) = part.strip_prefix('>') {
+                        (Some(Bound::Excluded(parse_bound(bound, text)?)), None)
+                    } else if let Some(bound) = part.strip_prefix("<=") {
+                        (None, Some(Bound::Included(parse_bound(bound, text)?)))
+                    } else if let Some(bound) = part.strip_prefix('<') {
+                        (None, Some(Bound::Excluded(parse_bound(bound, text)?)))
+                    } else if let Some(bound) = part.strip_prefix('=') {
+                        (Some(Bound::Included(parse_bound(bound, text)?)), Some(Bound::Included(parse_bound(bound, text)?)))
+                    } else {
+                        return Err(parse_error(format!("expected a range or comparison, found `{}`", part)));
+                    };
+                    for (bound, slot) in [(lower, &mut range.start), (upper, &mut range.end)] {
+                        if let Some(bound) = bound {
+                            if !matches!(slot, Bound::Unbounded) {
+                                return Err(parse_error(format!("`{}` bounds the same side twice", text)));
+                            }
+                            *slot = bound;
+                        }
+                    }
+                }
+                range
+            }
+        };
+
+        if let (Bound::Included(start) | Bound::Excluded(start), Bound::Included(end) | Bound::Excluded(end)) = (&range.start, &range.end) {
+            if start > end {
+                return Err(parse_error(format!("`{}` starts after it ends", text)));
+            }
+        }
+        Ok(range)
+    }
+}
+
+/// Ranges render in Rust syntax where possible, and as comparisons when the
+/// start is exclusive: `10..20`, `..=100`, `> 5`, `> 0, <= 10`.
+impl<T: Display> Display for RangeValue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.start, &self.end) {
+            (Bound::Excluded(start), Bound::Unbounded) => write!(f, "> {}", start),
+            (Bound::Excluded(start), Bound::Included(end)) => write!(f, "> {}, <= {}", start, end),
+            (Bound::Excluded(start), Bound::Excluded(end)) => write!(f, "> {}, < {}", start, end),
+            (start, end) => {
+                if let Bound::Included(start) = start {
+                    write!(f, "{}", start)?;
+                }
+                match end {
+                    Bound::Included(end) => write!(f, "..={}", end),
+                    Bound::Excluded(end) => write!(f, "..{}", end),
+                    Bound::Unbounded => f.write_str(".."),
+                }
+            }
+        }
+    }
+}
+
+impl<T> DisplayValue for RangeValue<T> where T: Display + Debug + Clone + Send + Sync + 'static {}
+
+impl<T> From<Range<T>> for RangeValue<T> {
+    fn from(range: Range<T>) -> Self {
+        Self { start: Bound::Included(range.start), end: Bound::Excluded(range.end) }
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for RangeValue<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        Self { start: Bound::Included(start), end: Bound::Included(end) }
+    }
+}
+
+impl<T> From<RangeFrom<T>> for RangeValue<T> {
+    fn from(range: RangeFrom<T>) -> Self {
+        Self { start: Bound::Included(range.start), end: Bound::Unbounded }
+    }
+}
+
+impl<T> From<RangeTo<T>> for RangeValue<T> {
+    fn from(range: RangeTo<T>) -> Self {
+        Self { start: Bound::Unbounded, end: Bound::Excluded(range.end) }
+    }
+}
+
+impl<T> From<RangeToInclusive<T>> for RangeValue<T> {
+    fn from(range: RangeToInclusive<T>) -> Self {
+        Self { start: Bound::Unbounded, end: Bound::Included(range.end) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_render() {
+        for (text, rendered) in [
+            ("10..20", "10..20"),
+            (" 10 ..= 20 ", "10..=20"),
+            ("..=100", "..=100"),
+            ("-5..", "-5.."),
+            ("..", ".."),
+            (">= 5", "5.."),
+            ("< 3", "..3"),
+            ("> 0, <= 10", "> 0, <= 10"),
+            ("= 7", "7..=7"),
+        ] {
+            let range: RangeValue<i64> = text.parse().unwrap();
+            assert_eq!(format!("{}", range), rendered, "{}", text);
+            assert_eq!(format!("{}", range).parse::<RangeValue<i64>>().unwrap(), range);
+        }
+
+        let window = RangeValue::from(10..20);
+        assert!(window.contains(&10) && window.contains(&19) && !window.contains(&20));
+        assert!(!">2, <=4".parse::<RangeValue<i32>>().unwrap().contains(&2));
+
+        for bad in ["20..10", "1..=", "> 1, >= 2", "about 5", "a..b"] {
+            assert!(matches!(bad.parse::<RangeValue<i32>>(), Err(ParameterError::Parse { format: "range", .. })), "{}", bad);
+        }
+    }
+}
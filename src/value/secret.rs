@@ -0,0 +1,127 @@
+use std::{
+    any::Any,
+    fmt,
+    sync::Arc,
+};
+use crate::Result;
+use super::traits::ParameterValue;
+
+/// The text shown in place of a masked value.
+pub(crate) const MASK: &str = "***";
+
+/// A value that must never appear in logs or serialized output.
+///
+/// `Display`, `Debug`, `ParameterValue::to_string` and `to_json` all render
+/// `***`, and so does every display and logging path of `Parameters`. The
+/// lossless sinks, such as `Serialize` and `ParameterStore::save`, write the
+/// wrapped value instead, which comes back as a plain value when loaded.
+/// Secrets compare by their real contents, so rotating a token is seen as a change.
+///
+/// `Secret` deliberately does not implement `Clone`; copies are made through
+/// the stored `Parameters` or by wrapping the exposed value again.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{Parameters, Secret};
+///
+/// let mut params = Parameters::new();
+/// params.insert_secret("api_token", "s3cr3t".to_string());
+///
+/// assert_eq!(params.get_string("api_token"), Some("***".to_string()));
+/// assert_eq!(params.get::<Secret<String>>("api_token").unwrap().expose(), "s3cr3t");
+/// ```
+pub struct Secret<T>(T);
+
+impl<T> Secret<T> {
+    pub fn new(value: T) -> Self {
+        Secret(value)
+    }
+
+    /// Borrow the wrapped value.
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Secret<T> {
+    fn from(value: T) -> Self {
+        Secret(value)
+    }
+}
+
+impl<T: PartialEq> PartialEq for Secret<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> fmt::Display for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(MASK)
+    }
+}
+
+impl<T> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(MASK)
+    }
+}
+
+impl<T> ParameterValue for Secret<T>
+where
+    T: ParameterValue + Clone + PartialEq + 'static,
+{
+    fn to_string(&self) -> String {
+        MASK.to_string()
+    }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    fn clone_arc(&self) -> Arc<dyn ParameterValue> {
+        Arc::new(Secret(self.0.clone()))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn eq_dyn(&self, other: &dyn ParameterValue) -> bool {
+        other.as_any().downcast_ref::<Self>().is_some_and(|other| self == other)
+    }
+
+    fn exposed(&self) -> Option<&dyn ParameterValue> {
+        Some(&self.0)
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value> {
+        Ok(serde_json::Value::String(MASK.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Parameters;
+    use super::*;
+
+    #[test]
+    fn test_secret_is_masked_but_compared() {
+        let params = Parameters::new().with("token", Secret::new("hunter2".to_string()));
+        let rotated = Parameters::new().with("token", Secret::new("hunter3".to_string()));
+
+        assert_eq!(format!("{:?}", params), r#"Parameters {"token": ***}"#);
+        assert!(!format!("{}", params).contains("hunter"));
+        assert_eq!(params.diff(&rotated).changed, vec!["token"]);
+        assert_eq!(params.clone(), params);
+    }
+}
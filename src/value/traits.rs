@@ -3,25 +3,89 @@ use std::{
     fmt::Debug,
     sync::Arc,
 };
-use serde_json;
 use crate::{error::ParameterError, Result};
+use super::json::typed_json;
 
 pub trait ParameterValue: Send + Sync + Debug {
     fn to_string(&self) -> String;
     fn type_name(&self) -> &'static str;
     fn clone_arc(&self) -> Arc<dyn ParameterValue>;
     fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Compare with another stored value.
+    ///
+    /// The default treats values as equal when they have the same concrete type
+    /// and the same string representation.
+    fn eq_dyn(&self, other: &dyn ParameterValue) -> bool {
+        self.type_name() == other.type_name() && self.to_string() == other.to_string()
+    }
+
+    /// The value wrapped by a `Secret`, or `None` for every other value.
+    ///
+    /// Lossless sinks such as serialization and persistence write this instead of the mask.
+    fn exposed(&self) -> Option<&dyn ParameterValue> {
+        None
+    }
 
     fn to_json(&self) -> Result<serde_json::Value> {
-        Err(ParameterError::ConversionFailed(
-            "JSON serialization not implemented for this type".into()
+        Err(ParameterError::conversion_failed(
+            "",
+            &self.to_string(),
+            self.type_name(),
+            "JSON",
+            "JSON serialization not implemented for this type",
         ))
     }
 }
 
+/// Marks a type that is stored as a parameter value as is and rendered through its `ToString`.
+///
+/// Implementing this empty trait gives a type a `ParameterValue`
+/// implementation whose `to_json` keeps numbers, bools and strings typed and
+/// renders other types as JSON strings. It is implemented for the primitive types, `String` and
+/// `&'static str`, and for the crate's own value types; collections such as
+/// `Vec<T>` and `HashMap<String, T>` have their own `ParameterValue`
+/// implementations instead, which is why the marker is needed at all.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{DisplayValue, Parameters};
+///
+/// #[derive(Debug, Clone)]
+/// struct Region(&'static str);
+///
+/// impl std::fmt::Display for Region {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         f.write_str(self.0)
+///     }
+/// }
+///
+/// impl DisplayValue for Region {}
+///
+/// let params = Parameters::new().with("region", Region("eu-west-1"));
+/// assert_eq!(params.get_string("region").as_deref(), Some("eu-west-1"));
+/// ```
+pub trait DisplayValue: Send + Sync + Debug + Clone + ToString + Any {}
+
+macro_rules! display_value {
+    ($($t:ty),+ $(,)?) => {
+        $(impl DisplayValue for $t {})+
+    };
+}
+
+display_value!(
+    i8, i16, i32, i64, i128, isize,
+    u8, u16, u32, u64, u128, usize,
+    f32, f64, bool, char,
+    String, &'static str, Box<str>, Arc<str>,
+    serde_json::Value,
+);
+
 impl<T> ParameterValue for T
 where
-    T: Send + Sync + Debug + Clone + ToString + Any + 'static
+    T: DisplayValue + 'static
 {
     fn to_string(&self) -> String {
         self.to_string()
@@ -38,4 +102,12 @@ where
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value> {
+        typed_json(self, || ToString::to_string(self))
+    }
 }
\ No newline at end of file
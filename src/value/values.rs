@@ -1,6 +1,9 @@
 use std::ops::{Add, Sub, Mul, Div};
 use num_traits::PrimInt;
-use std::fmt::Debug;
+use std::{any::Any, fmt::{self, Debug, Display}, str::FromStr, sync::Arc};
+use serde_json::{Number, Value};
+use crate::error::ParameterError;
+use super::traits::{DisplayValue, ParameterValue};
 
 pub trait IntegerBehavior:
     PrimInt +
@@ -35,17 +38,190 @@ impl<T> IntegerBehavior for T where T:
 #[derive(Debug, Clone)]
 pub struct IntVec<T: IntegerBehavior>(pub Vec<T>);
 
-impl<T> ToString for IntVec<T>
+impl<T> Display for IntVec<T>
 where
     T: IntegerBehavior
 {
-    fn to_string(&self) -> String {
-        format!("{:?}", self.0)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.0)
     }
 }
 
+impl<T: IntegerBehavior> DisplayValue for IntVec<T> {}
+
 impl <T:IntegerBehavior> From<IntVec<T>> for Vec<T> {
     fn from(value: IntVec<T>) -> Self {
         value.0
     }
 }
+
+impl<T> FromStr for IntVec<T>
+where
+    T: IntegerBehavior + FromStr,
+{
+    type Err = ParameterError;
+
+    /// Parse a comma-separated list such as `1, 2, 3`, optionally wrapped in brackets.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse_items(text, |item| item.parse().ok()).map(IntVec)
+    }
+}
+
+/// Split a comma-separated list, optionally wrapped in `[` and `]`, and parse each trimmed item.
+fn parse_items<T>(text: &str, parse: impl Fn(&str) -> Option<T>) -> Result<Vec<T>, ParameterError> {
+    let text = text.trim();
+    let text = text.strip_prefix('[').and_then(|inner| inner.strip_suffix(']')).unwrap_or(text);
+    if text.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    text.split(',')
+        .map(|item| {
+            let item = item.trim();
+            parse(item).ok_or_else(|| ParameterError::Parse {
+                format: "list",
+                message: format!("invalid item `{}` in `{}`", item, text),
+            })
+        })
+        .collect()
+}
+
+/// Implements `ParameterValue` for a list newtype, rendering it as `[a, b]` and converting it to a JSON array.
+///
+/// The list types do not implement `Display`, so that they can provide their own `to_json`.
+macro_rules! list_value {
+    ($name:ident, $item:ty, $parse:expr, $json:expr) => {
+        impl ParameterValue for $name {
+            fn to_string(&self) -> String {
+                let items: Vec<String> = self.0.iter().map(|item| format!("{}", item)).collect();
+                format!("[{}]", items.join(", "))
+            }
+
+            fn type_name(&self) -> &'static str {
+                std::any::type_name::<Self>()
+            }
+
+            fn clone_arc(&self) -> Arc<dyn ParameterValue> {
+                Arc::new(self.clone())
+            }
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn Any {
+                self
+            }
+
+            fn eq_dyn(&self, other: &dyn ParameterValue) -> bool {
+                other.as_any().downcast_ref::<Self>().is_some_and(|other| self == other)
+            }
+
+            fn to_json(&self) -> crate::Result<Value> {
+                let json: fn(&$item) -> Value = $json;
+                Ok(Value::Array(self.0.iter().map(json).collect()))
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParameterError;
+
+            /// Parse a comma-separated list, optionally wrapped in brackets.
+            fn from_str(text: &str) -> Result<Self, Self::Err> {
+                let parse: fn(&str) -> Option<$item> = $parse;
+                parse_items(text, parse).map($name)
+            }
+        }
+
+        impl From<Vec<$item>> for $name {
+            fn from(items: Vec<$item>) -> Self {
+                $name(items)
+            }
+        }
+
+        impl From<$name> for Vec<$item> {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl FromIterator<$item> for $name {
+            fn from_iter<I: IntoIterator<Item = $item>>(iter: I) -> Self {
+                $name(iter.into_iter().collect())
+            }
+        }
+    };
+}
+
+/// A list of strings stored as a single parameter.
+///
+/// Parses from a comma-separated string; items are trimmed, and items
+/// wrapped in double quotes are unquoted.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{Parameters, StrVec};
+///
+/// let params = Parameters::new().with("hosts", "db-1, db-2");
+/// let hosts: StrVec = params.try_get_parse("hosts").unwrap();
+/// assert_eq!(hosts.0, ["db-1", "db-2"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StrVec(pub Vec<String>);
+
+list_value!(
+    StrVec,
+    String,
+    |item| Some(item.strip_prefix('"').and_then(|item| item.strip_suffix('"')).unwrap_or(item).to_string()),
+    |item| Value::String(item.clone())
+);
+
+/// A list of floating-point numbers stored as a single parameter.
+///
+/// # Examples
+///
+/// ```
+/// use parameterx::{FloatVec, Parameters};
+///
+/// let params = Parameters::new().with("weights", "[0.5, 1.5]".parse::<FloatVec>().unwrap());
+/// assert_eq!(params.to_json().unwrap()["weights"], serde_json::json!([0.5, 1.5]));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FloatVec(pub Vec<f64>);
+
+list_value!(
+    FloatVec,
+    f64,
+    |item| item.parse().ok(),
+    |item| Number::from_f64(*item).map_or(Value::Null, Value::Number)
+);
+
+/// A list of booleans stored as a single parameter, parsed from `true` and `false` items.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BoolVec(pub Vec<bool>);
+
+list_value!(
+    BoolVec,
+    bool,
+    |item| item.parse().ok(),
+    |item| Value::Bool(*item)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lists_parse_and_round_trip() {
+        let words: StrVec = r#"[a, "b c" , d]"#.parse().unwrap();
+        assert_eq!(words.0, ["a", "b c", "d"]);
+        assert_eq!(ParameterValue::to_string(&words), "[a, b c, d]");
+        assert_eq!(ParameterValue::to_string(&words).parse::<StrVec>().unwrap(), words);
+
+        assert_eq!("".parse::<BoolVec>().unwrap(), BoolVec::default());
+        assert_eq!(BoolVec(vec![true, false]).to_json().unwrap(), serde_json::json!([true, false]));
+        assert!(matches!("true, maybe".parse::<BoolVec>(), Err(ParameterError::Parse { format: "list", .. })));
+        assert_eq!("1,2,3".parse::<IntVec<u8>>().unwrap().0, [1, 2, 3]);
+        assert!("1,300".parse::<IntVec<u8>>().is_err());
+    }
+}
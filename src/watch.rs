@@ -0,0 +1,217 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{mpsc::{self, RecvTimeoutError, Sender}, Arc, Mutex},
+    thread::{self, JoinHandle},
+    time::{Duration, SystemTime},
+};
+use crate::{ParameterError, Parameters, ParametersDiff, Result, SharedParameters};
+
+type ReloadHook = Box<dyn Fn(&ParametersDiff) + Send>;
+type ErrorHook = Box<dyn Fn(&ParameterError) + Send>;
+
+/// The modification time and length last seen, used to notice that a file changed.
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &Path) -> Stamp {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+#[derive(Default)]
+struct Hooks {
+    reload: Vec<ReloadHook>,
+    error: Vec<ErrorHook>,
+}
+
+struct State {
+    path: PathBuf,
+    parameters: SharedParameters,
+    hooks: Mutex<Hooks>,
+}
+
+impl State {
+    /// Swap in the file's current contents, keeping the old values if it cannot be loaded.
+    fn reload(&self) -> Result<ParametersDiff> {
+        let hooks = self.hooks.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        match Parameters::from_file(&self.path) {
+            Ok(new) => {
+                let diff = self.parameters.reload_from(new);
+                if !diff.is_empty() {
+                    for hook in &hooks.reload {
+                        hook(&diff);
+                    }
+                }
+                Ok(diff)
+            }
+            Err(err) => {
+                for hook in &hooks.error {
+                    hook(&err);
+                }
+                Err(err)
+            }
+        }
+    }
+}
+
+/// Keeps a `SharedParameters` in step with a configuration file.
+///
+/// A background thread polls the file's modification time and length and,
+/// when either changes, reloads it with `Parameters::from_file` and swaps the
+/// new values in under the write lock, so readers see either the old set or
+/// the new one. Subscribers of the shared parameters are notified of the keys
+/// that changed. A file that fails to load leaves the current values in place
+/// and is reported to the `on_error` callbacks. Polling stops when the
+/// watcher is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use parameterx::FileWatcher;
+///
+/// let path = std::env::temp_dir().join("parameterx-watch-doc.json");
+/// std::fs::write(&path, r#"{"workers": 4}"#).unwrap();
+///
+/// let watcher = FileWatcher::start(&path, Duration::from_millis(50)).unwrap();
+/// let params = watcher.parameters();
+/// assert_eq!(params.get::<i64>("workers"), Some(4));
+///
+/// std::fs::write(&path, r#"{"workers": 16}"#).unwrap();
+/// watcher.reload().unwrap();
+/// assert_eq!(params.get::<i64>("workers"), Some(16));
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+pub struct FileWatcher {
+    state: Arc<State>,
+    stop: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl FileWatcher {
+    /// Load `path` and poll it for changes every `interval`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The file to load, in any format `Parameters::from_file` understands.
+    /// * `interval` - How often to check the file for changes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the watcher, or the error from the initial load.
+    pub fn start(path: impl Into<PathBuf>, interval: Duration) -> Result<Self> {
+        let path = path.into();
+        let mut seen = stamp(&path);
+        let parameters = SharedParameters::new(Parameters::from_file(&path)?);
+        let state = Arc::new(State { path, parameters, hooks: Mutex::default() });
+
+        let (stop, stopped) = mpsc::channel::<()>();
+        let polled = state.clone();
+        let thread = thread::spawn(move || {
+            while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                let current = stamp(&polled.path);
+                if current != seen {
+                    seen = current;
+                    let _ = polled.reload();
+                }
+            }
+        });
+
+        Ok(Self { state, stop: Some(stop), thread: Some(thread) })
+    }
+
+    /// A handle to the watched parameters; it sees every reload.
+    pub fn parameters(&self) -> SharedParameters {
+        self.state.parameters.clone()
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.state.path
+    }
+
+    /// Call `hook` with the differences each time a reload changes the parameters.
+    pub fn on_reload(&self, hook: impl Fn(&ParametersDiff) + Send + 'static) {
+        self.hooks().reload.push(Box::new(hook));
+    }
+
+    /// Call `hook` each time the file changes but cannot be loaded.
+    pub fn on_error(&self, hook: impl Fn(&ParameterError) + Send + 'static) {
+        self.hooks().error.push(Box::new(hook));
+    }
+
+    /// Reload the file now rather than waiting for the next poll.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the differences swapped in, or the load error, in which case the values are unchanged.
+    pub fn reload(&self) -> Result<ParametersDiff> {
+        self.state.reload()
+    }
+
+    fn hooks(&self) -> std::sync::MutexGuard<'_, Hooks> {
+        self.state.hooks.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl std::fmt::Debug for FileWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileWatcher")
+            .field("path", &self.state.path)
+            .finish()
+    }
+}
+
+impl Drop for FileWatcher {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Parameters {
+    /// Load `path` and keep the returned parameters up to date as the file changes,
+    /// checking once a second.
+    ///
+    /// See [`FileWatcher`] for how reloads are applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The file to load and watch.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the watcher, or the error from the initial load.
+    pub fn watch_file(path: impl Into<PathBuf>) -> Result<FileWatcher> {
+        FileWatcher::start(path, Duration::from_secs(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::channel;
+    use super::*;
+
+    #[test]
+    fn test_polling_reloads_and_keeps_values_on_error() {
+        let path = std::env::temp_dir().join("parameterx-watch-test.json");
+        fs::write(&path, r#"{"mode": "safe"}"#).unwrap();
+        let watcher = FileWatcher::start(&path, Duration::from_millis(10)).unwrap();
+        let (reloads, reloaded) = channel();
+        let (errors, failed) = channel();
+        watcher.on_reload(move |diff| reloads.send(diff.len()).unwrap());
+        watcher.on_error(move |err| errors.send(format!("{}", err)).unwrap());
+
+        fs::write(&path, r#"{"mode": "fast", "extra": true}"#).unwrap();
+        assert_eq!(reloaded.recv_timeout(Duration::from_secs(5)), Ok(2));
+        assert_eq!(watcher.parameters().get::<String>("mode").as_deref(), Some("fast"));
+
+        fs::write(&path, "{ not json").unwrap();
+        assert!(failed.recv_timeout(Duration::from_secs(5)).is_ok());
+        assert_eq!(watcher.parameters().get::<String>("mode").as_deref(), Some("fast"));
+
+        drop(watcher);
+        fs::remove_file(&path).unwrap();
+    }
+}